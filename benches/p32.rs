@@ -17,6 +17,9 @@ fn criterion_p32(c: &mut Criterion) {
     c.bench_function("p32_sqrt", |c| c.iter(|| bb(X).sqrt()));
     c.bench_function("p32_round", |c| c.iter(|| bb(Y).round()));
 
+    c.bench_function("p32_from_f64", |c| c.iter(|| P32::from(bb(117.334_f64))));
+    c.bench_function("p32_from_f32", |c| c.iter(|| P32::from(bb(0.7613_f32))));
+
     c.bench_function("p32_sin", |c| c.iter(|| bb(Z).sin()));
     c.bench_function("p32_sin2", |c| c.iter(|| bb(Y).sin()));
     c.bench_function("p32_cos", |c| c.iter(|| bb(Z).cos()));
@@ -60,6 +63,9 @@ fn criterion_p16(c: &mut Criterion) {
     c.bench_function("p16_sqrt", |c| c.iter(|| bb(X).sqrt()));
     c.bench_function("p16_round", |c| c.iter(|| bb(Y).round()));
 
+    c.bench_function("p16_from_f64", |c| c.iter(|| P16::from(bb(117.334_f64))));
+    c.bench_function("p16_from_f32", |c| c.iter(|| P16::from(bb(0.7613_f32))));
+
     c.bench_function("p16_exp", |c| c.iter(|| bb(X).exp()));
     c.bench_function("p16_exp2", |c| c.iter(|| bb(X).exp2()));
     c.bench_function("p16_ln", |c| c.iter(|| bb(X).ln()));
@@ -2,69 +2,332 @@ use crate::WithSign;
 #[cfg(feature = "nightly")]
 use crate::{PxE1, PxE2};
 use crate::{P16E1, P32E2, P8E0};
-use core::convert::From;
-
-fn check_extra_p32_two_bits(
-    mut float: f64,
-    mut temp: f64,
-    bits_n_plus_one: &mut bool,
-    bits_more: &mut bool,
-) {
-    temp /= 2.;
-    if temp <= float {
-        *bits_n_plus_one = true;
-        float -= temp;
+#[cfg(feature = "alga")]
+use crate::{P16E2, P64E2, P8E2};
+use core::convert::{From, TryFrom};
+use core::fmt;
+
+/// Unpacks the bits of an `n`-bit posit with `es` exponent bits.
+///
+/// Returns the sign, the scale (power of two of the hidden bit) and the
+/// fraction with the hidden bit placed at bit 63. Zero and NaR must be
+/// handled by the caller.
+pub(crate) fn decode_posit(bits: u64, n: u32, es: u32) -> (bool, i32, u64) {
+    let mask = u64::MAX >> (64 - n);
+    let mut body = bits & mask;
+    let sign = (body >> (n - 1)) != 0;
+    if sign {
+        body = body.wrapping_neg() & mask;
     }
-    if float > 0. {
-        *bits_more = true;
+    let (scale, frac) = decode_body(body << (65 - n), es);
+    (sign, scale, frac)
+}
+
+/// Scale and fraction, as returned by `decode_posit`, of a positive posit
+/// whose bits after the sign are placed at the top of `x`.
+pub(crate) fn decode_body(x: u64, es: u32) -> (i32, u64) {
+    let (k, run) = if (x >> 63) != 0 {
+        let run = (!x).leading_zeros();
+        (run as i32 - 1, run)
+    } else {
+        let run = x.leading_zeros();
+        (-(run as i32), run)
+    };
+    let rest = x.checked_shl(run + 1).unwrap_or(0);
+    let exp = rest.checked_shr(64 - es).unwrap_or(0) as i32;
+    let frac = (1 << 63) | (rest.checked_shl(es).unwrap_or(0) >> 1);
+    ((k << es) + exp, frac)
+}
+
+/// Rounds `(-1)^sign * frac * 2^(scale - 127)` to the nearest `n`-bit posit
+/// with `es` exponent bits and returns its bits.
+///
+/// `frac` must have the hidden bit set at bit 127; `sticky` marks nonzero
+/// bits beyond `frac`. Rounding is to nearest, ties to even, on the posit
+/// bit string, and never produces zero or NaR.
+pub(crate) fn encode_posit(
+    sign: bool,
+    scale: i32,
+    frac: u128,
+    mut sticky: bool,
+    n: u32,
+    es: u32,
+) -> u64 {
+    let m = n - 1;
+    let maxpos = u64::MAX >> (65 - n);
+    let k = scale >> es;
+    let body = if k > (n as i32) - 3 {
+        maxpos
+    } else if k < -((n as i32) - 2) {
+        1
+    } else {
+        let exp = (scale - (k << es)) as u128;
+        let (regime, len) = if k >= 0 {
+            (((1_u128 << (k + 1)) - 1) << 1, (k + 2) as u32)
+        } else {
+            (1, (1 - k) as u32)
+        };
+        let mut x = regime << (128 - len);
+        if es > 0 {
+            x |= exp << (128 - len - es);
+        }
+        let f = frac << 1;
+        let shift = len + es;
+        x |= f >> shift;
+        sticky |= (f << (128 - shift)) != 0;
+
+        let mut body = (x >> (128 - m)) as u64;
+        let rest = x << m;
+        let bit_n_plus_one = (rest >> 127) != 0;
+        let bits_more = sticky || (rest << 1) != 0;
+        if bit_n_plus_one && (bits_more || (body & 1) != 0) {
+            body += 1;
+        }
+        body.max(1).min(maxpos)
+    };
+    let mask = u64::MAX >> (64 - n);
+    if sign {
+        body.wrapping_neg() & mask
+    } else {
+        body
     }
 }
 
-pub(crate) fn convert_fraction_p32(
-    mut float: f64,
-    mut frac_length: u16,
-    bits_n_plus_one: &mut bool,
-    bits_more: &mut bool,
-) -> u32 {
-    let mut frac = 0_u32;
+/// Rounds the bits of an `n`-bit posit with `es` exponent bits to `m` bits
+/// with `e` exponent bits.
+pub(crate) fn convert_posit_bits(bits: u64, n: u32, es: u32, m: u32, e: u32) -> u64 {
+    let bits = bits & (u64::MAX >> (64 - n));
+    if bits == 0 {
+        0
+    } else if bits == 1 << (n - 1) {
+        1 << (m - 1)
+    } else {
+        let (sign, scale, frac) = decode_posit(bits, n, es);
+        encode_posit(sign, scale, (frac as u128) << 64, false, m, e)
+    }
+}
 
-    if float == 0. {
+/// Rounds a double to an `n`-bit posit with `es` exponent bits.
+///
+/// Normal numbers are rounded straight from their IEEE bit pattern: the
+/// regime, the exponent bits and the 52 fraction bits are laid out in a
+/// single word and rounded to nearest even on the bits shifted out.
+#[inline]
+pub(crate) fn f64_to_posit_bits(float: f64, n: u32, es: u32) -> u64 {
+    let bits = float.to_bits();
+    let sign = (bits >> 63) != 0;
+    let abs = bits & 0x7FFF_FFFF_FFFF_FFFF;
+    let exp = (abs >> 52) as i32;
+    if abs == 0 {
         return 0;
-    } else if float == core::f64::INFINITY {
-        return 0x8000_0000;
+    } else if exp == 0x7FF {
+        return 1 << (n - 1);
+    } else if exp == 0 || es > 11 {
+        return f64_to_posit_bits_slow(sign, abs, n, es);
     }
 
-    float -= 1.; //remove hidden bit
-    if frac_length == 0 {
-        check_extra_p32_two_bits(float, 1.0, bits_n_plus_one, bits_more);
+    let scale = exp - 1023;
+    let k = scale >> es;
+    let body = if k > (n as i32) - 3 {
+        u64::MAX >> (65 - n)
+    } else if k < -((n as i32) - 2) {
+        1
     } else {
-        let mut temp = 1_f64;
-        loop {
-            temp /= 2.;
-            if temp <= float {
-                float -= temp;
-                frac_length -= 1;
-                frac = (frac << 1) + 1; //shift in one
-                if float == 0. {
-                    frac <<= frac_length as u16;
-                    break;
-                }
+        // the regime bits from bit 63 down, `len` of them
+        let (regime, len) = if k >= 0 {
+            (!(u64::MAX >> (k + 1)), (k + 2) as u32)
+        } else {
+            (1 << (63 + k), (1 - k) as u32)
+        };
+        // the exponent bits followed by the fraction bits, left-aligned
+        let e = (scale & ((1 << es) - 1)) as u64;
+        let tail = (e << 52 | (abs & 0x000F_FFFF_FFFF_FFFF)) << (12 - es);
+        let x = regime | (tail >> len);
+        let sticky = (tail << (64 - len)) != 0 || (x & ((1 << (64 - n)) - 1)) != 0;
+        let body = x >> (65 - n);
+        let bit_n_plus_one = (x >> (64 - n)) & 1;
+        // rounding up never carries past maxpos, whose regime is excluded
+        body + (bit_n_plus_one & (sticky as u64 | body))
+    };
+    if sign {
+        body.wrapping_neg() & (u64::MAX >> (64 - n))
+    } else {
+        body
+    }
+}
 
-                if frac_length == 0 {
-                    check_extra_p32_two_bits(float, temp, bits_n_plus_one, bits_more);
-                    break;
-                }
-            } else {
-                frac <<= 1; //shift in a zero
-                frac_length -= 1;
-                if frac_length == 0 {
-                    check_extra_p32_two_bits(float, temp, bits_n_plus_one, bits_more);
-                    break;
-                }
+/// Rounds the subnormal doubles, and any double to posits with exponents
+/// too wide for the fast path of `f64_to_posit_bits`.
+#[cold]
+fn f64_to_posit_bits_slow(sign: bool, abs: u64, n: u32, es: u32) -> u64 {
+    let exp = (abs >> 52) as i32;
+    let mant = abs & 0x000F_FFFF_FFFF_FFFF;
+    let (scale, frac) = if exp == 0 {
+        let lz = mant.leading_zeros();
+        (-1074 + 63 - lz as i32, mant << lz)
+    } else {
+        (exp - 1023, (mant | 0x0010_0000_0000_0000) << 11)
+    };
+    encode_posit(sign, scale, (frac as u128) << 64, false, n, es)
+}
+
+/// Rounds an `n`-bit posit with `es` exponent bits to the bits of a binary
+/// floating-point number with `mant` fraction bits and maximum exponent
+/// `emax`.
+///
+/// NaR becomes a quiet NaN, and numbers out of range overflow to infinity or
+/// underflow to zero.
+pub(crate) fn posit_to_ieee_bits(bits: u64, n: u32, es: u32, mant: u32, emax: i32) -> u64 {
+    let bits = bits & (u64::MAX >> (64 - n));
+    let inf = ((2 * emax + 1) as u64) << mant;
+    if bits == 0 {
+        return 0;
+    } else if bits == 1 << (n - 1) {
+        return inf | (1 << (mant - 1));
+    }
+    let (sign, scale, frac) = decode_posit(bits, n, es);
+    let sign = (sign as u64) << (mant + (2 * emax + 1).count_ones());
+    let emin = 1 - emax;
+    if scale > emax {
+        return sign | inf;
+    }
+    // Normal numbers keep `mant + 1` bits of the fraction, subnormals less.
+    let (biased, shift) = if scale >= emin {
+        ((scale - emin) as u64, 63 - mant)
+    } else {
+        (0, 63 - mant + (emin - scale) as u32)
+    };
+    if shift > 64 {
+        return sign;
+    }
+    let rem = frac & (u64::MAX >> (64 - shift));
+    let half = 1 << (shift - 1);
+    // The hidden bit of normal numbers carries into the exponent field.
+    let mut bits = (biased << mant) + frac.checked_shr(shift).unwrap_or(0);
+    if rem > half || (rem == half && (bits & 1) != 0) {
+        bits += 1;
+    }
+    sign | bits
+}
+
+/// Rounds the integer `(-1)^sign * a` to an `n`-bit posit with `es` exponent
+/// bits.
+pub(crate) fn u128_to_posit_bits(sign: bool, a: u128, n: u32, es: u32) -> u64 {
+    if a == 0 {
+        return 0;
+    }
+    let lz = a.leading_zeros();
+    encode_posit(sign, 127 - lz as i32, a << lz, false, n, es)
+}
+
+/// An error which can be returned when converting a posit to an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PositConversionError {
+    /// The posit is NaR.
+    NaR,
+    /// The rounded posit is beyond the range of the integer type.
+    Overflow,
+    /// The rounded posit is negative and the integer type is unsigned.
+    Negative,
+}
+
+impl fmt::Display for PositConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            PositConversionError::NaR => "cannot convert NaR to an integer",
+            PositConversionError::Overflow => "posit out of range of the integer type",
+            PositConversionError::Negative => {
+                "cannot convert a negative posit to an unsigned integer"
             }
-        }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PositConversionError {}
+
+/// Rounding direction of the conversions of posits to integers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum IntRounding {
+    /// To nearest, ties to even.
+    Nearest,
+    /// Toward negative infinity.
+    Floor,
+    /// Toward positive infinity.
+    Ceil,
+    /// Toward zero.
+    Trunc,
+}
+
+/// Rounds an `n`-bit posit with `es` exponent bits to an integer.
+///
+/// Returns the sign and the magnitude, or an error for NaR and for
+/// magnitudes beyond the range of `u128`.
+pub(crate) fn posit_bits_to_u128(
+    bits: u64,
+    n: u32,
+    es: u32,
+    rounding: IntRounding,
+) -> Result<(bool, u128), PositConversionError> {
+    let bits = bits & (u64::MAX >> (64 - n));
+    if bits == 0 {
+        return Ok((false, 0));
+    } else if bits == 1 << (n - 1) {
+        return Err(PositConversionError::NaR);
+    }
+    let (sign, scale, frac) = decode_posit(bits, n, es);
+    if scale >= 128 {
+        return Err(PositConversionError::Overflow);
+    } else if scale >= 63 {
+        return Ok((sign, (frac as u128) << (scale - 63)));
+    }
+    // `int` and the bits of `frac` beyond it, compared to one half
+    let (int, rem, half) = if scale < 0 {
+        // below one, and below one half unless its scale is -1
+        (0, if scale == -1 { frac as u128 } else { 1 }, 1 << 63)
+    } else {
+        let shift = (63 - scale) as u32;
+        let frac = frac as u128;
+        let half = 1 << (shift - 1);
+        (frac >> shift, frac & ((half << 1) - 1), half)
+    };
+    let up = match rounding {
+        IntRounding::Nearest => rem > half || (rem == half && (int & 1) != 0),
+        IntRounding::Floor => sign && rem != 0,
+        IntRounding::Ceil => !sign && rem != 0,
+        IntRounding::Trunc => false,
+    };
+    Ok((sign, int + up as u128))
+}
+
+/// Rounds an `n`-bit posit with `es` exponent bits to an integer of type `T`.
+pub(crate) fn posit_bits_to_int<T>(
+    bits: u64,
+    n: u32,
+    es: u32,
+    rounding: IntRounding,
+) -> Result<T, PositConversionError>
+where
+    T: TryFrom<u128> + TryFrom<i128>,
+{
+    let (sign, int) = posit_bits_to_u128(bits, n, es, rounding)?;
+    sign_magnitude_to_int(sign, int)
+}
+
+/// Converts the integer `±int` to type `T`.
+pub(crate) fn sign_magnitude_to_int<T>(sign: bool, int: u128) -> Result<T, PositConversionError>
+where
+    T: TryFrom<u128> + TryFrom<i128>,
+{
+    if !sign || int == 0 {
+        T::try_from(int).map_err(|_| PositConversionError::Overflow)
+    } else if T::try_from(-1_i128).is_err() {
+        Err(PositConversionError::Negative)
+    } else if int > 1 << 127 {
+        Err(PositConversionError::Overflow)
+    } else {
+        T::try_from((int as i128).wrapping_neg()).map_err(|_| PositConversionError::Overflow)
     }
-    frac
 }
 
 impl From<P8E0> for P16E1 {
@@ -1103,22 +1366,117 @@ impl<const N: u32> From<PxE1<{ N }>> for P8E0 {
 
 #[cfg(feature = "alga")]
 crate::impl_subset_into!(
-    u8 as P8E0, P16E1, P32E2;
-    u16 as P8E0, P16E1, P32E2;
-    u32 as P8E0, P16E1, P32E2;
-    u64 as P8E0, P16E1, P32E2;
-    usize as P8E0, P16E1, P32E2;
-
-    i8 as P8E0, P16E1, P32E2;
-    i16 as P8E0, P16E1, P32E2;
-    i32 as P8E0, P16E1, P32E2;
-    i64 as P8E0, P16E1, P32E2;
-    isize as P8E0, P16E1, P32E2;
-
-    f32 as P8E0, P16E1, P32E2;
-    f64 as P8E0, P16E1, P32E2;
-
-    P8E0  as P8E0, P16E1, P32E2;
-    P16E1 as P8E0, P16E1, P32E2;
-    P32E2 as P8E0, P16E1, P32E2;
+    u8 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    u16 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    u32 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    u64 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    usize as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+
+    i8 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    i16 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    i32 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    i64 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    isize as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+
+    f32 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    f64 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+
+    P8E0  as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    P16E1 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    P32E2 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    P64E2 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    P8E2  as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
+    P16E2 as P8E0, P16E1, P32E2, P64E2, P8E2, P16E2;
 );
+
+#[test]
+fn posit_decode_encode() {
+    for i in 1..0x_0001_0000_u64 {
+        if i == 0x_8000 {
+            continue;
+        }
+        let (sign, scale, frac) = decode_posit(i, 16, 1);
+        assert_eq!(
+            encode_posit(sign, scale, (frac as u128) << 64, false, 16, 1),
+            i
+        );
+        let p = P16E1::from_bits(i as u16);
+        let f = (frac as f64) / ((1_u64 << 63) as f64) * 2_f64.powi(scale);
+        assert_eq!(f64::from(p), if sign { -f } else { f });
+    }
+}
+
+#[test]
+fn posit_encode_f64() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..100_000 {
+        let f: f64 = f64::from_bits(rng.gen::<u64>() >> 2) * 2_f64.powi(rng.gen_range(-100, 100));
+        let bits = f.to_bits();
+        let scale = ((bits >> 52) & 0x7ff) as i32 - 1023;
+        let frac = ((bits & 0x_000f_ffff_ffff_ffff) | 0x_0010_0000_0000_0000) as u128;
+        if !f.is_normal() {
+            continue;
+        }
+        for &sign in &[false, true] {
+            let g = if sign { -f } else { f };
+            let frac = frac << 75;
+            assert_eq!(
+                encode_posit(sign, scale, frac, false, 8, 0) as u8,
+                P8E0::from(g).to_bits()
+            );
+            assert_eq!(
+                encode_posit(sign, scale, frac, false, 16, 1) as u16,
+                P16E1::from(g).to_bits()
+            );
+            assert_eq!(
+                encode_posit(sign, scale, frac, false, 32, 2) as u32,
+                P32E2::from(g).to_bits()
+            );
+        }
+    }
+}
+
+/// Checks the rounding of the `f32` values next to the posits `bodies` and
+/// to the midpoints above them, which are all the rounding boundaries of the
+/// positive posits if `bodies` covers them all: each result must lie within
+/// the midpoints around it, on the even posit at a tie.
+#[cfg(test)]
+pub(crate) fn test_f32_rounding<F, I>(n: u32, es: u32, from_f32: F, bodies: I)
+where
+    F: Fn(f32) -> u64,
+    I: Iterator<Item = u64>,
+{
+    let maxpos = u64::MAX >> (65 - n);
+    // exact value of the (n + 1)-bit posit between `body - 1` and `body`
+    let mid = |body: u64| {
+        let (_, scale, frac) = decode_posit((body << 1) - 1, n + 1, es);
+        frac as f64 * 2_f64.powi(scale - 63)
+    };
+    for body in bodies.filter(|&b| b >= 1 && b <= maxpos) {
+        let (_, scale, frac) = decode_posit(body, n, es);
+        let value = frac as f64 * 2_f64.powi(scale - 63);
+        let mut inputs = [0_f32; 6];
+        let above = mid(body + 1).min(f32::MAX as f64);
+        for (i, &x) in [value, above].iter().enumerate() {
+            let f = (x as f32).to_bits();
+            inputs[3 * i] = f32::from_bits(f - 1);
+            inputs[3 * i + 1] = f32::from_bits(f);
+            inputs[3 * i + 2] = f32::from_bits(f + 1);
+        }
+        for &f in inputs.iter().filter(|f| f.is_finite() && **f > 0.) {
+            let p = from_f32(f);
+            assert!(p >= 1 && p <= maxpos, "{:e}", f);
+            let x = f as f64;
+            if p > 1 {
+                let lo = mid(p);
+                assert!(x > lo || (x == lo && (p & 1) == 0), "{:e} {:x}", f, p);
+            }
+            if p < maxpos {
+                let hi = mid(p + 1);
+                assert!(x < hi || (x == hi && (p & 1) == 0), "{:e} {:x}", f, p);
+            }
+            assert_eq!(from_f32(-f), p.wrapping_neg() & (u64::MAX >> (64 - n)));
+        }
+    }
+}
@@ -0,0 +1,550 @@
+//! Double-double arithmetic: unevaluated sums `hi + lo` of two doubles with
+//! about 106 significant bits, used to evaluate elementary functions
+//! accurately enough to round them correctly.
+//!
+//! The functions below have a relative error of about 2^-97, while among the
+//! 2^32 arguments of a 32-bit posit function the closest result to a rounding
+//! boundary is expected around 2^-60 away, unless it lies exactly on one.
+
+use crate::convert::{encode_posit, f64_to_posit_bits};
+use crate::xfloat::add_unpacked;
+use core::ops;
+
+#[cfg(test)]
+pub(crate) mod reference;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct DD {
+    pub hi: f64,
+    pub lo: f64,
+}
+
+/// ln(2)
+pub(crate) const LN2: DD = DD::new(core::f64::consts::LN_2, 2.319_046_813_846_299_6e-17);
+/// ln(10)
+pub(crate) const LN10: DD = DD::new(core::f64::consts::LN_10, -2.170_756_223_382_249_4e-16);
+/// log2(e)
+pub(crate) const LOG2_E: DD = DD::new(core::f64::consts::LOG2_E, 2.035_527_374_093_103_3e-17);
+/// π/2
+pub(crate) const FRAC_PI_2: DD = DD::new(core::f64::consts::FRAC_PI_2, 6.123_233_995_736_766e-17);
+/// π
+pub(crate) const PI: DD = DD::new(core::f64::consts::PI, 1.224_646_799_147_353_2e-16);
+
+/// Bits of 2/π, most significant first, enough to reduce any double below
+/// 2^400.
+const TWO_OVER_PI: [u64; 10] = [
+    0xA2F9_836E_4E44_1529,
+    0xFC27_57D1_F534_DDC0,
+    0xDB62_9599_3C43_9041,
+    0xFE51_63AB_DEBB_C561,
+    0xB724_6E3A_424D_D2E0,
+    0x0649_2EEA_09D1_921C,
+    0xFE1D_EB1C_B129_A73E,
+    0xE882_35F5_2EBB_4484,
+    0xE99C_7026_B45F_7E41,
+    0x3991_D639_8353_39F4,
+];
+
+/// Exact sum of two doubles.
+#[inline]
+fn two_sum(a: f64, b: f64) -> DD {
+    let s = a + b;
+    let bb = s - a;
+    DD::new(s, (a - (s - bb)) + (b - bb))
+}
+
+/// Exact sum of two doubles with `|a| >= |b|`.
+#[inline]
+fn quick_two_sum(a: f64, b: f64) -> DD {
+    let s = a + b;
+    DD::new(s, b - (s - a))
+}
+
+/// Splits a double into two halves of 26 significant bits.
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+    let t = 134_217_729. * a; // 2^27 + 1
+    let hi = t - (t - a);
+    (hi, a - hi)
+}
+
+/// Exact product of two doubles.
+#[inline]
+fn two_prod(a: f64, b: f64) -> DD {
+    let p = a * b;
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    DD::new(p, ((ah * bh - p) + ah * bl + al * bh) + al * bl)
+}
+
+/// Exact `2^k` for `-1022 <= k <= 1023`.
+#[inline]
+fn pow2(k: i32) -> f64 {
+    f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+impl DD {
+    pub const ONE: Self = Self::new(1., 0.);
+
+    #[inline]
+    pub const fn new(hi: f64, lo: f64) -> Self {
+        Self { hi, lo }
+    }
+
+    /// Exact value of an integer.
+    #[inline]
+    fn from_u64(a: u64) -> Self {
+        two_sum(((a >> 11) << 11) as f64, (a & 0x7FF) as f64)
+    }
+
+    /// Multiplies by `2^k`, exactly unless the low part underflows.
+    #[inline]
+    pub fn scale(self, k: i32) -> Self {
+        let s = pow2(k);
+        Self::new(self.hi * s, self.lo * s)
+    }
+
+    #[inline]
+    pub fn mul_f64(self, b: f64) -> Self {
+        let p = two_prod(self.hi, b);
+        quick_two_sum(p.hi, p.lo + self.lo * b)
+    }
+
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        if self.hi <= 0. {
+            return Self::new(0., 0.);
+        }
+        let s = libm::sqrt(self.hi);
+        let r = self - two_prod(s, s);
+        quick_two_sum(s, r.hi * 0.5 / s)
+    }
+
+    /// Rounds to an `n`-bit posit with `es` exponent bits.
+    pub fn to_posit_bits(self, n: u32, es: u32) -> u64 {
+        let unpack = |f: f64| {
+            let bits = f.to_bits();
+            let exp = ((bits >> 52) & 0x7FF) as i32;
+            let frac = (bits & 0x000F_FFFF_FFFF_FFFF) | 0x0010_0000_0000_0000;
+            // the hidden bit at bit 125, as `add_unpacked` expects
+            ((bits >> 63) != 0, exp - 1023, (frac as u128) << 73)
+        };
+        if self.hi == 0. {
+            return 0;
+        } else if self.lo == 0. || !self.hi.is_normal() || !self.lo.is_normal() {
+            return f64_to_posit_bits(self.hi, n, es);
+        }
+        match add_unpacked(unpack(self.hi), unpack(self.lo)) {
+            Some((sign, scale, frac, sticky)) => encode_posit(sign, scale, frac, sticky, n, es),
+            None => 0,
+        }
+    }
+}
+
+impl From<f64> for DD {
+    #[inline]
+    fn from(a: f64) -> Self {
+        Self::new(a, 0.)
+    }
+}
+
+impl ops::Neg for DD {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.hi, -self.lo)
+    }
+}
+
+impl ops::Add for DD {
+    type Output = Self;
+    #[inline]
+    fn add(self, b: Self) -> Self {
+        let s = two_sum(self.hi, b.hi);
+        let t = two_sum(self.lo, b.lo);
+        let s = quick_two_sum(s.hi, s.lo + t.hi);
+        quick_two_sum(s.hi, s.lo + t.lo)
+    }
+}
+
+impl ops::Sub for DD {
+    type Output = Self;
+    #[inline]
+    fn sub(self, b: Self) -> Self {
+        self + (-b)
+    }
+}
+
+impl ops::Mul for DD {
+    type Output = Self;
+    #[inline]
+    fn mul(self, b: Self) -> Self {
+        let p = two_prod(self.hi, b.hi);
+        quick_two_sum(p.hi, p.lo + (self.hi * b.lo + self.lo * b.hi))
+    }
+}
+
+impl ops::Div for DD {
+    type Output = Self;
+    #[inline]
+    fn div(self, b: Self) -> Self {
+        let q1 = self.hi / b.hi;
+        let r = self - b.mul_f64(q1);
+        let q2 = r.hi / b.hi;
+        let r = r - b.mul_f64(q2);
+        let q3 = r.hi / b.hi;
+        quick_two_sum(q1, q2) + Self::from(q3)
+    }
+}
+
+/// Rounds `f(x)` to an `n`-bit posit with `es` exponent bits, from `approx`,
+/// the double result of a libm function accurate to a few ulps, or if that
+/// is too close to a rounding boundary, from `accurate()` evaluated in
+/// double-double.
+pub(crate) fn round_correctly<F>(approx: f64, accurate: F, n: u32, es: u32) -> u64
+where
+    F: FnOnce() -> DD,
+{
+    let margin = approx * pow2(-50);
+    let lo = f64_to_posit_bits(approx - margin, n, es);
+    if lo == f64_to_posit_bits(approx + margin, n, es) {
+        lo
+    } else {
+        accurate().to_posit_bits(n, es)
+    }
+}
+
+/// Splits `e^x` into `(k, e^r - 1)` with `x = k ln(2) + r`, for `|x| < 700`.
+fn exp_parts(x: DD) -> (i32, DD) {
+    let k = libm::round(x.hi * LOG2_E.hi);
+    // |r| <= ln(2) / 2, divided by 2^6
+    let r = (x - LN2.mul_f64(k)).scale(-6);
+    let mut term = r;
+    let mut sum = r;
+    for i in 2..14 {
+        term = (term * r) / DD::from(i as f64);
+        sum = sum + term;
+    }
+    // e^(2r) - 1 = (e^r - 1) (e^r - 1 + 2)
+    for _ in 0..6 {
+        sum = sum * (sum + DD::from(2.));
+    }
+    (k as i32, sum)
+}
+
+/// `e^x`, for `|x| < 700`.
+pub(crate) fn exp(x: DD) -> DD {
+    let (k, sum) = exp_parts(x);
+    (sum + DD::ONE).scale(k)
+}
+
+/// `e^x - 1`, for `|x| < 700`, accurate also for `x` close to zero.
+pub(crate) fn exp_m1(x: DD) -> DD {
+    match exp_parts(x) {
+        (0, sum) => sum,
+        // |e^x - 1| > 0.29, no bits cancel
+        (k, sum) => (sum + DD::ONE).scale(k) - DD::ONE,
+    }
+}
+
+/// `atanh(s)`, for `|s| < 0.172`.
+fn atanh(s: DD) -> DD {
+    let s2 = s * s;
+    let mut term = s;
+    let mut sum = s;
+    for k in 1..24 {
+        term = term * s2;
+        sum = sum + term / DD::from((2 * k + 1) as f64);
+    }
+    sum
+}
+
+/// Splits a positive normal double into `2^e * m` with `m` in
+/// `[sqrt(1/2), sqrt(2))` and returns `(e, ln(m))`.
+pub(crate) fn ln_parts(x: f64) -> (i32, DD) {
+    let bits = x.to_bits();
+    let mut e = ((bits >> 52) & 0x7FF) as i32 - 1022;
+    let mut m = f64::from_bits((bits & 0x000F_FFFF_FFFF_FFFF) | (1022 << 52));
+    if m < core::f64::consts::FRAC_1_SQRT_2 {
+        m *= 2.;
+        e -= 1;
+    }
+    // ln(m) = 2 atanh(s) with s = (m - 1) / (m + 1) and |s| < 0.172
+    let s = two_sum(m, -1.) / two_sum(m, 1.);
+    (e, atanh(s).scale(1))
+}
+
+/// Natural logarithm of a positive normal double.
+pub(crate) fn ln(x: f64) -> DD {
+    let (e, ln_m) = ln_parts(x);
+    LN2.mul_f64(e as f64) + ln_m
+}
+
+/// `ln(1 + x)` of a double greater than -1, accurate also for `x` close to
+/// zero.
+pub(crate) fn ln_1p(x: f64) -> DD {
+    if -0.25 < x && x < 0.375 {
+        // ln(1 + x) = 2 atanh(s) with s = x / (2 + x) and |s| < 0.16
+        atanh(DD::from(x) / two_sum(2., x)).scale(1)
+    } else {
+        // 1 + x = u.hi (1 + u.lo / u.hi) exactly, and |u.lo / u.hi| <= 2^-53
+        let u = two_sum(1., x);
+        ln(u.hi) + DD::from(u.lo / u.hi)
+    }
+}
+
+/// `log10(x)` rounded to an `n`-bit posit with `es` exponent bits, NaR for
+/// zero, negative numbers and NaN.
+pub(crate) fn log10(x: f64, n: u32, es: u32) -> u64 {
+    if x.is_nan() || x <= 0. {
+        return 1 << (n - 1);
+    }
+    round_correctly(libm::log10(x), || ln(x) / LN10, n, es)
+}
+
+/// `log(x) / log(base)` rounded to an `n`-bit posit with `es` exponent bits,
+/// NaR if either argument is zero, negative or NaN, or if the base is one.
+///
+/// The only rational results are quotients of small integers, far from the
+/// rounding boundaries, so that the double-double quotient rounds correctly.
+pub(crate) fn log(x: f64, base: f64, n: u32, es: u32) -> u64 {
+    if x.is_nan() || base.is_nan() || x <= 0. || base <= 0. || base == 1. {
+        return 1 << (n - 1);
+    }
+    let approx = libm::log(x) / libm::log(base);
+    round_correctly(approx, || ln(x) / ln(base), n, es)
+}
+
+/// Reduces a finite double to `x = q * π/2 + r` with `|r| <= π/4`, and
+/// returns `(q mod 4, r)`.
+///
+/// Large arguments are multiplied by 2/π exactly in integer arithmetic
+/// (Payne-Hanek), so that no bits cancel.
+pub(crate) fn rem_frac_pi_2(x: f64) -> (u32, DD) {
+    if x.abs() <= core::f64::consts::FRAC_PI_4 {
+        return (0, DD::from(x));
+    }
+    let bits = x.to_bits();
+    let neg = (bits >> 63) != 0;
+    // x = m * 2^e
+    let m = (bits & 0x000F_FFFF_FFFF_FFFF) | 0x0010_0000_0000_0000;
+    let e = ((bits >> 52) & 0x7FF) as i32 - 1075;
+    // The words of 2/π before `first` contribute multiples of 4.
+    let first = if e > 2 { ((e - 2) / 64) as usize } else { 0 };
+    debug_assert!(first + 5 <= TWO_OVER_PI.len());
+    // m times the five words from `first`, least significant word first
+    let mut prod = [0_u64; 6];
+    let mut carry = 0_u128;
+    for i in 0..5 {
+        let p = (m as u128) * (TWO_OVER_PI[first + 4 - i] as u128) + carry;
+        prod[i] = p as u64;
+        carry = p >> 64;
+    }
+    prod[5] = carry as u64;
+    // the product has `point` bits after the binary point
+    let point = 64 * (first as i32 + 5) - e;
+    let bits_at = |lsb: i32| -> u64 {
+        // the 64 bits from `lsb` up
+        let word = |i: i32| {
+            if !(0..6).contains(&i) {
+                0
+            } else {
+                prod[i as usize] as u128
+            }
+        };
+        let (i, s) = (lsb.div_euclid(64), lsb.rem_euclid(64) as u32);
+        (((word(i + 1) << 64 | word(i)) >> s) & 0xFFFF_FFFF_FFFF_FFFF) as u64
+    };
+    let mut q = (bits_at(point) & 3) as u32;
+    let mut frac = [
+        bits_at(point - 64),
+        bits_at(point - 128),
+        bits_at(point - 192),
+        bits_at(point - 256),
+    ];
+    // round the quotient to nearest, leaving a fraction in [-1/2, 1/2]
+    let round_up = (frac[0] >> 63) != 0;
+    if round_up {
+        q += 1;
+        // negate the fraction
+        let mut carry = true;
+        for f in frac.iter_mut().rev() {
+            let (z, c) = (!*f).overflowing_add(carry as u64);
+            *f = z;
+            carry = c;
+        }
+    }
+    let mut r = DD::from(0.);
+    for (i, &f) in frac.iter().enumerate() {
+        r = r + DD::from_u64(f).scale(-64 * (i as i32 + 1));
+    }
+    let r = r * FRAC_PI_2;
+    let r = if round_up != neg { -r } else { r };
+    let q = if neg { 4 - q } else { q };
+    (q & 3, r)
+}
+
+/// Sine and cosine of `|r| <= π/4`.
+fn sin_cos_kernel(r: DD) -> (DD, DD) {
+    let r2 = r * r;
+    let (mut s, mut c) = (r, DD::ONE);
+    let (mut ts, mut tc) = (r, DD::ONE);
+    for k in 1..16 {
+        let k = 2 * k;
+        tc = -(tc * r2) / DD::from((k * (k - 1)) as f64);
+        ts = -(ts * r2) / DD::from((k * (k + 1)) as f64);
+        c = c + tc;
+        s = s + ts;
+    }
+    (s, c)
+}
+
+/// Sine and cosine of a finite double.
+pub(crate) fn sin_cos(x: f64) -> (DD, DD) {
+    let (q, r) = rem_frac_pi_2(x);
+    let (s, c) = sin_cos_kernel(r);
+    match q {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Reduces a finite double to `x = q/2 + t` with `|t| <= 1/4`, and returns
+/// `(q mod 4, t)`. Every step is exact.
+fn rem_half(x: f64) -> (u32, f64) {
+    let r = libm::fmod(x, 2.);
+    let q = libm::round(2. * r);
+    ((q as i32).rem_euclid(4) as u32, r - 0.5 * q)
+}
+
+/// Sine and cosine of `π x` for a finite double.
+pub(crate) fn sin_cos_pi(x: f64) -> (DD, DD) {
+    let (q, t) = rem_half(x);
+    let (s, c) = sin_cos_kernel(PI.mul_f64(t));
+    match q {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Arc tangent.
+pub(crate) fn atan(x: DD) -> DD {
+    let neg = x.hi < 0.;
+    let a = if neg { -x } else { x };
+    let inv = a.hi > 1.;
+    let mut t = if inv { DD::ONE / a } else { a };
+    // atan(t) = 2 atan(t / (1 + sqrt(1 + t^2))), down to |t| < 0.1
+    for _ in 0..3 {
+        t = t / (DD::ONE + (DD::ONE + t * t).sqrt());
+    }
+    let t2 = t * t;
+    let mut term = t;
+    let mut sum = t;
+    for k in 1..18 {
+        term = -(term * t2);
+        sum = sum + term / DD::from((2 * k + 1) as f64);
+    }
+    let r = sum.scale(3);
+    let r = if inv { FRAC_PI_2 - r } else { r };
+    if neg {
+        -r
+    } else {
+        r
+    }
+}
+
+/// Arc sine of `|x| <= 1`.
+pub(crate) fn asin(x: f64) -> DD {
+    if x.abs() == 1. {
+        return FRAC_PI_2.mul_f64(x);
+    }
+    let d = (two_sum(1., -x) * two_sum(1., x)).sqrt();
+    atan(DD::from(x) / d)
+}
+
+/// Arc cosine of `|x| <= 1`.
+pub(crate) fn acos(x: f64) -> DD {
+    if x == -1. {
+        return PI;
+    }
+    atan((two_sum(1., -x) / two_sum(1., x)).sqrt()).scale(1)
+}
+
+/// `sin(π (q/2 + t))` in double precision, for `|t| <= 1/4`.
+fn sin_pi_approx(q: u32, t: f64) -> f64 {
+    let r = PI.hi * t;
+    match q {
+        0 => libm::sin(r),
+        1 => libm::cos(r),
+        2 => -libm::sin(r),
+        _ => -libm::cos(r),
+    }
+}
+
+/// `sin(π x)` rounded to an `n`-bit posit with `es` exponent bits.
+pub(crate) fn sin_pi(x: f64, n: u32, es: u32) -> u64 {
+    if x.is_nan() {
+        return 1 << (n - 1);
+    }
+    let (q, t) = rem_half(x);
+    round_correctly(sin_pi_approx(q, t), || sin_cos_pi(x).0, n, es)
+}
+
+/// `cos(π x)` rounded to an `n`-bit posit with `es` exponent bits.
+pub(crate) fn cos_pi(x: f64, n: u32, es: u32) -> u64 {
+    if x.is_nan() {
+        return 1 << (n - 1);
+    }
+    let (q, t) = rem_half(x);
+    round_correctly(sin_pi_approx((q + 1) & 3, t), || sin_cos_pi(x).1, n, es)
+}
+
+/// `tan(π x)` rounded to an `n`-bit posit with `es` exponent bits, NaR at
+/// the poles `x = k + 1/2`.
+pub(crate) fn tan_pi(x: f64, n: u32, es: u32) -> u64 {
+    let (q, t) = rem_half(x);
+    if x.is_nan() || (q & 1 == 1 && t == 0.) {
+        return 1 << (n - 1);
+    }
+    let tan = libm::tan(PI.hi * t);
+    let approx = if q & 1 == 0 { tan } else { -1. / tan };
+    round_correctly(
+        approx,
+        || {
+            let (s, c) = sin_cos_pi(x);
+            s / c
+        },
+        n,
+        es,
+    )
+}
+
+/// `asin(x) / π` rounded to an `n`-bit posit with `es` exponent bits, NaR
+/// outside `[-1, 1]`.
+pub(crate) fn asin_pi(x: f64, n: u32, es: u32) -> u64 {
+    if x.is_nan() || x.abs() > 1. {
+        return 1 << (n - 1);
+    }
+    round_correctly(libm::asin(x) / PI.hi, || asin(x) / PI, n, es)
+}
+
+/// `acos(x) / π` rounded to an `n`-bit posit with `es` exponent bits, NaR
+/// outside `[-1, 1]`.
+pub(crate) fn acos_pi(x: f64, n: u32, es: u32) -> u64 {
+    if x.is_nan() || x.abs() > 1. {
+        return 1 << (n - 1);
+    }
+    round_correctly(libm::acos(x) / PI.hi, || acos(x) / PI, n, es)
+}
+
+/// `atan(x) / π` rounded to an `n`-bit posit with `es` exponent bits.
+pub(crate) fn atan_pi(x: f64, n: u32, es: u32) -> u64 {
+    if x.is_nan() {
+        return 1 << (n - 1);
+    }
+    round_correctly(libm::atan(x) / PI.hi, || atan(DD::from(x)) / PI, n, es)
+}
@@ -0,0 +1,250 @@
+//! Arbitrary precision decimal numbers with exact binary shifts, which
+//! convert posits to and from decimal strings.
+
+/// Number of significant digits kept. Every value of a 2048-bit quire, and
+/// every rounding boundary of a posit up to 64 bits with a moderate `es`, has
+/// fewer digits, so the digits beyond matter only as a sticky bit.
+const MAX_DIGITS: usize = 1400;
+
+/// Largest shift of a `Decimal`, so that a digit shifted left fits in `u64`.
+const MAX_SHIFT: u32 = 60;
+
+/// Largest binary shifts that keep a number below one, indexed by the negated
+/// position of the decimal point: `floor(i * log2(10))`, and 1 for 0.
+const POW_TAB: [u32; 19] = [
+    1, 3, 6, 9, 13, 16, 19, 23, 26, 29, 33, 36, 39, 43, 46, 49, 53, 56, 59,
+];
+
+/// Arbitrary precision decimal number `0.d[0]d[1]... * 10^dp`, with binary
+/// shifts that are exact until `MAX_DIGITS` digits.
+pub(crate) struct Decimal {
+    /// Digits, most significant first, without leading zeros.
+    pub(crate) d: [u8; MAX_DIGITS],
+    /// Number of digits used.
+    pub(crate) nd: usize,
+    /// Position of the decimal point.
+    pub(crate) dp: i64,
+    /// Whether nonzero digits beyond `d` were dropped.
+    pub(crate) trunc: bool,
+}
+
+impl Decimal {
+    pub(crate) fn new() -> Self {
+        Self {
+            d: [0; MAX_DIGITS],
+            nd: 0,
+            dp: 0,
+            trunc: false,
+        }
+    }
+
+    /// Appends a digit, from the integer part or from the fraction.
+    pub(crate) fn push(&mut self, digit: u8, integer: bool) {
+        if self.nd == 0 && digit == 0 {
+            if !integer {
+                self.dp -= 1;
+            }
+            return;
+        }
+        if integer {
+            self.dp += 1;
+        }
+        if self.nd < MAX_DIGITS {
+            self.d[self.nd] = digit;
+            self.nd += 1;
+        } else if digit != 0 {
+            self.trunc = true;
+        }
+    }
+
+    /// Drops the trailing zeros.
+    pub(crate) fn trim(&mut self) {
+        while self.nd > 0 && self.d[self.nd - 1] == 0 {
+            self.nd -= 1;
+        }
+        if self.nd == 0 {
+            self.dp = 0;
+        }
+    }
+
+    /// Creates the decimal number of an integer.
+    pub(crate) fn from_int(mut i: u64) -> Self {
+        let mut d = Self::new();
+        let mut digits = [0_u8; 20];
+        while i > 0 {
+            digits[d.nd] = (i % 10) as u8;
+            i /= 10;
+            d.nd += 1;
+        }
+        for (d, &digit) in d.d.iter_mut().zip(digits[..d.nd].iter().rev()) {
+            *d = digit;
+        }
+        d.dp = d.nd as i64;
+        d.trim();
+        d
+    }
+
+    /// Multiplies the number by `2^k`.
+    pub(crate) fn shift(&mut self, mut k: i32) {
+        if self.nd == 0 {
+            return;
+        }
+        while k > 0 {
+            let s = (k as u32).min(MAX_SHIFT);
+            self.shift_left(s);
+            k -= s as i32;
+        }
+        while k < 0 {
+            let s = (-k as u32).min(MAX_SHIFT);
+            self.shift_right(s);
+            k += s as i32;
+        }
+    }
+
+    /// Digit `i`, zero outside of the digits used.
+    pub(crate) fn digit(&self, i: i64) -> u8 {
+        if i >= 0 && i < self.nd as i64 {
+            self.d[i as usize]
+        } else {
+            0
+        }
+    }
+
+    /// Whether dropping the digits from `nd` on should round up: they are
+    /// above half of a unit of digit `nd - 1`, or exactly half and that digit
+    /// is odd.
+    fn should_round_up(&self, nd: usize) -> bool {
+        if self.d[nd] == 5 && nd + 1 == self.nd && !self.trunc {
+            nd > 0 && (self.d[nd - 1] % 2) == 1
+        } else {
+            self.d[nd] >= 5
+        }
+    }
+
+    /// Rounds the number to `nd` digits, to nearest with ties to even.
+    pub(crate) fn round(&mut self, nd: usize) {
+        if nd >= self.nd {
+            return;
+        }
+        if self.should_round_up(nd) {
+            self.round_up(nd);
+        } else {
+            self.round_down(nd);
+        }
+    }
+
+    /// Truncates the number to `nd` digits.
+    pub(crate) fn round_down(&mut self, nd: usize) {
+        if nd >= self.nd {
+            return;
+        }
+        self.nd = nd;
+        self.trim();
+    }
+
+    /// Rounds the number up to `nd` digits.
+    pub(crate) fn round_up(&mut self, nd: usize) {
+        if nd >= self.nd {
+            return;
+        }
+        for i in (0..nd).rev() {
+            if self.d[i] < 9 {
+                self.d[i] += 1;
+                self.nd = i + 1;
+                return;
+            }
+        }
+        // all nines
+        self.d[0] = 1;
+        self.nd = 1;
+        self.dp += 1;
+    }
+
+    /// Multiplies the number by `2^k`, `k <= MAX_SHIFT`.
+    fn shift_left(&mut self, k: u32) {
+        // digits of the product, least significant first
+        let mut out = [0_u8; MAX_DIGITS + 20];
+        let mut len = 0;
+        let mut carry = 0_u64;
+        for &digit in self.d[..self.nd].iter().rev() {
+            let n = ((digit as u64) << k) + carry;
+            out[len] = (n % 10) as u8;
+            carry = n / 10;
+            len += 1;
+        }
+        while carry > 0 {
+            out[len] = (carry % 10) as u8;
+            carry /= 10;
+            len += 1;
+        }
+        self.dp += (len - self.nd) as i64;
+        let keep = len.min(MAX_DIGITS);
+        for (d, &o) in self.d.iter_mut().zip(out[..len].iter().rev()) {
+            *d = o;
+        }
+        self.trunc |= out[..len - keep].iter().any(|&o| o != 0);
+        self.nd = keep;
+        self.trim();
+    }
+
+    /// Divides the number by `2^k`, `k <= MAX_SHIFT`.
+    fn shift_right(&mut self, k: u32) {
+        let mask = (1_u64 << k) - 1;
+        let mut r = 0;
+        let mut n = 0_u64;
+        // read the digits until the quotient is nonzero
+        while (n >> k) == 0 {
+            if r < self.nd {
+                n = n * 10 + self.d[r] as u64;
+            } else {
+                n *= 10;
+            }
+            r += 1;
+        }
+        self.dp -= r as i64 - 1;
+        let mut w = 0;
+        while r < self.nd {
+            self.d[w] = (n >> k) as u8;
+            w += 1;
+            n = (n & mask) * 10 + self.d[r] as u64;
+            r += 1;
+        }
+        while n > 0 {
+            let digit = (n >> k) as u8;
+            if w < MAX_DIGITS {
+                self.d[w] = digit;
+                w += 1;
+            } else if digit > 0 {
+                self.trunc = true;
+            }
+            n = (n & mask) * 10;
+        }
+        self.nd = w;
+        self.trim();
+    }
+
+    /// Splits the nonzero number into its scale, its 64 leading bits at the
+    /// top of a `u128` and whether any bit below them is set.
+    pub(crate) fn unpack(mut self) -> (i32, u128, bool) {
+        // bring the number into [1/2, 1)
+        let mut exp = 0_i32;
+        while self.dp > 0 {
+            let k = POW_TAB.get(self.dp as usize).copied().unwrap_or(MAX_SHIFT);
+            self.shift_right(k);
+            exp += k as i32;
+        }
+        while self.dp < 0 || (self.dp == 0 && self.d[0] < 5) {
+            let k = POW_TAB.get(-self.dp as usize).copied().unwrap_or(MAX_SHIFT);
+            self.shift_left(k);
+            exp -= k as i32;
+        }
+        self.shift_left(MAX_SHIFT);
+        self.shift_left(64 - MAX_SHIFT);
+        let int_len = self.dp as usize;
+        let int = (0..int_len).fold(0_u64, |int, i| {
+            int * 10 + self.d.get(i).filter(|_| i < self.nd).copied().unwrap_or(0) as u64
+        });
+        let sticky = self.trunc || self.nd > int_len;
+        (exp - 1, (int as u128) << 64, sticky)
+    }
+}
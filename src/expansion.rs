@@ -0,0 +1,445 @@
+//! Expansions of a quire into sums of posits, and the double-posit numbers
+//! built on them.
+//!
+//! The expansion of a quire rounds it to a posit, subtracts that posit
+//! exactly and goes on with the remainder, so the terms do not overlap and
+//! their sum is the quire's value, up to what lies below `minpos`.
+
+use crate::{AssociatedQuire, Quire};
+use core::ops;
+use num_traits::Signed;
+
+/// Iterator over the posits of the expansion of a quire.
+///
+/// It ends when the remainder is zero, or when its rounding would not bring
+/// it closer to zero or would not be smaller than the previous term, which
+/// only happens near `minpos` and `maxpos`, where the posits are too far
+/// apart. A NaR quire yields a single NaR.
+#[derive(Clone, Debug)]
+pub struct IntoPosits<P, Q> {
+    quire: Option<Q>,
+    last: Option<P>,
+}
+
+impl<P, Q> IntoPosits<P, Q> {
+    #[inline]
+    pub(crate) fn new(quire: Q) -> Self {
+        Self {
+            quire: Some(quire),
+            last: None,
+        }
+    }
+}
+
+impl<P, Q> Iterator for IntoPosits<P, Q>
+where
+    P: Copy + PartialOrd + Signed,
+    Q: Quire<P> + Clone,
+{
+    type Item = P;
+    // `Option::is_none_or` needs a newer compiler
+    #[allow(clippy::unnecessary_map_or)]
+    fn next(&mut self) -> Option<P> {
+        let q = self.quire.as_mut()?;
+        if q.is_zero() {
+            self.quire = None;
+            return None;
+        }
+        let p = q.to_posit();
+        if q.is_nar() {
+            self.quire = None;
+            return Some(p);
+        }
+        // `p` is a term only if the remainder is beyond `p / 2`
+        let half = P::one() / (P::one() + P::one());
+        let mut t = q.clone();
+        t.sub_product(p, half);
+        let t = t.to_posit();
+        let smaller = self.last.map_or(true, |last| p.abs() < last.abs());
+        if t.is_zero() || t.is_negative() != p.is_negative() || !smaller {
+            self.quire = None;
+            return None;
+        }
+        q.sub_product(p, P::one());
+        self.last = Some(p);
+        Some(p)
+    }
+}
+
+/// The first `K` posits of the expansion of `quire`, padded with zeros.
+pub(crate) fn to_posits<P, Q, const K: usize>(quire: Q) -> [P; K]
+where
+    P: Copy + PartialOrd + Signed,
+    Q: Quire<P> + Clone,
+{
+    let mut posits = [P::zero(); K];
+    for (p, term) in posits.iter_mut().zip(IntoPosits::new(quire)) {
+        *p = term;
+    }
+    posits
+}
+
+/// The exact sum of `posits`.
+pub(crate) fn from_posits<P, Q, I>(posits: I) -> Q
+where
+    P: num_traits::One,
+    Q: Quire<P>,
+    I: IntoIterator<Item = P>,
+{
+    let mut q = Q::init();
+    for p in posits {
+        q.add_product(p, P::one());
+    }
+    q
+}
+
+/// Number stored as the unevaluated sum of two posits, the first two terms
+/// of the expansion of its value, with about twice the precision of `P`.
+///
+/// Every operation is done exactly in the quire of `P` and then split into
+/// two posits again. It converts to and from `[P; 2]`, so it can be stored
+/// in arrays of posits.
+#[derive(Clone, Copy, Debug)]
+pub struct DoublePosit<P> {
+    hi: P,
+    lo: P,
+}
+
+impl<P> DoublePosit<P>
+where
+    P: Copy + PartialOrd + Signed + AssociatedQuire<P>,
+    P::Q: Clone,
+{
+    /// The leading posit, which is the value rounded to `P`.
+    #[inline]
+    pub fn hi(self) -> P {
+        self.hi
+    }
+
+    /// The rounded remainder of `hi`.
+    #[inline]
+    pub fn lo(self) -> P {
+        self.lo
+    }
+
+    #[inline]
+    pub fn is_nar(self) -> bool {
+        P::Q::from_posit(self.hi).is_nar()
+    }
+
+    /// Rounds a quire to the first two terms of its expansion.
+    #[inline]
+    pub fn from_quire(q: P::Q) -> Self {
+        let [hi, lo] = to_posits(q);
+        Self { hi, lo }
+    }
+
+    /// The exact value of `hi + lo`.
+    #[inline]
+    pub fn to_quire(self) -> P::Q {
+        from_posits([self.hi, self.lo].iter().copied())
+    }
+}
+
+impl<P> PartialEq for DoublePosit<P>
+where
+    P: Copy + PartialOrd + Signed + AssociatedQuire<P>,
+    P::Q: Clone,
+{
+    /// Compares the values, since a tie in the rounding of `hi` can split
+    /// the same value into two pairs.
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_nar() || other.is_nar() {
+            return self.hi == other.hi;
+        }
+        let mut q = self.to_quire();
+        q.sub_product(other.hi, P::one());
+        q.sub_product(other.lo, P::one());
+        q.is_zero()
+    }
+}
+
+impl<P> From<P> for DoublePosit<P>
+where
+    P: Signed,
+{
+    #[inline]
+    fn from(p: P) -> Self {
+        Self {
+            hi: p,
+            lo: P::zero(),
+        }
+    }
+}
+
+impl<P> From<[P; 2]> for DoublePosit<P>
+where
+    P: Copy + PartialOrd + Signed + AssociatedQuire<P>,
+    P::Q: Clone,
+{
+    #[inline]
+    fn from(posits: [P; 2]) -> Self {
+        Self::from_quire(from_posits(posits.iter().copied()))
+    }
+}
+
+impl<P> From<DoublePosit<P>> for [P; 2] {
+    #[inline]
+    fn from(x: DoublePosit<P>) -> Self {
+        [x.hi, x.lo]
+    }
+}
+
+impl<P> ops::Neg for DoublePosit<P>
+where
+    P: Signed,
+{
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl<P> ops::Add for DoublePosit<P>
+where
+    P: Copy + PartialOrd + Signed + AssociatedQuire<P>,
+    P::Q: Clone,
+{
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let mut q = self.to_quire();
+        q.add_product(rhs.hi, P::one());
+        q.add_product(rhs.lo, P::one());
+        Self::from_quire(q)
+    }
+}
+
+impl<P> ops::Sub for DoublePosit<P>
+where
+    P: Copy + PartialOrd + Signed + AssociatedQuire<P>,
+    P::Q: Clone,
+{
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        let mut q = self.to_quire();
+        q.sub_product(rhs.hi, P::one());
+        q.sub_product(rhs.lo, P::one());
+        Self::from_quire(q)
+    }
+}
+
+impl<P> ops::Mul for DoublePosit<P>
+where
+    P: Copy + PartialOrd + Signed + AssociatedQuire<P>,
+    P::Q: Clone,
+{
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let mut q = P::Q::init();
+        q.add_product(self.hi, rhs.hi);
+        q.add_product(self.hi, rhs.lo);
+        q.add_product(self.lo, rhs.hi);
+        q.add_product(self.lo, rhs.lo);
+        Self::from_quire(q)
+    }
+}
+
+impl<P> ops::Div for DoublePosit<P>
+where
+    P: Copy + PartialOrd + Signed + AssociatedQuire<P>,
+    P::Q: Clone,
+{
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        // Long division: each step divides the exact remainder by `rhs.hi`
+        // and gains the precision of `P`, so three of them leave the
+        // rounding of the quotient to two terms as the main error.
+        let mut rem = self.to_quire();
+        let mut quot = P::Q::init();
+        for _ in 0..3 {
+            let x = rem.to_posit() / rhs.hi;
+            quot.add_product(x, P::one());
+            rem.sub_product(rhs.hi, x);
+            rem.sub_product(rhs.lo, x);
+        }
+        Self::from_quire(quot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{P16E1, P32E2, P8E0, Q16E1, Q32E2, Q8E0};
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+
+    /// Checks the expansions of random sums of products: the terms start
+    /// with the rounded quire, `to_posits` agrees with the iterator, and
+    /// the remainder left after reloading the terms is zero or cannot give
+    /// another term.
+    fn test_expansion<P, Q>(ntests: usize)
+    where
+        P: Copy + Signed + PartialOrd + core::fmt::Debug,
+        Q: Quire<P> + Clone + core::fmt::Debug + ops::Sub<Output = Q>,
+        Standard: Distribution<P>,
+    {
+        let mut rng = rand::thread_rng();
+        for _ in 0..ntests {
+            let mut q = Q::init();
+            for _ in 0..rng.gen_range(1, 4) {
+                q.add_product(rng.gen(), rng.gen());
+            }
+
+            let mut terms = [P::zero(); 64];
+            let mut n = 0;
+            for p in IntoPosits::<P, Q>::new(q.clone()) {
+                terms[n] = p;
+                n += 1;
+            }
+            let first: [P; 3] = to_posits(q.clone());
+            assert_eq!(&first[..], &terms[..3], "{:?}", q);
+
+            if q.is_nar() {
+                assert_eq!(n, 1);
+                assert!(Q::from_posit(terms[0]).is_nar());
+                continue;
+            }
+            if n > 0 {
+                assert_eq!(terms[0], q.to_posit(), "{:?}", q);
+            }
+            let r = q.clone() - from_posits::<P, Q, _>(terms[..n].iter().copied());
+            if !r.is_zero() {
+                let p = r.to_posit();
+                let mut t = r;
+                t.sub_product(p, P::one() / (P::one() + P::one()));
+                assert!(
+                    t.is_zero()
+                        || t.to_posit().is_negative() != p.is_negative()
+                        || (n > 0 && p.abs() >= terms[n - 1].abs()),
+                    "{:?}",
+                    q
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_expansion_p8e0() {
+        test_expansion::<P8E0, Q8E0>(crate::NTESTS16);
+    }
+
+    #[test]
+    fn test_expansion_p16e1() {
+        test_expansion::<P16E1, Q16E1>(crate::NTESTS16);
+    }
+
+    #[test]
+    fn test_expansion_p32e2() {
+        test_expansion::<P32E2, Q32E2>(crate::NTESTS16);
+    }
+
+    /// Checks the double-posit operations against the exact results in the
+    /// quire, on operands between 2 and 6 in magnitude with a remainder of
+    /// their own: each error must be below `eps` times the result (the
+    /// dividend for divisions), unless the result is below one, where the
+    /// remainder loses precision to the regime.
+    fn test_double_posit<P>(ntests: usize, eps: f64)
+    where
+        P: Copy + Signed + PartialOrd + AssociatedQuire<P> + From<f64> + core::fmt::Debug,
+        P::Q: Clone + ops::Sub<Output = P::Q> + ops::Add<Output = P::Q>,
+    {
+        let mut rng = rand::thread_rng();
+        let mut random = || {
+            let mut q = P::Q::init();
+            q.add_product(
+                P::from(rng.gen_range(1., 2.)),
+                P::from(rng.gen_range(1., 2.)),
+            );
+            q.add_product(P::from(rng.gen_range(1., 2.)), P::one());
+            if rng.gen() {
+                q.neg();
+            }
+            DoublePosit::<P>::from_quire(q)
+        };
+        let small = |err: P::Q, x: P| {
+            x.abs() < P::one() || err.is_zero() || err.to_posit().abs() <= x.abs() * P::from(eps)
+        };
+        for _ in 0..ntests {
+            let a = random();
+            let b = random();
+
+            let c = a + b;
+            let err = a.to_quire() + b.to_quire() - c.to_quire();
+            assert!(small(err, c.hi()), "{:?} + {:?}", a, b);
+            let c = a - b;
+            let err = a.to_quire() - b.to_quire() - c.to_quire();
+            assert!(small(err, c.hi()), "{:?} - {:?}", a, b);
+
+            let c = a * b;
+            let mut err = P::Q::init();
+            for &x in &[a.hi(), a.lo()] {
+                for &y in &[b.hi(), b.lo()] {
+                    err.add_product(x, y);
+                }
+            }
+            assert!(small(err - c.to_quire(), c.hi()), "{:?} * {:?}", a, b);
+
+            let c = a / b;
+            let mut err = a.to_quire();
+            for &x in &[b.hi(), b.lo()] {
+                for &y in &[c.hi(), c.lo()] {
+                    err.sub_product(x, y);
+                }
+            }
+            assert!(small(err, a.hi()), "{:?} / {:?}", a, b);
+
+            assert_eq!(DoublePosit::from(<[P; 2]>::from(c)), c);
+            assert_eq!(-(-c), c);
+        }
+    }
+
+    #[test]
+    fn test_double_posit_p16e1() {
+        test_double_posit::<P16E1>(crate::NTESTS16, 1e-5);
+    }
+
+    #[test]
+    fn test_double_posit_p32e2() {
+        test_double_posit::<P32E2>(crate::NTESTS16, 1e-14);
+    }
+
+    #[test]
+    fn test_double_posit_special() {
+        let one = DoublePosit::from(P32E2::ONE);
+        let zero = DoublePosit::from(P32E2::ZERO);
+        assert!((one / zero).is_nar());
+        assert!((DoublePosit::from(P32E2::NAR) + one).is_nar());
+        assert_eq!(<[P32E2; 2]>::from(one), [P32E2::ONE, P32E2::ZERO]);
+
+        // 1/3 holds twice the digits of a posit
+        let third = one / DoublePosit::from(P32E2::from(3.));
+        assert_eq!(third.hi(), P32E2::from(1. / 3.));
+        assert!(third.lo() != P32E2::ZERO);
+        let mut q = Q32E2::from(P32E2::ONE);
+        q.sub_product(third.hi(), P32E2::from(3.));
+        q.sub_product(third.lo(), P32E2::from(3.));
+        assert!(q.to_posit().abs() < P32E2::from(1e-14));
+
+        // the expansion of a quire reloads to the quire
+        let mut q = Q16E1::init();
+        q.add_product(P16E1::from(1. / 3.), P16E1::from(1. / 7.));
+        assert_eq!(
+            Q16E1::from_posits(q.clone().into_posits()).to_bits(),
+            q.to_bits()
+        );
+        let [p1, p2] = q.to_posits();
+        assert_eq!((p1, p2), q.into_two_posits());
+    }
+}
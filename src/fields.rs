@@ -0,0 +1,312 @@
+//! Fields of the posit encodings: sign, regime, exponent and fraction.
+
+use crate::convert::decode_posit;
+use core::fmt;
+
+/// Fields of the encoding of a nonzero, non-NaR posit.
+///
+/// A negative posit is the two's complement of a positive one, and its fields
+/// are those of the positive one, so that the value is
+/// `(-1)^sign * 2^(regime * 2^es + exponent) * (1 + fraction / 2^fraction_width)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PositFields {
+    /// Sign bit.
+    pub sign: bool,
+    /// Regime `k`, a run of `k + 1` ones for `k >= 0` and of `-k` zeros
+    /// otherwise, followed by the opposite bit unless the run reaches the end.
+    pub regime: i32,
+    /// Exponent, `es` bits wide. Its bits beyond the end of the encoding are
+    /// zero.
+    pub exponent: u32,
+    /// Fraction bits after the hidden bit.
+    pub fraction: u64,
+    /// Number of fraction bits.
+    pub fraction_width: u32,
+}
+
+/// Length of the regime `k` in an `n`-bit posit, terminating bit included.
+fn regime_len(k: i32, n: u32) -> u32 {
+    let len = if k >= 0 { k + 2 } else { 1 - k };
+    (len as u32).min(n - 1)
+}
+
+/// Fields of the `n`-bit posit `bits` with `es` exponent bits, `None` for
+/// zero and NaR.
+pub(crate) fn decode(bits: u64, n: u32, es: u32) -> Option<PositFields> {
+    let bits = bits & (u64::MAX >> (64 - n));
+    if bits == 0 || bits == 1 << (n - 1) {
+        return None;
+    }
+    let (sign, scale, frac) = decode_posit(bits, n, es);
+    let regime = scale >> es;
+    let fraction_width = (n - 1).saturating_sub(regime_len(regime, n) + es);
+    Some(PositFields {
+        sign,
+        regime,
+        exponent: (scale - (regime << es)) as u32,
+        fraction: (frac & !(1 << 63)) >> (63 - fraction_width),
+        fraction_width,
+    })
+}
+
+/// Bits of the `n`-bit posit with `es` exponent bits and the given fields,
+/// `None` if the fields are out of range or do not fit in the encoding.
+///
+/// The fraction may be narrower than the encoding, or wider, even beyond the
+/// 64 bits of `fraction`, if the bits beyond the encoding are zero.
+pub(crate) fn encode(fields: &PositFields, n: u32, es: u32) -> Option<u64> {
+    let PositFields {
+        sign,
+        regime,
+        exponent,
+        fraction,
+        fraction_width,
+    } = *fields;
+    let max_k = n as i32 - 2;
+    if regime > max_k
+        || regime < -max_k
+        || exponent.checked_shr(es).unwrap_or(0) != 0
+        || fraction.checked_shr(fraction_width).unwrap_or(0) != 0
+    {
+        return None;
+    }
+    let len = regime_len(regime, n);
+    let avail = n - 1 - len;
+    let exp_width = es.min(avail);
+    let frac_avail = avail - exp_width;
+    let dropped_exp = exponent & ((1 << (es - exp_width)) - 1);
+    let frac = if fraction_width > frac_avail {
+        let dropped = fraction_width - frac_avail;
+        let kept = fraction.checked_shr(dropped).unwrap_or(0);
+        if kept.checked_shl(dropped).unwrap_or(0) != fraction {
+            return None;
+        }
+        kept
+    } else {
+        fraction << (frac_avail - fraction_width)
+    };
+    if dropped_exp != 0 {
+        return None;
+    }
+    let regime_bits = if regime < 0 {
+        1
+    } else if regime == max_k {
+        (1 << len) - 1
+    } else {
+        ((1 << (regime + 1)) - 1) << 1
+    };
+    let exp_bits = ((exponent >> (es - exp_width)) as u64) << frac_avail;
+    let body = (regime_bits << avail) | exp_bits | frac;
+    Some(if sign {
+        body.wrapping_neg() & (u64::MAX >> (64 - n))
+    } else {
+        body
+    })
+}
+
+/// `num_traits::Float::integer_decode` of the `n`-bit posit `bits` with `es`
+/// exponent bits: the hidden bit and the fraction bits as an integer, the
+/// power of two of its last bit and the sign.
+///
+/// Zero is `(0, 0, 1)`, and NaR, whose bits are a lone sign bit, `(0, 0, -1)`.
+pub(crate) fn integer_decode(bits: u64, n: u32, es: u32) -> (u64, i16, i8) {
+    match decode(bits, n, es) {
+        Some(fields) => {
+            let scale = (fields.regime << es) + fields.exponent as i32;
+            (
+                (1 << fields.fraction_width) | fields.fraction,
+                (scale - fields.fraction_width as i32) as i16,
+                if fields.sign { -1 } else { 1 },
+            )
+        }
+        None if bits & (u64::MAX >> (64 - n)) == 0 => (0, 0, 1),
+        None => (0, 0, -1),
+    }
+}
+
+/// Alternate `Debug` of a posit, with its bits, its value and its fields.
+pub(crate) fn debug(f: &mut fmt::Formatter, name: &str, bits: u64, n: u32, es: u32) -> fmt::Result {
+    struct Value(u64, u32, u32);
+    impl fmt::Debug for Value {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            crate::format::fmt_posit(self.0, self.1, self.2, f, None)
+        }
+    }
+    let bits = bits & (u64::MAX >> (64 - n));
+    f.debug_struct(name)
+        .field("bits", &format_args!("{:#0w$b}", bits, w = n as usize + 2))
+        .field("value", &Value(bits, n, es))
+        .field("fields", &decode(bits, n, es))
+        .finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, PositFields};
+
+    /// Checks the fields of `bits` against its value and that they encode it.
+    fn check(bits: u64, n: u32, es: u32) {
+        let mask = u64::MAX >> (64 - n);
+        let fields = match decode(bits, n, es) {
+            Some(fields) => fields,
+            None => {
+                assert!(bits & mask == 0 || bits & mask == 1 << (n - 1));
+                return;
+            }
+        };
+        assert_eq!(encode(&fields, n, es), Some(bits & mask));
+        let f = f64::from_bits(crate::convert::posit_to_ieee_bits(bits, n, es, 52, 1023));
+        let scale = (fields.regime << es) + fields.exponent as i32;
+        let m = 1. + fields.fraction as f64 / 2_f64.powi(fields.fraction_width as i32);
+        let sign = if fields.sign { -1. } else { 1. };
+        assert_eq!(sign * m * 2_f64.powi(scale), f);
+    }
+
+    #[test]
+    fn test_fields_exhaustive() {
+        for &(n, es) in [(8, 0), (8, 2), (16, 1), (16, 2)].iter() {
+            for bits in 0..(1 << n) {
+                check(bits, n, es);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fields_random() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        for _ in 0..crate::NTESTS32 {
+            check(rng.gen::<u32>() as u64, 32, 2);
+            // f64 holds the fields but not always the value
+            let bits = rng.gen::<u64>();
+            if let Some(fields) = decode(bits, 64, 2) {
+                assert_eq!(encode(&fields, 64, 2), Some(bits));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fields() {
+        use crate::{P16E1, P32E2, P64E2, P8E0, P8E2};
+        let fields = |sign, regime, exponent, fraction, fraction_width| PositFields {
+            sign,
+            regime,
+            exponent,
+            fraction,
+            fraction_width,
+        };
+        assert_eq!(P32E2::ONE.decode(), Some(fields(false, 0, 0, 0, 27)));
+        assert_eq!(P32E2::ZERO.decode(), None);
+        assert_eq!(P32E2::NAR.decode(), None);
+        assert_eq!(
+            P32E2::from(-3.).decode(),
+            Some(fields(true, 0, 1, 1 << 26, 27))
+        );
+        assert_eq!(P8E2::MAX.decode(), Some(fields(false, 6, 0, 0, 0)));
+        assert_eq!(
+            P8E2::MIN_POSITIVE.decode(),
+            Some(fields(false, -6, 0, 0, 0))
+        );
+        // 0b0_0..01_1: the last bit is the top exponent bit
+        assert_eq!(
+            P64E2::from_bits(0b11).decode(),
+            Some(fields(false, -61, 2, 0, 0))
+        );
+        assert_eq!(
+            P16E1::from(0.75).decode(),
+            Some(fields(false, -1, 1, 1 << 11, 12))
+        );
+
+        assert_eq!(
+            P32E2::from_fields(fields(false, 0, 0, 0, 27)),
+            Some(P32E2::ONE)
+        );
+        assert_eq!(
+            P32E2::from_fields(fields(true, 0, 1, 1, 1)),
+            Some(P32E2::from(-3.))
+        );
+        assert_eq!(
+            P32E2::from_fields(fields(false, 0, 0, 1 << 30, 31)),
+            Some(P32E2::from(1.5))
+        );
+        assert_eq!(
+            P8E0::from_fields(fields(false, 6, 0, 0, 0)),
+            Some(P8E0::MAX)
+        );
+        assert_eq!(
+            P64E2::from_fields(fields(false, -61, 2, 0, 0)),
+            Some(P64E2::from_bits(0b11))
+        );
+        // out of range or not exact
+        assert_eq!(P32E2::from_fields(fields(false, 31, 0, 0, 0)), None);
+        assert_eq!(P32E2::from_fields(fields(false, -31, 0, 0, 0)), None);
+        assert_eq!(P32E2::from_fields(fields(false, 0, 4, 0, 0)), None);
+        assert_eq!(P32E2::from_fields(fields(false, 0, 0, 2, 1)), None);
+        assert_eq!(P32E2::from_fields(fields(false, 0, 0, 1, 28)), None);
+        assert_eq!(P64E2::from_fields(fields(false, -61, 1, 0, 0)), None);
+        assert_eq!(P8E2::from_fields(fields(false, 5, 0, 1, 1)), None);
+        // zero-padded fractions of 63 bits and wider
+        for &width in [63, 64, 65, 200, u32::MAX].iter() {
+            let one = P64E2::from_fields(fields(false, 0, 0, 0, width));
+            assert_eq!(one, Some(P64E2::ONE), "{}", width);
+        }
+        for &width in [63, 64].iter() {
+            let p = P64E2::from_fields(fields(false, 0, 0, 1 << (width - 1), width));
+            assert_eq!(p, Some(P64E2::from(1.5)), "{}", width);
+        }
+        assert_eq!(P64E2::from_fields(fields(false, 0, 0, 1, 63)), None);
+        assert_eq!(P64E2::from_fields(fields(false, 0, 0, 1, 64)), None);
+        assert_eq!(P64E2::from_fields(fields(false, 0, 0, 1 << 4, 64)), None);
+        assert_eq!(
+            P64E2::from_fields(fields(false, 0, 0, 1 << 5, 64)),
+            Some(P64E2::from_bits(0x_4000_0000_0000_0001))
+        );
+
+        #[cfg(feature = "nightly")]
+        {
+            use crate::{Posit, PxE1, PxE2};
+            let p = PxE1::<{ 12 }>::from(-3.);
+            assert_eq!(p.decode(), Some(fields(true, 0, 1, 1 << 7, 8)));
+            assert_eq!(PxE1::from_fields(fields(true, 0, 1, 1, 1)), Some(p));
+            let p = PxE2::<{ 20 }>::from(0.75);
+            assert_eq!(PxE2::from_fields(p.decode().unwrap()), Some(p));
+            let p = Posit::<24, 3>::from(0.75);
+            assert_eq!(p.decode(), Some(fields(false, -1, 7, 1 << 17, 18)));
+            assert_eq!(Posit::from_fields(p.decode().unwrap()), Some(p));
+        }
+    }
+
+    #[test]
+    fn test_fields_debug() {
+        use crate::format::tests::Buf;
+        use crate::{P32E2, P8E0};
+        let s = Buf::from_fmt(format_args!("{:?}", P8E0::ONE));
+        assert_eq!(s.as_str(), "P8E0(64)");
+        let s = Buf::from_fmt(format_args!("{:#?}", P8E0::from(-1.5)));
+        assert_eq!(
+            s.as_str(),
+            "P8E0 {
+    bits: 0b10110000,
+    value: -1.5,
+    fields: Some(
+        PositFields {
+            sign: true,
+            regime: 0,
+            exponent: 0,
+            fraction: 16,
+            fraction_width: 5,
+        },
+    ),
+}"
+        );
+        let s = Buf::from_fmt(format_args!("{:#?}", P32E2::NAR));
+        assert_eq!(
+            s.as_str(),
+            "P32E2 {
+    bits: 0b10000000000000000000000000000000,
+    value: NaR,
+    fields: None,
+}"
+        );
+    }
+}
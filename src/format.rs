@@ -0,0 +1,685 @@
+//! Decimal formatting of posits: shortest round-trip digits by default, and
+//! the exactly rounded value when a precision is given. Quires are written
+//! exactly.
+
+use crate::convert::decode_body;
+use crate::decimal::Decimal;
+use crate::limbs;
+use core::fmt::{self, Write};
+
+/// Formats the `n`-bit posit `bits` with `es` exponent bits, in scientific
+/// notation with the exponent marker `exp` if there is one.
+///
+/// Without a precision, the digits are the shortest ones which parse back to
+/// the same posit, the nearest to it among those. With a precision, the exact
+/// value is rounded to nearest, ties to even. NaR is written `NaR`.
+pub(crate) fn fmt_posit(
+    bits: u64,
+    n: u32,
+    es: u32,
+    f: &mut fmt::Formatter,
+    exp: Option<&str>,
+) -> fmt::Result {
+    let mask = u64::MAX >> (64 - n);
+    let bits = bits & mask;
+    if bits == 1 << (n - 1) {
+        return pad(f, "", &[Part::Str("NaR")]);
+    }
+    let sign = if (bits >> (n - 1)) != 0 {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let body = if sign == "-" {
+        bits.wrapping_neg() & mask
+    } else {
+        bits
+    };
+    let d = if body == 0 {
+        Decimal::new()
+    } else if f.precision().is_some() {
+        exact(decode_body(body << (65 - n), es))
+    } else {
+        shortest(body, n, es)
+    };
+    fmt_decimal(f, sign, d, exp)
+}
+
+/// Formats the exact value of the `width`-bit quire `limbs`, sign-extended to
+/// 64-bit limbs, whose least significant bit has the weight `2^-one_bit`, in
+/// scientific notation with the exponent marker `exp` if there is one.
+///
+/// Without a precision, all the digits are written. With a precision, the
+/// value is rounded to nearest, ties to even. NaR is written `NaR`.
+pub(crate) fn fmt_quire(
+    limbs: &[u64],
+    width: u32,
+    one_bit: i32,
+    f: &mut fmt::Formatter,
+    exp: Option<&str>,
+) -> fmt::Result {
+    if limbs::is_nar(limbs, width) {
+        return pad(f, "", &[Part::Str("NaR")]);
+    }
+    let mut mag = [0; limbs::MAX_LIMBS];
+    let (negative, mag) = magnitude(limbs, &mut mag);
+    let sign = if negative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    // groups of 19 digits, least significant first
+    let mut groups = [0_u64; 2 * limbs::MAX_LIMBS];
+    let mut len = 0;
+    while mag.iter().any(|&l| l != 0) {
+        groups[len] = limbs::div_small(mag, 10_000_000_000_000_000_000);
+        len += 1;
+    }
+    let mut d = Decimal::new();
+    for &group in groups[..len].iter().rev() {
+        let mut unit = 1_000_000_000_000_000_000;
+        while unit > 0 {
+            d.push(((group / unit) % 10) as u8, true);
+            unit /= 10;
+        }
+    }
+    d.trim();
+    d.shift(-one_bit);
+    fmt_decimal(f, sign, d, exp)
+}
+
+/// Formats the exact value of the quire like `fmt_quire`, in hexadecimal
+/// with a `0x` prefix in the alternate form. `one_bit` is a multiple of 4.
+pub(crate) fn fmt_quire_hex(
+    limbs: &[u64],
+    width: u32,
+    one_bit: i32,
+    f: &mut fmt::Formatter,
+) -> fmt::Result {
+    if limbs::is_nar(limbs, width) {
+        return pad(f, "", &[Part::Str("NaR")]);
+    }
+    let mut mag = [0; limbs::MAX_LIMBS];
+    let (negative, mag) = magnitude(limbs, &mut mag);
+    let sign = if negative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let mut buf = [0_u8; limbs::MAX_LIMBS * 16];
+    let digits = &mut buf[..mag.len() * 16];
+    for (chunk, limb) in digits.chunks_mut(16).zip(mag.iter()) {
+        for (i, c) in chunk.iter_mut().enumerate() {
+            let digit = (limb >> (60 - 4 * i)) & 0xf;
+            *c = core::char::from_digit(digit as u32, 16).unwrap() as u8;
+        }
+    }
+    let digits = core::str::from_utf8(digits).unwrap();
+    let (int, fract) = digits.split_at(digits.len() - (one_bit / 4) as usize);
+    let int = int.trim_start_matches('0');
+    let fract = fract.trim_end_matches('0');
+    let parts = [
+        Part::Str(if f.alternate() { "0x" } else { "" }),
+        Part::Str(if int.is_empty() { "0" } else { int }),
+        Part::Str(if fract.is_empty() { "" } else { "." }),
+        Part::Str(fract),
+    ];
+    pad(f, sign, &parts)
+}
+
+/// Splits the limbs into their sign and their magnitude, written to `buf`.
+fn magnitude<'a>(limbs: &[u64], buf: &'a mut [u64]) -> (bool, &'a mut [u64]) {
+    let buf = &mut buf[..limbs.len()];
+    buf.copy_from_slice(limbs);
+    let negative = (limbs[0] >> 63) != 0;
+    if negative {
+        limbs::neg(buf);
+    }
+    (negative, buf)
+}
+
+/// Formats the decimal number `d` after `sign`, in scientific notation with
+/// the exponent marker `exp` if there is one, rounded to the precision of the
+/// formatter if it has one.
+fn fmt_decimal(
+    f: &mut fmt::Formatter,
+    sign: &str,
+    mut d: Decimal,
+    exp: Option<&str>,
+) -> fmt::Result {
+    let mut exp_buf = [0; 24];
+    match (exp, f.precision()) {
+        (None, None) => {
+            let digits = &d.d[..d.nd];
+            if d.nd == 0 {
+                pad(f, sign, &[Part::Str("0")])
+            } else if d.dp <= 0 {
+                let zeros = Part::Zeros(-d.dp as usize);
+                pad(f, sign, &[Part::Str("0."), zeros, Part::Digits(digits)])
+            } else if d.dp as usize >= d.nd {
+                let zeros = Part::Zeros(d.dp as usize - d.nd);
+                pad(f, sign, &[Part::Digits(digits), zeros])
+            } else {
+                let (int, fract) = digits.split_at(d.dp as usize);
+                let parts = [Part::Digits(int), Part::Str("."), Part::Digits(fract)];
+                pad(f, sign, &parts)
+            }
+        }
+        (None, Some(prec)) => {
+            let nd = d.dp + prec as i64;
+            if nd < 0 {
+                d = Decimal::new();
+            } else {
+                d.round(nd as usize);
+            }
+            let digits = &d.d[..d.nd];
+            let int_len = (d.dp.max(0) as usize).min(d.nd);
+            let (int, int_zeros) = if int_len == 0 {
+                (Part::Str("0"), 0)
+            } else {
+                (Part::Digits(&digits[..int_len]), d.dp as usize - int_len)
+            };
+            let lead = prec.min((-d.dp).max(0) as usize);
+            let fract = &digits[int_len..];
+            let trail = prec - lead - fract.len();
+            let point = if prec > 0 { "." } else { "" };
+            let parts = [
+                int,
+                Part::Zeros(int_zeros),
+                Part::Str(point),
+                Part::Zeros(lead),
+                Part::Digits(fract),
+                Part::Zeros(trail),
+            ];
+            pad(f, sign, &parts)
+        }
+        (Some(marker), prec) => {
+            if let Some(prec) = prec {
+                d.round(prec + 1);
+            }
+            let e = if d.nd == 0 { 0 } else { d.dp - 1 };
+            let (first, rest) = if d.nd == 0 {
+                (Part::Str("0"), &d.d[..0])
+            } else {
+                (Part::Digits(&d.d[..1]), &d.d[1..d.nd])
+            };
+            let trail = prec.map_or(0, |prec| prec - rest.len());
+            let point = if rest.len() + trail > 0 { "." } else { "" };
+            let parts = [
+                first,
+                Part::Str(point),
+                Part::Digits(rest),
+                Part::Zeros(trail),
+                Part::Str(marker),
+                Part::Str(int_str(e, &mut exp_buf)),
+            ];
+            pad(f, sign, &parts)
+        }
+    }
+}
+
+/// Exact decimal value of `frac * 2^(scale - 63)`.
+fn exact((scale, frac): (i32, u64)) -> Decimal {
+    let mut d = Decimal::from_int(frac);
+    d.shift(scale - 63);
+    d
+}
+
+/// Shortest digits of the positive posit `body` which round to it, found as
+/// in Steele and White's algorithm from the exact values of the posit and of
+/// the rounding boundaries around it.
+fn shortest(body: u64, n: u32, es: u32) -> Decimal {
+    let (scale, frac) = decode_body(body << (65 - n), es);
+    // the rounding boundaries are the `n + 1`-bit posits in between
+    let mid = |b: u64| decode_body(((2 * b as u128 + 1) << (64 - n)) as u64, es);
+    let maxpos = u64::MAX >> (65 - n);
+    let pow2 = |scale: i32| (scale, 1 << 63);
+    // every number beyond minpos or maxpos rounds to it, so mirror the other
+    // boundary there to keep the digits close to the posit
+    let (lower, upper) = match (body > 1, body < maxpos) {
+        (true, true) => (mid(body - 1), mid(body)),
+        (false, true) => {
+            let upper = mid(body);
+            (
+                pow2(2 * scale - upper.0 - (upper.1 != 1 << 63) as i32),
+                upper,
+            )
+        }
+        (true, false) => {
+            let lower = mid(body - 1);
+            (lower, pow2(2 * scale - lower.0))
+        }
+        (false, false) => (pow2(scale - 1), pow2(scale + 1)),
+    };
+    let mut d = exact((scale, frac));
+    let lower = exact(lower);
+    let upper = exact(upper);
+    // the boundaries round to the even posit
+    let inclusive = (body & 1) == 0;
+
+    // 0 while the digits of d and upper agree, 1 after a difference of one
+    // followed by nines in d and zeros in upper, and 2 once rounding up stays
+    // below upper
+    let mut upper_delta = 0;
+    for ui in 0.. {
+        let mi = ui - upper.dp + d.dp;
+        if mi >= d.nd as i64 {
+            break;
+        }
+        let li = ui - upper.dp + lower.dp;
+        let (l, m, u) = (lower.digit(li), d.digit(mi), upper.digit(ui));
+
+        let ok_down = l != m || (inclusive && li + 1 == lower.nd as i64);
+        upper_delta = match upper_delta {
+            0 if m + 1 < u => 2,
+            0 if m != u => 1,
+            1 if m != 9 || u != 0 => 2,
+            delta => delta,
+        };
+        let ok_up = upper_delta > 0 && (inclusive || upper_delta > 1 || ui + 1 < upper.nd as i64);
+
+        match (ok_down, ok_up) {
+            (true, true) => d.round((mi + 1) as usize),
+            (true, false) => d.round_down((mi + 1) as usize),
+            (false, true) if mi < 0 => {
+                // a single unit above the leading digit
+                d.dp -= mi;
+                d.d[0] = 1;
+                d.nd = 1;
+            }
+            (false, true) => d.round_up((mi + 1) as usize),
+            (false, false) => continue,
+        }
+        break;
+    }
+    d
+}
+
+/// Piece of a formatted number.
+enum Part<'a> {
+    Str(&'a str),
+    /// Digits of a `Decimal`, from 0 to 9.
+    Digits(&'a [u8]),
+    Zeros(usize),
+}
+
+impl Part<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Part::Str(s) => s.len(),
+            Part::Digits(d) => d.len(),
+            Part::Zeros(n) => *n,
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const ZEROS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+        match self {
+            Part::Str(s) => f.write_str(s),
+            Part::Digits(digits) => {
+                let mut buf = [0; 64];
+                for chunk in digits.chunks(buf.len()) {
+                    for (b, d) in buf.iter_mut().zip(chunk) {
+                        *b = b'0' + d;
+                    }
+                    f.write_str(core::str::from_utf8(&buf[..chunk.len()]).unwrap())?;
+                }
+                Ok(())
+            }
+            Part::Zeros(mut n) => {
+                while n > 0 {
+                    let len = n.min(ZEROS.len());
+                    f.write_str(&ZEROS[..len])?;
+                    n -= len;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Writes the sign and the parts, padded to the width of the formatter like
+/// the floating-point numbers of `core`.
+fn pad(f: &mut fmt::Formatter, sign: &str, parts: &[Part]) -> fmt::Result {
+    let len = sign.len() + parts.iter().map(Part::len).sum::<usize>();
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        Part::Zeros(padding).write(f)?;
+        return parts.iter().try_for_each(|part| part.write(f));
+    }
+    let (pre, post) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        _ => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    parts.iter().try_for_each(|part| part.write(f))?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Writes `i` in decimal into `buf`.
+fn int_str(i: i64, buf: &mut [u8; 24]) -> &str {
+    let mut pos = buf.len();
+    let mut u = i.unsigned_abs();
+    loop {
+        pos -= 1;
+        buf[pos] = b'0' + (u % 10) as u8;
+        u /= 10;
+        if u == 0 {
+            break;
+        }
+    }
+    if i < 0 {
+        pos -= 1;
+        buf[pos] = b'-';
+    }
+    core::str::from_utf8(&buf[pos..]).unwrap()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{fmt_posit, shortest};
+    use crate::parse::parse_posit;
+    use core::fmt::{self, Write};
+
+    /// Stack buffer for the strings of the tests.
+    pub(crate) struct Buf {
+        bytes: [u8; 2048],
+        len: usize,
+    }
+
+    impl Buf {
+        pub(crate) fn new() -> Self {
+            Self {
+                bytes: [0; 2048],
+                len: 0,
+            }
+        }
+        pub(crate) fn from_fmt(args: fmt::Arguments) -> Self {
+            let mut buf = Self::new();
+            buf.write_fmt(args).unwrap();
+            buf
+        }
+        pub(crate) fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl fmt::Write for Buf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.bytes[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    /// Posit of any size, `(bits, n, es)`.
+    struct P(u64, u32, u32);
+
+    impl fmt::Display for P {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            fmt_posit(self.0, self.1, self.2, f, None)
+        }
+    }
+
+    impl fmt::LowerExp for P {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            fmt_posit(self.0, self.1, self.2, f, Some("e"))
+        }
+    }
+
+    /// Checks that the posit `bits` round-trips in both notations, and that
+    /// no number with fewer digits rounds to it.
+    fn check_shortest(bits: u64, n: u32, es: u32) {
+        let mask = u64::MAX >> (64 - n);
+        let p = P(bits, n, es);
+        for s in [
+            Buf::from_fmt(format_args!("{}", p)),
+            Buf::from_fmt(format_args!("{:e}", p)),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                parse_posit(s.as_str(), n, es),
+                Ok(bits & mask),
+                "{}",
+                s.as_str()
+            );
+        }
+        let body = if (bits >> (n - 1)) & 1 != 0 {
+            bits.wrapping_neg() & mask
+        } else {
+            bits & mask
+        };
+        if body == 0 {
+            return;
+        }
+        let d = shortest(body, n, es);
+        if d.nd < 2 {
+            return;
+        }
+        for &up in [false, true].iter() {
+            let mut shorter = shortest(body, n, es);
+            if up {
+                shorter.round_up(d.nd - 1);
+            } else {
+                shorter.round_down(d.nd - 1);
+            }
+            let mut s = Buf::new();
+            for &digit in shorter.d[..shorter.nd].iter() {
+                s.write_char((b'0' + digit) as char).unwrap();
+            }
+            write!(s, "e{}", shorter.dp - shorter.nd as i64).unwrap();
+            assert_ne!(parse_posit(s.as_str(), n, es), Ok(body), "{}", s.as_str());
+        }
+    }
+
+    /// Checks the exactly rounded formats against those of `f64`, which
+    /// holds the posit exactly.
+    fn check_exact(bits: u64, n: u32, es: u32) {
+        let p = P(bits, n, es);
+        let f = f64::from_bits(crate::convert::posit_to_ieee_bits(bits, n, es, 52, 1023));
+        let pairs = [
+            (format_args!("{:.0}", p), format_args!("{:.0}", f)),
+            (format_args!("{:.2}", p), format_args!("{:.2}", f)),
+            (format_args!("{:.7}", p), format_args!("{:.7}", f)),
+            (format_args!("{:.40}", p), format_args!("{:.40}", f)),
+            (format_args!("{:.0e}", p), format_args!("{:.0e}", f)),
+            (format_args!("{:.3e}", p), format_args!("{:.3e}", f)),
+            (format_args!("{:.20e}", p), format_args!("{:.20e}", f)),
+        ];
+        for (a, b) in pairs.iter() {
+            let (a, b) = (Buf::from_fmt(*a), Buf::from_fmt(*b));
+            assert_eq!(a.as_str(), b.as_str());
+        }
+    }
+
+    /// Writes `|m| * 2^-k` exactly in decimal, or in hexadecimal for `k` a
+    /// multiple of 4.
+    pub(crate) fn exact_quire(m: &num_bigint::BigInt, k: i32, hex: bool) -> Buf {
+        use num_bigint::BigInt;
+        use num_traits::{Pow, Signed};
+        let (digits, frac_len) = if hex {
+            let len = (k / 4) as usize;
+            (
+                Buf::from_fmt(format_args!("{:0>w$x}", m.abs(), w = len + 1)),
+                len,
+            )
+        } else {
+            let m = m.abs() * BigInt::from(5).pow(k as u32);
+            let len = k as usize;
+            (Buf::from_fmt(format_args!("{:0>w$}", m, w = len + 1)), len)
+        };
+        let digits = digits.as_str();
+        let (int, fract) = digits.split_at(digits.len() - frac_len);
+        let fract = fract.trim_end_matches('0');
+        let point = if fract.is_empty() { "" } else { "." };
+        Buf::from_fmt(format_args!("{}{}{}", int, point, fract))
+    }
+
+    /// Checks the exact formatting of a `width`-bit quire and its parsing, on
+    /// random values of every magnitude and on the ties between them.
+    pub(crate) fn test_quire_fmt<Q, const N: usize>(
+        width: u32,
+        one_bit: i32,
+        to_limbs: fn(&Q) -> [u64; N],
+        from_limbs: fn([u64; N]) -> Q,
+    ) where
+        Q: fmt::Display + fmt::LowerHex + core::str::FromStr,
+    {
+        use crate::limbs::{is_nar, random, value};
+        use num_bigint::BigInt;
+        use num_traits::{Signed, Zero};
+        let mut rng = rand::thread_rng();
+        let nar = -(BigInt::from(1) << (width - 1) as usize);
+        let parse = |s: &Buf| value(&to_limbs(&s.as_str().parse::<Q>().ok().unwrap()));
+        let fits = |v: BigInt| if v.abs() < nar.abs() { v } else { nar.clone() };
+        for _ in 0..crate::NTESTS16 {
+            let limbs = random::<N>(&mut rng, width);
+            if is_nar(&limbs, width) {
+                continue;
+            }
+            let v = value(&limbs);
+            let q = from_limbs(limbs);
+            let sign = if v.is_negative() { "-" } else { "" };
+
+            let exact = exact_quire(&v, one_bit, false);
+            let expected = Buf::from_fmt(format_args!("{}{}", sign, exact.as_str()));
+            let s = Buf::from_fmt(format_args!("{}", q));
+            assert_eq!(s.as_str(), expected.as_str());
+            assert_eq!(parse(&s), v);
+            let hex = exact_quire(&v, one_bit, true);
+            let s = Buf::from_fmt(format_args!("{:x}", q));
+            assert_eq!(&s.as_str()[sign.len()..], hex.as_str());
+            let s = Buf::from_fmt(format_args!("{:#x}", q));
+            let expected = Buf::from_fmt(format_args!("{}0x{}", sign, hex.as_str()));
+            assert_eq!(s.as_str(), expected.as_str());
+            assert_eq!(parse(&s), v);
+
+            // halfway to the next value, ties to even, and just beyond
+            let tie = (&v << 1) + 1;
+            let exact = exact_quire(&tie, one_bit + 1, false);
+            let s = Buf::from_fmt(format_args!("{}{}", sign, exact.as_str()));
+            let even = if (&v % BigInt::from(2)).is_zero() {
+                v.clone()
+            } else {
+                &v + 1
+            };
+            assert_eq!(parse(&s), fits(even), "{}", s.as_str());
+            let s = Buf::from_fmt(format_args!("{}{}1", sign, exact.as_str()));
+            let beyond = if v.is_negative() { v.clone() } else { &v + 1 };
+            assert_eq!(parse(&s), fits(beyond), "{}", s.as_str());
+        }
+    }
+
+    #[test]
+    fn test_fmt_exhaustive() {
+        for &(n, es) in [(8, 0), (8, 2), (16, 1), (16, 2)].iter() {
+            for bits in 0..(1 << n) {
+                if bits != 1 << (n - 1) {
+                    check_shortest(bits, n, es);
+                    check_exact(bits, n, es);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fmt_random() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        for _ in 0..crate::NTESTS16 {
+            let bits = rng.gen::<u32>() as u64;
+            if bits != 1 << 31 {
+                check_shortest(bits, 32, 2);
+                check_exact(bits, 32, 2);
+            }
+            let bits = rng.gen::<u64>();
+            if bits != 1 << 63 {
+                check_shortest(bits, 64, 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fmt() {
+        use crate::{P16E1, P32E2, P64E2, P8E0, P8E2};
+        let s = |args: fmt::Arguments| Buf::from_fmt(args);
+        assert_eq!(s(format_args!("{}", P32E2::from(12.3))).as_str(), "12.3");
+        assert_eq!(s(format_args!("{}", P16E1::from(0.3))).as_str(), "0.3");
+        assert_eq!(s(format_args!("{}", P8E0::from(-0.1))).as_str(), "-0.1");
+        let tenth = "0.1".parse::<P64E2>().unwrap();
+        assert_ne!(tenth, P64E2::from(0.1));
+        assert_eq!(s(format_args!("{}", tenth)).as_str(), "0.1");
+        assert_eq!(
+            s(format_args!("{}", P64E2::from(0.1))).as_str(),
+            "0.1000000000000000056"
+        );
+        assert_eq!(s(format_args!("{}", P8E2::MAX)).as_str(), "20000000");
+        assert_eq!(s(format_args!("{:e}", P8E2::MIN_POSITIVE)).as_str(), "1e-7");
+
+        let p = P32E2::from(1.5);
+        assert_eq!(s(format_args!("{:.3}", p)).as_str(), "1.500");
+        assert_eq!(s(format_args!("{:+}", p)).as_str(), "+1.5");
+        assert_eq!(s(format_args!("{:>8}", p)).as_str(), "     1.5");
+        assert_eq!(s(format_args!("{:*^7}", p)).as_str(), "**1.5**");
+        assert_eq!(s(format_args!("{:<5}|", p)).as_str(), "1.5  |");
+        assert_eq!(s(format_args!("{:08.2}", -p)).as_str(), "-0001.50");
+        assert_eq!(
+            s(format_args!("{:e}", P32E2::from(1500.))).as_str(),
+            "1.5e3"
+        );
+        assert_eq!(
+            s(format_args!("{:E}", P32E2::from(0.015))).as_str(),
+            "1.5E-2"
+        );
+        assert_eq!(
+            s(format_args!("{:.1e}", P32E2::from(1250.))).as_str(),
+            "1.2e3"
+        );
+        assert_eq!(
+            s(format_args!("{:+.2e}", P32E2::from(1.))).as_str(),
+            "+1.00e0"
+        );
+
+        assert_eq!(s(format_args!("{}", P32E2::ZERO)).as_str(), "0");
+        assert_eq!(s(format_args!("{:.2}", P32E2::ZERO)).as_str(), "0.00");
+        assert_eq!(s(format_args!("{:e}", P32E2::ZERO)).as_str(), "0e0");
+        assert_eq!(s(format_args!("{:.1e}", P32E2::ZERO)).as_str(), "0.0e0");
+
+        assert_eq!(s(format_args!("{}", P32E2::NAR)).as_str(), "NaR");
+        assert_eq!(s(format_args!("{:+.2e}", P32E2::NAR)).as_str(), "NaR");
+        assert_eq!(s(format_args!("{:>5}", P16E1::NAR)).as_str(), "  NaR");
+
+        #[cfg(feature = "nightly")]
+        {
+            use crate::{Posit, PxE1, PxE2};
+            assert_eq!(
+                s(format_args!("{}", PxE1::<{ 12 }>::from(-12.3))).as_str(),
+                "-12.3"
+            );
+            assert_eq!(
+                s(format_args!("{:e}", PxE2::<{ 20 }>::from(0.3))).as_str(),
+                "3e-1"
+            );
+            assert_eq!(
+                s(format_args!("{:.2}", Posit::<24, 3>::from(1.5))).as_str(),
+                "1.50"
+            );
+        }
+    }
+}
@@ -1,6 +1,4 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(feature = "nightly", feature(const_generics))]
-#![cfg_attr(feature = "nightly", feature(const_fn))]
 #![allow(clippy::cast_lossless)]
 #![allow(clippy::float_cmp)]
 #![allow(clippy::many_single_char_names)]
@@ -28,6 +26,42 @@ pub mod quire32;
 pub use self::quire32::Q32E2;
 pub type Q32 = Q32E2;
 
+pub mod p64e2;
+pub use self::p64e2::P64E2;
+pub type P64 = P64E2;
+pub mod quire64;
+pub use self::quire64::Q64E2;
+pub type Q64 = Q64E2;
+
+// Posit Standard (2022) types, with es = 2 for every width
+pub mod p8e2;
+pub use self::p8e2::P8E2;
+pub mod quire8e2;
+pub use self::quire8e2::Q8E2;
+pub mod p16e2;
+pub use self::p16e2::P16E2;
+pub mod quire16e2;
+pub use self::quire16e2::Q16E2;
+
+/// Standard `posit8`.
+pub type Posit8 = P8E2;
+/// Standard `posit16`.
+pub type Posit16 = P16E2;
+/// Standard `posit32`.
+pub type Posit32 = P32E2;
+/// Standard `posit64`.
+pub type Posit64 = P64E2;
+/// Standard quire of `posit8`.
+pub type Quire8 = Q8E2;
+/// Standard quire of `posit16`.
+pub type Quire16 = Q16E2;
+/// Standard quire of `posit32`.
+pub type Quire32 = Q32E2;
+/// Quire of `posit64`, twice the size of the standard one.
+pub type Quire64 = Q64E2;
+
+mod limbs;
+
 #[cfg(feature = "nightly")]
 pub mod pxe1;
 #[cfg(feature = "nightly")]
@@ -38,8 +72,24 @@ pub mod pxe2;
 #[cfg(feature = "nightly")]
 pub use pxe2::PxE2;
 
+pub mod posit;
+pub use posit::{Posit, PositQuire};
+
 mod convert;
-use convert::convert_fraction_p32;
+pub use convert::PositConversionError;
+
+mod xfloat;
+
+mod dd;
+
+mod decimal;
+mod expansion;
+pub use expansion::{DoublePosit, IntoPosits};
+mod fields;
+pub use fields::PositFields;
+mod format;
+mod parse;
+pub use parse::ParsePositError;
 
 mod macros;
 
@@ -148,6 +198,25 @@ pub trait MathConsts {
     const LOG10_2: Self;
 }
 
+/// Trigonometric functions of angles measured in half-turns: `sin_pi(x)` is
+/// `sin(π x)`. Unlike radians, such angles are exact, so that for example
+/// `sin_pi` is zero at every integer and `tan_pi` has its poles at the
+/// half-integers.
+pub trait TrigPi {
+    /// Returns `sin(π x)`.
+    fn sin_pi(self) -> Self;
+    /// Returns `cos(π x)`.
+    fn cos_pi(self) -> Self;
+    /// Returns `tan(π x)`, NaR at the half-integers.
+    fn tan_pi(self) -> Self;
+    /// Returns `asin(x) / π`, in `[-1/2, 1/2]`, NaR outside `[-1, 1]`.
+    fn asin_pi(self) -> Self;
+    /// Returns `acos(x) / π`, in `[0, 1]`, NaR outside `[-1, 1]`.
+    fn acos_pi(self) -> Self;
+    /// Returns `atan(x) / π`, in `[-1/2, 1/2]`.
+    fn atan_pi(self) -> Self;
+}
+
 #[cfg(test)]
 const NTESTS32: usize = 1000_000;
 #[cfg(test)]
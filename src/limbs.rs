@@ -0,0 +1,457 @@
+//! Two's complement fixed-point numbers stored in 64-bit limbs, most
+//! significant limb first, as used by the quires of the standard posits.
+
+use crate::PositConversionError;
+use core::convert::TryFrom;
+
+/// Largest number of limbs, of the 2048-bit quires.
+pub(crate) const MAX_LIMBS: usize = 32;
+
+/// Negates the number, the smallest one stays the same.
+pub(crate) fn neg(limbs: &mut [u64]) {
+    let mut borrow = false;
+    for limb in limbs.iter_mut().rev() {
+        let (z, b) = 0_u64.overflowing_sub(*limb);
+        let (z, b2) = z.overflowing_sub(borrow as u64);
+        *limb = z;
+        borrow = b || b2;
+    }
+}
+
+/// Adds `±other`, a number of the same length, and returns whether the sum
+/// overflowed, leaving it wrapped around.
+pub(crate) fn add(limbs: &mut [u64], other: &[u64], negative: bool) -> bool {
+    let (sign_a, sign_b) = (is_negative(limbs), is_negative(other) != negative);
+    let mut carry = false;
+    for (limb, &part) in limbs.iter_mut().zip(other).rev() {
+        if negative {
+            let (z, b) = limb.overflowing_sub(part);
+            let (z, b2) = z.overflowing_sub(carry as u64);
+            *limb = z;
+            carry = b || b2;
+        } else {
+            let (z, c) = limb.overflowing_add(part);
+            let (z, c2) = z.overflowing_add(carry as u64);
+            *limb = z;
+            carry = c || c2;
+        }
+    }
+    sign_a == sign_b && is_negative(limbs) != sign_a
+}
+
+fn is_negative(limbs: &[u64]) -> bool {
+    (limbs[0] >> 63) != 0
+}
+
+/// Multiplies the number by `2^n`, returning `false` and leaving it unchanged
+/// if that is inexact: if set bits would be shifted out at the bottom, or the
+/// result does not fit.
+pub(crate) fn shift(limbs: &mut [u64], n: i32) -> bool {
+    let len = limbs.len();
+    let mut buf = [0; MAX_LIMBS];
+    let buf = &mut buf[..len];
+    shifted(limbs, n, buf);
+    // the shift is exact if and only if it can be undone
+    let mut back = [0; MAX_LIMBS];
+    let back = &mut back[..len];
+    shifted(buf, n.saturating_neg(), back);
+    if back != limbs {
+        return false;
+    }
+    limbs.copy_from_slice(buf);
+    true
+}
+
+/// Writes `limbs * 2^n`, rounded towards minus infinity and wrapping around,
+/// to `out`.
+fn shifted(limbs: &[u64], n: i32, out: &mut [u64]) {
+    let len = limbs.len() as i64;
+    let fill = if (limbs[0] >> 63) != 0 { u64::MAX } else { 0 };
+    // limb `i` counted from the least significant one, sign-extended above
+    let limb = |i: i64| match i {
+        i if i < 0 => 0,
+        i if i >= len => fill,
+        i => limbs[(len - 1 - i) as usize],
+    };
+    let (words, bits) = ((n as i64).div_euclid(64), (n as i64).rem_euclid(64));
+    for (i, z) in out.iter_mut().rev().enumerate() {
+        let j = i as i64 - words;
+        *z = if bits == 0 {
+            limb(j)
+        } else {
+            (limb(j) << bits) | (limb(j - 1) >> (64 - bits))
+        };
+    }
+}
+
+/// Adds `±a * b` exactly, where the operands are unpacked posits
+/// `(sign, scale, frac)` with the hidden bit of `frac` at bit 63, and the
+/// least significant bit of the number has the weight `2^-one_bit`. Returns
+/// whether the sum overflowed the carry bits, leaving it wrapped around.
+pub(crate) fn add_product(
+    limbs: &mut [u64],
+    one_bit: i32,
+    (sign_a, scale_a, frac_a): (bool, i32, u64),
+    (sign_b, scale_b, frac_b): (bool, i32, u64),
+    plus: bool,
+) -> bool {
+    let frac = (frac_a as u128) * (frac_b as u128);
+    // the hidden bit of the product is at bit 126 with weight 2^(scale_a + scale_b)
+    let lsb = scale_a + scale_b - 126 + one_bit;
+    add_shifted(limbs, frac, lsb, (sign_a ^ sign_b) == plus)
+}
+
+/// Adds `±frac * 2^lsb` in units of the least significant bit, and returns
+/// whether the sum overflowed.
+fn add_shifted(limbs: &mut [u64], frac: u128, lsb: i32, negative: bool) -> bool {
+    let sign = is_negative(limbs);
+    // Products are multiples of minpos^2, so the bits below the number are zero.
+    let (frac, lsb) = if lsb < 0 {
+        (frac >> -lsb, 0)
+    } else {
+        (frac, lsb as u32)
+    };
+    let shift = lsb % 64;
+    let low = frac << shift;
+    let high = if shift == 0 {
+        0
+    } else {
+        (frac >> (128 - shift)) as u64
+    };
+    let parts = [low as u64, (low >> 64) as u64, high];
+
+    let len = limbs.len();
+    let first = (lsb / 64) as usize;
+    let mut carry = false;
+    for i in first..len {
+        let part = match parts.get(i - first) {
+            Some(&part) => part,
+            None if carry => 0,
+            None => break,
+        };
+        let limb = &mut limbs[len - 1 - i];
+        if negative {
+            let (z, b) = limb.overflowing_sub(part);
+            let (z, b2) = z.overflowing_sub(carry as u64);
+            *limb = z;
+            carry = b || b2;
+        } else {
+            let (z, c) = limb.overflowing_add(part);
+            let (z, c2) = z.overflowing_add(carry as u64);
+            *limb = z;
+            carry = c || c2;
+        }
+    }
+    sign == negative && is_negative(limbs) != sign
+}
+
+/// Splits a nonzero number into its sign, its scale, the 128 bits starting
+/// at the most significant one and whether any bit below them is set, the
+/// arguments of `encode`.
+pub(crate) fn unpack(limbs: &[u64], one_bit: i32) -> (bool, i32, u128, bool) {
+    let len = limbs.len();
+    let mut buf = [0; MAX_LIMBS];
+    let buf = &mut buf[..len];
+    buf.copy_from_slice(limbs);
+    let sign = (buf[0] >> 63) != 0;
+    if sign {
+        neg(buf);
+    }
+
+    let top = buf.iter().position(|&l| l != 0).unwrap();
+    let lz = buf[top].leading_zeros();
+    // most significant set bit, counting from the least significant one
+    let msb = ((len - 1 - top) * 64) as i32 + 63 - lz as i32;
+    let limb = |i: usize| buf.get(i).copied().unwrap_or(0) as u128;
+    let window = (limb(top) << 64) | limb(top + 1);
+    let next = limb(top + 2);
+    let frac = if lz == 0 {
+        window
+    } else {
+        (window << lz) | (next >> (64 - lz))
+    };
+    let sticky = ((next as u64) << lz) != 0 || buf.iter().skip(top + 3).any(|&l| l != 0);
+    (sign, msb - one_bit, frac, sticky)
+}
+
+/// Rounds the number to the nearest binary floating-point number with
+/// `man_bits` fraction bits and `exp_bits` exponent bits, ties to even, and
+/// returns its bits.
+pub(crate) fn to_float(limbs: &[u64], one_bit: i32, man_bits: u32, exp_bits: u32) -> u64 {
+    if limbs.iter().all(|&l| l == 0) {
+        return 0;
+    }
+    let (sign, scale, frac, sticky) = unpack(limbs, one_bit);
+    let bias = (1 << (exp_bits - 1)) - 1;
+    // the exponent of the result, the smallest normal one for subnormals
+    let e = scale.max(1 - bias);
+    // bits of `frac` below the significand
+    let shift = 128 - (man_bits as i32 + 1 - (e - scale));
+    let (m, up) = match shift {
+        s if s > 128 => (0, false),
+        128 => (0, frac > 1 << 127 || (frac == 1 << 127 && sticky)),
+        s => {
+            let m = frac >> s;
+            let rem = frac & ((1 << s) - 1);
+            let half = 1 << (s - 1);
+            (m, rem > half || (rem == half && (sticky || (m & 1) != 0)))
+        }
+    };
+    // a carry out of the significand increments the exponent
+    let bits = (((e + bias - 1) as u64) << man_bits) + (m as u64) + (up as u64);
+    let inf = ((1 << exp_bits) - 1) << man_bits;
+    ((sign as u64) << (man_bits + exp_bits)) | bits.min(inf)
+}
+
+/// Rounds the number to the nearest integer, ties to even.
+pub(crate) fn to_int<T>(limbs: &[u64], one_bit: i32) -> Result<T, PositConversionError>
+where
+    T: TryFrom<u128> + TryFrom<i128>,
+{
+    let len = limbs.len();
+    let mut int = [0; MAX_LIMBS];
+    let int = &mut int[..len];
+    shifted(limbs, -one_bit, int);
+    // the fraction, which is nonnegative, compared to one half
+    let bit = |i: i32| ((limbs[len - 1 - (i / 64) as usize] >> (i % 64)) & 1) != 0;
+    if bit(one_bit - 1) && ((0..one_bit - 1).any(bit) || (int[len - 1] & 1) != 0) {
+        for limb in int.iter_mut().rev() {
+            *limb = limb.wrapping_add(1);
+            if *limb != 0 {
+                break;
+            }
+        }
+    }
+
+    let sign = is_negative(int);
+    let fill = if sign { u64::MAX } else { 0 };
+    let limb = |i: usize| if i < len { int[len - 1 - i] } else { fill };
+    let low = ((limb(1) as u128) << 64) | limb(0) as u128;
+    let fits = int[..len.saturating_sub(2)].iter().all(|&l| l == fill);
+    let int = match (sign, fits) {
+        (false, true) => low,
+        (false, false) => return Err(PositConversionError::Overflow),
+        (true, true) if (low >> 127) != 0 => low.wrapping_neg(),
+        // beyond the range of every integer type
+        (true, _) => u128::MAX,
+    };
+    crate::convert::sign_magnitude_to_int(sign, int)
+}
+
+/// Sets the `width`-bit number, sign-extended to the limbs, to
+/// `±mag * 2^exp`. Returns `false` and leaves it unchanged if that is not
+/// representable exactly.
+pub(crate) fn load(
+    limbs: &mut [u64],
+    width: u32,
+    one_bit: i32,
+    (sign, mag, exp): (bool, u128, i32),
+) -> bool {
+    if mag == 0 {
+        limbs.iter_mut().for_each(|l| *l = 0);
+        return true;
+    }
+    let tz = mag.trailing_zeros();
+    let (mag, lsb) = (mag >> tz, exp + tz as i32 + one_bit);
+    let msb = lsb + 127 - mag.leading_zeros() as i32;
+    // -2^(width - 1) is the bit pattern of NaR
+    if lsb < 0 || msb >= width as i32 - 1 {
+        return false;
+    }
+    limbs.iter_mut().for_each(|l| *l = 0);
+    add_shifted(limbs, mag, lsb, sign);
+    true
+}
+
+/// Splits a finite float into the arguments of `load`.
+pub(crate) fn f64_parts(x: f64) -> (bool, u128, i32) {
+    let bits = x.to_bits();
+    let exp = ((bits >> 52) & 0x7ff) as i32;
+    let frac = (bits & ((1 << 52) - 1)) as u128;
+    let (mag, exp) = if exp == 0 {
+        (frac, -1074)
+    } else {
+        (frac | (1 << 52), exp - 1075)
+    };
+    ((bits >> 63) != 0, mag, exp)
+}
+
+/// Whether the `width`-bit number, sign-extended to the limbs, is
+/// `-2^(width - 1)`, the bit pattern of NaR.
+pub(crate) fn is_nar(limbs: &[u64], width: u32) -> bool {
+    let len = limbs.len();
+    let mut top = [0; MAX_LIMBS];
+    let top = &mut top[..len];
+    shifted(limbs, (len * 64) as i32 - width as i32, top);
+    top[0] == 1 << 63 && top[1..].iter().all(|&l| l == 0)
+}
+
+/// Multiplies the unsigned number by `m` and adds `a`, returning the carry
+/// out of the most significant limb.
+pub(crate) fn mul_add_small(limbs: &mut [u64], m: u64, a: u64) -> u64 {
+    let mut carry = a as u128;
+    for limb in limbs.iter_mut().rev() {
+        let z = (*limb as u128) * (m as u128) + carry;
+        *limb = z as u64;
+        carry = z >> 64;
+    }
+    carry as u64
+}
+
+/// Divides the unsigned number by `d`, returning the remainder.
+pub(crate) fn div_small(limbs: &mut [u64], d: u64) -> u64 {
+    let mut rem = 0_u128;
+    for limb in limbs.iter_mut() {
+        let n = (rem << 64) | *limb as u128;
+        *limb = (n / d as u128) as u64;
+        rem = n % d as u128;
+    }
+    rem as u64
+}
+
+/// Exact value of the number in units of the least significant bit.
+#[cfg(test)]
+pub(crate) fn value(limbs: &[u64]) -> num_bigint::BigInt {
+    use num_bigint::{BigInt, Sign};
+    let mut bytes = [0_u8; MAX_LIMBS * 8];
+    let bytes = &mut bytes[..limbs.len() * 8];
+    for (chunk, limb) in bytes.chunks_mut(8).zip(limbs.iter()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    let m = BigInt::from_bytes_be(Sign::Plus, bytes);
+    if (limbs[0] >> 63) != 0 {
+        m - (BigInt::from(1) << (limbs.len() * 64))
+    } else {
+        m
+    }
+}
+
+/// `|v| / 2^shift` rounded to nearest, ties to even.
+#[cfg(test)]
+fn round_reference(v: &num_bigint::BigInt, shift: i32) -> num_bigint::BigInt {
+    use num_bigint::BigInt;
+    use num_traits::Signed;
+    let a = v.abs();
+    if shift <= 0 {
+        return a << -shift as usize;
+    }
+    let m = &a >> shift as usize;
+    let twice = (&a - (&m << shift as usize)) << 1;
+    let unit = BigInt::from(1) << shift as usize;
+    if twice > unit || (twice == unit && &m % 2 == BigInt::from(1)) {
+        m + 1
+    } else {
+        m
+    }
+}
+
+/// Correctly rounded float value of `v * 2^-one_bit`, computed in `f64`.
+#[cfg(test)]
+fn float_reference(v: &num_bigint::BigInt, one_bit: i32, man_bits: u32, exp_bits: u32) -> f64 {
+    use num_bigint::Sign;
+    use num_traits::ToPrimitive;
+    if v.sign() == Sign::NoSign {
+        return 0.;
+    }
+    let msb = v.bits() as i32 - 1;
+    let bias = (1 << (exp_bits - 1)) - 1;
+    let e = (msb - one_bit).max(1 - bias);
+    let m = round_reference(v, e - man_bits as i32 + one_bit);
+    // both factors and the product are exact, or the product overflows
+    let r = m.to_f64().unwrap() * 2_f64.powi(-(man_bits as i32)) * 2_f64.powi(e);
+    if v.sign() == Sign::Minus {
+        -r
+    } else {
+        r
+    }
+}
+
+/// Random `width`-bit number sign-extended to the limbs, of any magnitude.
+#[cfg(test)]
+pub(crate) fn random<const N: usize>(rng: &mut impl rand::Rng, width: u32) -> [u64; N] {
+    let mut bits = [0; N];
+    rng.fill(&mut bits[..]);
+    let mut limbs = [0; N];
+    let len = (N * 64) as i32;
+    shifted(&bits, -rng.gen_range(len - width as i32, len), &mut limbs);
+    limbs
+}
+
+/// Checks the conversions of a `width`-bit quire to and from the primitive
+/// types against exact references, on random values of every magnitude.
+#[cfg(test)]
+pub(crate) fn test_convert<Q, const N: usize>(
+    width: u32,
+    one_bit: i32,
+    to_limbs: fn(&Q) -> [u64; N],
+    from_limbs: fn([u64; N]) -> Q,
+) where
+    Q: num_traits::ToPrimitive + num_traits::FromPrimitive,
+{
+    use num_bigint::{BigInt, Sign};
+    use num_traits::{Float, Signed, ToPrimitive};
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let nar = -(BigInt::from(1) << (width - 1) as usize);
+    // the value in units of the least significant bit, if it fits
+    let fits = |units: BigInt| {
+        if units.abs() < nar.abs() {
+            Some(units)
+        } else {
+            None
+        }
+    };
+    for _ in 0..crate::NTESTS16 {
+        let limbs = random(&mut rng, width);
+        let v = value(&limbs);
+        if v == nar {
+            continue;
+        }
+        let q = from_limbs(limbs);
+        let x = q.to_f64().unwrap();
+        assert_eq!(x.to_bits(), float_reference(&v, one_bit, 52, 11).to_bits());
+        let y = q.to_f32().unwrap();
+        assert_eq!(
+            y.to_bits(),
+            (float_reference(&v, one_bit, 23, 8) as f32).to_bits()
+        );
+        let int = round_reference(&v, one_bit);
+        let int = if v.sign() == Sign::Minus { -int } else { int };
+        assert_eq!(q.to_i64(), int.to_i64());
+        assert_eq!(q.to_u64(), int.to_u64());
+        assert_eq!(q.to_i128(), int.to_i128());
+        assert_eq!(q.to_u128(), int.to_u128());
+
+        // exactly representable, one bit off, or anything
+        let x = match rng.gen_range(0, 3) {
+            0 => x,
+            1 => f64::from_bits(x.to_bits() ^ 1),
+            _ => f64::from_bits(rng.gen()),
+        };
+        let loaded = Q::from_f64(x).map(|q| value(&to_limbs(&q)));
+        if x.is_finite() {
+            let (mant, exp, sign) = x.integer_decode();
+            let units = BigInt::from(sign) * BigInt::from(mant);
+            let shift = exp as i32 + one_bit;
+            let units = if shift >= 0 {
+                fits(units << shift as usize)
+            } else if (&units % (BigInt::from(1) << -shift as usize)) == BigInt::from(0) {
+                fits(units >> -shift as usize)
+            } else {
+                None
+            };
+            assert_eq!(loaded, units);
+        } else {
+            assert_eq!(loaded, Some(nar.clone()));
+        }
+        let n = rng.gen::<i128>() >> rng.gen_range(0, 128);
+        let load = |n: BigInt| fits(n << one_bit as usize);
+        let value = |q: Option<Q>| q.map(|q| value(&to_limbs(&q)));
+        assert_eq!(value(Q::from_i128(n)), load(BigInt::from(n)));
+        assert_eq!(
+            value(Q::from_u128(n as u128)),
+            load(BigInt::from(n as u128))
+        );
+        assert_eq!(value(Q::from_i64(n as i64)), load(BigInt::from(n as i64)));
+    }
+}
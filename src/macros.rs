@@ -1,7 +1,7 @@
 #[macro_export]
 macro_rules! impl_num_traits {
-    ($posit:ty) => {
-        impl num_traits::Zero for $posit {
+    ([$($generics:tt)*] $posit:ty, $n:expr, $es:expr) => {
+        impl<$($generics)*> num_traits::Zero for $posit {
             fn zero() -> Self {
                 Self::ZERO
             }
@@ -10,7 +10,7 @@ macro_rules! impl_num_traits {
             }
         }
 
-        impl num_traits::One for $posit {
+        impl<$($generics)*> num_traits::One for $posit {
             #[inline]
             fn one() -> Self {
                 Self::ONE
@@ -21,32 +21,47 @@ macro_rules! impl_num_traits {
             }
         }
 
-        impl num_traits::Num for $posit {
-            type FromStrRadixErr = num_traits::ParseFloatError;
+        impl<$($generics)*> num_traits::Num for $posit {
+            type FromStrRadixErr = $crate::ParsePositError;
             fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-                Ok(Self::from(f64::from_str_radix(src, radix)?))
+                if radix == 10 {
+                    return src.parse();
+                }
+                match f64::from_str_radix(src, radix) {
+                    Ok(f) => Ok(Self::from(f)),
+                    Err(e) => Err(match e.kind {
+                        num_traits::FloatErrorKind::Empty => $crate::ParsePositError::Empty,
+                        num_traits::FloatErrorKind::Invalid => $crate::ParsePositError::Invalid,
+                    }),
+                }
             }
         }
 
-        impl num_traits::ToPrimitive for $posit {
+        impl<$($generics)*> num_traits::ToPrimitive for $posit {
             fn to_i64(&self) -> Option<i64> {
-                Some(i64::from(*self))
+                self.to_int_round().ok()
             }
             fn to_u64(&self) -> Option<u64> {
-                Some(u64::from(*self))
+                self.to_int_round().ok()
+            }
+            fn to_i128(&self) -> Option<i128> {
+                self.to_int_round().ok()
+            }
+            fn to_u128(&self) -> Option<u128> {
+                self.to_int_round().ok()
             }
             fn to_f64(&self) -> Option<f64> {
                 Some(f64::from(*self))
             }
         }
 
-        impl num_traits::NumCast for $posit {
-            fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+        impl<$($generics)*> num_traits::NumCast for $posit {
+            fn from<T: num_traits::ToPrimitive>(n: T) -> Option<Self> {
                 n.to_f64().map(|x| x.into())
             }
         }
 
-        impl num_traits::FromPrimitive for $posit {
+        impl<$($generics)*> num_traits::FromPrimitive for $posit {
             #[inline]
             fn from_i8(n: i8) -> Option<$posit> {
                 Some((n as i32).into())
@@ -91,16 +106,12 @@ macro_rules! impl_num_traits {
             }
         }
 
-        impl num_traits::Signed for $posit {
+        impl<$($generics)*> num_traits::Signed for $posit {
             fn abs(&self) -> Self {
                 Self::abs(*self)
             }
             fn abs_sub(&self, other: &Self) -> Self {
-                if *self <= *other {
-                    Self::ZERO
-                } else {
-                    *self - *other
-                }
+                num_traits::Float::abs_sub(*self, *other)
             }
             fn signum(&self) -> Self {
                 Self::signum(*self)
@@ -113,7 +124,7 @@ macro_rules! impl_num_traits {
             }
         }
 
-        impl num_traits::Float for $posit {
+        impl<$($generics)*> num_traits::Float for $posit {
             fn nan() -> Self {
                 Self::NAR
             }
@@ -132,6 +143,9 @@ macro_rules! impl_num_traits {
             fn min_positive_value() -> Self {
                 Self::MIN_POSITIVE
             }
+            fn epsilon() -> Self {
+                Self::EPSILON
+            }
             fn max_value() -> Self {
                 Self::MAX
             }
@@ -216,8 +230,14 @@ macro_rules! impl_num_traits {
             fn min(self, other: Self) -> Self {
                 core::cmp::Ord::min(self, other)
             }
-            fn abs_sub(self, _other: Self) -> Self {
-                unimplemented!()
+            fn abs_sub(self, other: Self) -> Self {
+                if self.is_nar() || other.is_nar() {
+                    Self::NAR
+                } else if self <= other {
+                    Self::ZERO
+                } else {
+                    self - other
+                }
             }
             fn cbrt(self) -> Self {
                 Self::cbrt(self)
@@ -274,12 +294,12 @@ macro_rules! impl_num_traits {
                 Self::atanh(self)
             }
             fn integer_decode(self) -> (u64, i16, i8) {
-                unimplemented!()
+                $crate::fields::integer_decode(self.to_bits() as u64, $n, $es)
             }
         }
 
         use crate::MathConsts;
-        impl num_traits::FloatConst for $posit {
+        impl<$($generics)*> num_traits::FloatConst for $posit {
             fn E() -> Self {
                 MathConsts::E
             }
@@ -328,9 +348,15 @@ macro_rules! impl_num_traits {
             fn SQRT_2() -> Self {
                 MathConsts::SQRT_2
             }
+            fn LOG2_10() -> Self {
+                MathConsts::LOG2_10
+            }
+            fn LOG10_2() -> Self {
+                MathConsts::LOG10_2
+            }
         }
 
-        impl num_traits::Bounded for $posit {
+        impl<$($generics)*> num_traits::Bounded for $posit {
             fn min_value() -> Self {
                 Self::MIN
             }
@@ -339,6 +365,9 @@ macro_rules! impl_num_traits {
             }
         }
     };
+    ($posit:ty, $n:expr, $es:expr) => {
+        $crate::impl_num_traits!([] $posit, $n, $es);
+    };
 }
 
 #[cfg(feature = "alga")]
@@ -906,13 +935,6 @@ macro_rules! impl_convert {
             }
         }
 
-        impl From<$posit> for i8 {
-            #[inline]
-            fn from(a: $posit) -> Self {
-                i32::from(a) as i8
-            }
-        }
-
         impl From<i16> for $posit {
             #[inline]
             fn from(a: i16) -> Self {
@@ -920,13 +942,6 @@ macro_rules! impl_convert {
             }
         }
 
-        impl From<$posit> for i16 {
-            #[inline]
-            fn from(a: $posit) -> Self {
-                i32::from(a) as i16
-            }
-        }
-
         impl From<isize> for $posit {
             #[inline]
             fn from(a: isize) -> Self {
@@ -934,13 +949,6 @@ macro_rules! impl_convert {
             }
         }
 
-        impl From<$posit> for isize {
-            #[inline]
-            fn from(a: $posit) -> Self {
-                i64::from(a) as isize
-            }
-        }
-
         impl From<u8> for $posit {
             #[inline]
             fn from(a: u8) -> Self {
@@ -948,13 +956,6 @@ macro_rules! impl_convert {
             }
         }
 
-        impl From<$posit> for u8 {
-            #[inline]
-            fn from(a: $posit) -> Self {
-                u32::from(a) as u8
-            }
-        }
-
         impl From<u16> for $posit {
             #[inline]
             fn from(a: u16) -> Self {
@@ -962,24 +963,99 @@ macro_rules! impl_convert {
             }
         }
 
-        impl From<$posit> for u16 {
-            #[inline]
-            fn from(a: $posit) -> Self {
-                u32::from(a) as u16
-            }
-        }
-
         impl From<usize> for $posit {
             #[inline]
             fn from(a: usize) -> Self {
                 Self::from(a as u64)
             }
         }
+    };
+}
 
-        impl From<$posit> for usize {
+/// Implements `TrigPi` with the inherent methods of the same names.
+#[macro_export]
+macro_rules! impl_trig_pi {
+    ($posit:ty) => {
+        impl $crate::TrigPi for $posit {
+            #[inline]
+            fn sin_pi(self) -> Self {
+                Self::sin_pi(self)
+            }
+            #[inline]
+            fn cos_pi(self) -> Self {
+                Self::cos_pi(self)
+            }
+            #[inline]
+            fn tan_pi(self) -> Self {
+                Self::tan_pi(self)
+            }
             #[inline]
-            fn from(a: $posit) -> Self {
-                u64::from(a) as usize
+            fn asin_pi(self) -> Self {
+                Self::asin_pi(self)
+            }
+            #[inline]
+            fn acos_pi(self) -> Self {
+                Self::acos_pi(self)
+            }
+            #[inline]
+            fn atan_pi(self) -> Self {
+                Self::atan_pi(self)
+            }
+        }
+    };
+}
+
+/// Implements the error and gamma functions of a posit type of at most 32
+/// bits by rounding the posit64 kernels once.
+///
+/// The kernels are within about `2^-50` of the exact value, relative to it, or
+/// absolute for an `lgamma` smaller than one, and a posit64 has 32 more
+/// fraction bits than the posit at every scale. So the results are not always
+/// correctly rounded: they may be one ulp off where the exact value is within
+/// about `2^-48` of a rounding boundary.
+#[macro_export]
+macro_rules! impl_special {
+    ($posit:ty) => {
+        impl $posit {
+            /// Error function, within one ulp.
+            ///
+            /// The result is correctly rounded unless the exact value lies within
+            /// about `2^-48` (relative) of a rounding boundary.
+            pub fn erf(self) -> Self {
+                Self::from($crate::P64E2::from(self).erf())
+            }
+
+            /// Complementary error function, `1 - erf(self)`, within one ulp also
+            /// where it is much smaller than one.
+            ///
+            /// The result is correctly rounded unless the exact value lies within
+            /// about `2^-48` (relative) of a rounding boundary.
+            pub fn erfc(self) -> Self {
+                Self::from($crate::P64E2::from(self).erfc())
+            }
+
+            /// Gamma function, within one ulp.
+            ///
+            /// Returns NaR at the poles, zero and the negative integers. Results
+            /// beyond the range of the posit saturate at `MAX`, or at
+            /// `MIN_POSITIVE` with the sign of the exact result. The result is
+            /// correctly rounded unless the exact value lies within about `2^-48`
+            /// (relative) of a rounding boundary.
+            pub fn tgamma(self) -> Self {
+                Self::from($crate::P64E2::from(self).tgamma())
+            }
+
+            /// Natural logarithm of the absolute value of the gamma function.
+            ///
+            /// Returns NaR at the poles, zero and the negative integers. The error
+            /// of the kernel is about `2^-50`, relative to the result where it is
+            /// at least one and absolute where it is smaller. So the result is
+            /// correctly rounded unless the exact value lies within about `2^-48`
+            /// of a rounding boundary in those terms, and within one ulp except
+            /// for results much smaller than `2^-48` next to the zeros on the
+            /// negative axis.
+            pub fn lgamma(self) -> Self {
+                Self::from($crate::P64E2::from(self).lgamma())
             }
         }
     };
@@ -1049,6 +1125,249 @@ macro_rules! quire_add_sub_array {
     )*}
 }
 
+/// Implements the exact addition and subtraction of two quires of the same
+/// type with the `add_quire` function of the module.
+#[macro_export]
+macro_rules! quire_add_sub_quire {
+    ($quire:ty) => {
+        impl ops::AddAssign<&$quire> for $quire {
+            #[inline]
+            fn add_assign(&mut self, rhs: &$quire) {
+                add_quire(self, rhs, true);
+            }
+        }
+
+        impl ops::SubAssign<&$quire> for $quire {
+            #[inline]
+            fn sub_assign(&mut self, rhs: &$quire) {
+                add_quire(self, rhs, false);
+            }
+        }
+
+        impl ops::AddAssign for $quire {
+            #[inline]
+            fn add_assign(&mut self, rhs: $quire) {
+                *self += &rhs;
+            }
+        }
+
+        impl ops::SubAssign for $quire {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $quire) {
+                *self -= &rhs;
+            }
+        }
+
+        impl ops::Add<&$quire> for $quire {
+            type Output = $quire;
+            #[inline]
+            fn add(mut self, rhs: &$quire) -> $quire {
+                self += rhs;
+                self
+            }
+        }
+
+        impl ops::Sub<&$quire> for $quire {
+            type Output = $quire;
+            #[inline]
+            fn sub(mut self, rhs: &$quire) -> $quire {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl ops::Add for $quire {
+            type Output = $quire;
+            #[inline]
+            fn add(self, rhs: $quire) -> $quire {
+                self + &rhs
+            }
+        }
+
+        impl ops::Sub for $quire {
+            type Output = $quire;
+            #[inline]
+            fn sub(self, rhs: $quire) -> $quire {
+                self - &rhs
+            }
+        }
+    };
+}
+
+/// Conversions of a quire to floats and integers, rounded once to nearest,
+/// ties to even, and exact conversions from them. The quire is a `width`-bit
+/// number sign-extended to `to_limbs`, with the least significant bit of
+/// weight `2^-one_bit`.
+#[macro_export]
+macro_rules! impl_quire_convert {
+    (@try_from $quire:ty, $one_bit:expr; $($int:ty),*) => {$(
+        impl core::convert::TryFrom<&$quire> for $int {
+            type Error = $crate::PositConversionError;
+            #[inline]
+            fn try_from(q_a: &$quire) -> Result<Self, Self::Error> {
+                if q_a.is_nar() {
+                    return Err($crate::PositConversionError::NaR);
+                }
+                $crate::limbs::to_int(&q_a.to_limbs(), $one_bit)
+            }
+        }
+
+        impl core::convert::TryFrom<$quire> for $int {
+            type Error = $crate::PositConversionError;
+            #[inline]
+            fn try_from(q_a: $quire) -> Result<Self, Self::Error> {
+                Self::try_from(&q_a)
+            }
+        }
+    )*};
+    ($quire:ty, $width:expr, $one_bit:expr) => {
+        impl $quire {
+            fn load(parts: (bool, u128, i32)) -> Option<Self> {
+                let mut limbs = Self::ZERO.to_limbs();
+                if $crate::limbs::load(&mut limbs, $width, $one_bit, parts) {
+                    Some(Self::from_limbs(limbs))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl From<&$quire> for f64 {
+            #[inline]
+            fn from(q_a: &$quire) -> Self {
+                if q_a.is_nar() {
+                    return f64::NAN;
+                }
+                f64::from_bits($crate::limbs::to_float(&q_a.to_limbs(), $one_bit, 52, 11))
+            }
+        }
+
+        impl From<$quire> for f64 {
+            #[inline]
+            fn from(q_a: $quire) -> Self {
+                Self::from(&q_a)
+            }
+        }
+
+        impl From<&$quire> for f32 {
+            #[inline]
+            fn from(q_a: &$quire) -> Self {
+                if q_a.is_nar() {
+                    return f32::NAN;
+                }
+                f32::from_bits($crate::limbs::to_float(&q_a.to_limbs(), $one_bit, 23, 8) as u32)
+            }
+        }
+
+        impl From<$quire> for f32 {
+            #[inline]
+            fn from(q_a: $quire) -> Self {
+                Self::from(&q_a)
+            }
+        }
+
+        $crate::impl_quire_convert!(
+            @try_from $quire, $one_bit;
+            i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+        );
+
+        impl num_traits::ToPrimitive for $quire {
+            fn to_i64(&self) -> Option<i64> {
+                core::convert::TryFrom::try_from(self).ok()
+            }
+            fn to_u64(&self) -> Option<u64> {
+                core::convert::TryFrom::try_from(self).ok()
+            }
+            fn to_i128(&self) -> Option<i128> {
+                core::convert::TryFrom::try_from(self).ok()
+            }
+            fn to_u128(&self) -> Option<u128> {
+                core::convert::TryFrom::try_from(self).ok()
+            }
+            fn to_f32(&self) -> Option<f32> {
+                Some(f32::from(self))
+            }
+            fn to_f64(&self) -> Option<f64> {
+                Some(f64::from(self))
+            }
+        }
+
+        /// Exact conversions, which fail if the value is not representable
+        /// in the quire. Infinities and NaN convert to NaR.
+        impl num_traits::FromPrimitive for $quire {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                Self::from_i128(n.into())
+            }
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                Self::from_u128(n.into())
+            }
+            #[inline]
+            fn from_i128(n: i128) -> Option<Self> {
+                Self::load((n < 0, n.unsigned_abs(), 0))
+            }
+            #[inline]
+            fn from_u128(n: u128) -> Option<Self> {
+                Self::load((false, n, 0))
+            }
+            #[inline]
+            fn from_f32(n: f32) -> Option<Self> {
+                Self::from_f64(n.into())
+            }
+            fn from_f64(n: f64) -> Option<Self> {
+                if !n.is_finite() {
+                    return Some(Self::NAR);
+                }
+                Self::load($crate::limbs::f64_parts(n))
+            }
+        }
+    };
+}
+
+/// Exact decimal and hexadecimal formatting of a quire, and parsing rounded
+/// to its least significant bit, with the layout of `impl_quire_convert`.
+#[macro_export]
+macro_rules! impl_quire_fmt {
+    ($quire:ty, $width:expr, $one_bit:expr) => {
+        impl core::fmt::Display for $quire {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::format::fmt_quire(&self.to_limbs(), $width, $one_bit, f, None)
+            }
+        }
+
+        impl core::fmt::LowerExp for $quire {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::format::fmt_quire(&self.to_limbs(), $width, $one_bit, f, Some("e"))
+            }
+        }
+
+        impl core::fmt::UpperExp for $quire {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::format::fmt_quire(&self.to_limbs(), $width, $one_bit, f, Some("E"))
+            }
+        }
+
+        impl core::fmt::LowerHex for $quire {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::format::fmt_quire_hex(&self.to_limbs(), $width, $one_bit, f)
+            }
+        }
+
+        impl core::str::FromStr for $quire {
+            type Err = $crate::ParsePositError;
+            fn from_str(src: &str) -> Result<Self, Self::Err> {
+                let mut limbs = Self::ZERO.to_limbs();
+                if $crate::parse::parse_quire(src, &mut limbs, $width, $one_bit)? {
+                    Ok(Self::from_limbs(limbs))
+                } else {
+                    Ok(Self::NAR)
+                }
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! quire_add_sub {
     ($posit:ty, $quire:ty) => {
@@ -1250,3 +1569,911 @@ macro_rules! quire_add_sub_x {
         }
     };
 }
+
+/// Arithmetic of a posit type on its unpacked bits, rounding every result
+/// once.
+#[macro_export]
+macro_rules! impl_xfloat_ops {
+    ($posit:ty, $n:literal, $es:literal) => {
+        impl core::ops::Neg for $posit {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self::new(self.0.wrapping_neg())
+            }
+        }
+
+        impl core::ops::AddAssign for $posit {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other
+            }
+        }
+
+        impl core::ops::SubAssign for $posit {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other
+            }
+        }
+
+        impl core::ops::MulAssign for $posit {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other
+            }
+        }
+
+        impl core::ops::DivAssign for $posit {
+            #[inline]
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other
+            }
+        }
+
+        impl core::ops::RemAssign for $posit {
+            #[inline]
+            fn rem_assign(&mut self, other: Self) {
+                *self = *self % other
+            }
+        }
+
+        impl core::ops::Add for $posit {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                let (a, b) = (self.to_bits() as u64, other.to_bits() as u64);
+                Self::from_bits($crate::xfloat::add(a, b, $n, $es) as _)
+            }
+        }
+
+        impl core::ops::Sub for $posit {
+            type Output = Self;
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                self + (-other)
+            }
+        }
+
+        impl core::ops::Mul for $posit {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                let (a, b) = (self.to_bits() as u64, other.to_bits() as u64);
+                Self::from_bits($crate::xfloat::mul(a, b, $n, $es) as _)
+            }
+        }
+
+        impl core::ops::Div for $posit {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: Self) -> Self {
+                let (a, b) = (self.to_bits() as u64, other.to_bits() as u64);
+                Self::from_bits($crate::xfloat::div(a, b, $n, $es) as _)
+            }
+        }
+
+        $crate::impl_rem!($posit, $n, $es);
+    };
+}
+
+/// `sqrt`, `mul_add`, the rounding functions and the elementary functions of
+/// a posit type on its unpacked bits.
+///
+/// The elementary functions are evaluated in double precision and rounded
+/// once more. Posits up to 32 bits have fewer fraction bits than an `f64`,
+/// so that is almost always correctly rounded. Near one a posit64 has 59
+/// fraction bits, and the results are only accurate to the 53 bits of the
+/// intermediate `f64` (within about 64 ulps).
+#[macro_export]
+macro_rules! impl_xfloat_math {
+    ($posit:ty, $n:literal, $es:literal) => {
+        impl $posit {
+            /// Fused multiply-add, `self * b + c` rounded once.
+            #[inline]
+            pub fn mul_add(self, b: Self, c: Self) -> Self {
+                Self::from_bits($crate::xfloat::mul_add(
+                    self.to_bits() as u64,
+                    b.to_bits() as u64,
+                    c.to_bits() as u64,
+                    $n,
+                    $es,
+                ) as _)
+            }
+
+            /// Square root, correctly rounded.
+            ///
+            /// Returns NaR for negative numbers and NaR.
+            #[inline]
+            pub fn sqrt(self) -> Self {
+                Self::from_bits($crate::xfloat::sqrt(self.to_bits() as u64, $n, $es) as _)
+            }
+
+            /// Raises the number to an integer power.
+            ///
+            /// The result is correctly rounded unless the exact power lies within about
+            /// `n^2 2^-124` (relative) of a rounding boundary, where it may be one ulp
+            /// smaller in magnitude. `x.powi(0)` is 1 for every real `x`, and NaR for
+            /// zero raised to a negative power.
+            #[inline]
+            pub fn powi(self, n: i32) -> Self {
+                Self::from_bits($crate::xfloat::powi(self.to_bits() as u64, n, $n, $es) as _)
+            }
+
+            /// Rounds to the nearest integer, ties to even.
+            pub fn round(self) -> Self {
+                if self.is_nar() || self.is_zero() {
+                    return self;
+                }
+                let bits = self.to_bits() as u64;
+                match $crate::convert::posit_bits_to_u128(bits, $n, $es, $crate::convert::IntRounding::Nearest) {
+                    Ok((sign, int)) => Self::from_bits($crate::convert::u128_to_posit_bits(sign, int, $n, $es) as _),
+                    // far beyond the last fraction bit
+                    Err(_) => self,
+                }
+            }
+
+            /// Rounds toward zero.
+            pub fn trunc(self) -> Self {
+                if self.is_nar() || self.is_zero() {
+                    return self;
+                }
+                let (sign, scale, frac) = self.unpack();
+                if scale < 0 {
+                    Self::ZERO
+                } else if scale >= 63 {
+                    self
+                } else {
+                    let int = frac & !((1 << (63 - scale)) - 1);
+                    Self::encode(sign, scale, (int as u128) << 64, false)
+                }
+            }
+
+            #[inline]
+            pub fn floor(self) -> Self {
+                let t = self.trunc();
+                if self < t {
+                    t - Self::ONE
+                } else {
+                    t
+                }
+            }
+
+            #[inline]
+            pub fn ceil(self) -> Self {
+                let t = self.trunc();
+                if self > t {
+                    t + Self::ONE
+                } else {
+                    t
+                }
+            }
+
+            #[inline]
+            pub fn fract(self) -> Self {
+                self - self.trunc()
+            }
+        }
+
+        impl $posit {
+            /// Exponential function, `e^(self)`.
+            pub fn exp(self) -> Self {
+                if self.is_nar() {
+                    Self::NAR
+                } else {
+                    Self::saturate_exp(libm::exp(f64::from(self)))
+                }
+            }
+
+            /// Returns `2^(self)`.
+            pub fn exp2(self) -> Self {
+                if self.is_nar() {
+                    Self::NAR
+                } else {
+                    Self::saturate_exp(libm::exp2(f64::from(self)))
+                }
+            }
+
+            /// Returns `10^(self)`.
+            pub fn exp10(self) -> Self {
+                if self.is_nar() {
+                    Self::NAR
+                } else {
+                    Self::saturate_exp(libm::exp10(f64::from(self)))
+                }
+            }
+
+            /// Natural logarithm.
+            ///
+            /// Returns NaR for zero, negative numbers and NaR.
+            pub fn ln(self) -> Self {
+                if self <= Self::ZERO {
+                    Self::NAR
+                } else {
+                    Self::from(libm::log(f64::from(self)))
+                }
+            }
+
+            /// Base-2 logarithm.
+            ///
+            /// Returns NaR for zero, negative numbers and NaR.
+            pub fn log2(self) -> Self {
+                if self <= Self::ZERO {
+                    Self::NAR
+                } else {
+                    Self::from(libm::log2(f64::from(self)))
+                }
+            }
+
+            /// Base-10 logarithm.
+            ///
+            /// Returns NaR for zero, negative numbers and NaR.
+            pub fn log10(self) -> Self {
+                if self <= Self::ZERO {
+                    Self::NAR
+                } else {
+                    Self::from(libm::log10(f64::from(self)))
+                }
+            }
+
+            /// Logarithm with respect to an arbitrary base.
+            ///
+            /// Returns NaR if either number is zero, negative or NaR, or if the base
+            /// is one.
+            pub fn log(self, base: Self) -> Self {
+                if self <= Self::ZERO || base <= Self::ZERO || base == Self::ONE {
+                    Self::NAR
+                } else {
+                    Self::from(libm::log(f64::from(self)) / libm::log(f64::from(base)))
+                }
+            }
+
+            /// Sine of the number in radians.
+            pub fn sin(self) -> Self {
+                Self::from(libm::sin(f64::from(self)))
+            }
+
+            /// Cosine of the number in radians.
+            pub fn cos(self) -> Self {
+                Self::from(libm::cos(f64::from(self)))
+            }
+
+            /// Tangent of the number in radians.
+            pub fn tan(self) -> Self {
+                Self::from(libm::tan(f64::from(self)))
+            }
+
+            /// Arcsine in radians, in the range [-pi/2, pi/2].
+            ///
+            /// Returns NaR if the number is outside the range [-1, 1].
+            pub fn asin(self) -> Self {
+                Self::from(libm::asin(f64::from(self)))
+            }
+
+            /// Arccosine in radians, in the range [0, pi].
+            ///
+            /// Returns NaR if the number is outside the range [-1, 1].
+            pub fn acos(self) -> Self {
+                Self::from(libm::acos(f64::from(self)))
+            }
+
+            /// Arctangent in radians, in the range [-pi/2, pi/2].
+            pub fn atan(self) -> Self {
+                Self::from(libm::atan(f64::from(self)))
+            }
+
+            /// Four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians.
+            ///
+            /// Returns NaR if both numbers are zero.
+            pub fn atan2(self, other: Self) -> Self {
+                if self.is_zero() && other.is_zero() {
+                    Self::NAR
+                } else {
+                    Self::from(libm::atan2(f64::from(self), f64::from(other)))
+                }
+            }
+
+            #[inline]
+            pub fn sin_cos(self) -> (Self, Self) {
+                (self.sin(), self.cos())
+            }
+
+            /// Hyperbolic sine.
+            pub fn sinh(self) -> Self {
+                Self::saturate(libm::sinh(f64::from(self)))
+            }
+
+            /// Hyperbolic cosine.
+            pub fn cosh(self) -> Self {
+                Self::saturate(libm::cosh(f64::from(self)))
+            }
+
+            /// Hyperbolic tangent.
+            pub fn tanh(self) -> Self {
+                Self::from(libm::tanh(f64::from(self)))
+            }
+
+            /// Inverse hyperbolic sine.
+            pub fn asinh(self) -> Self {
+                Self::from(libm::asinh(f64::from(self)))
+            }
+
+            /// Inverse hyperbolic cosine.
+            ///
+            /// Returns NaR for numbers less than one.
+            pub fn acosh(self) -> Self {
+                if self < Self::ONE {
+                    Self::NAR
+                } else {
+                    Self::from(libm::acosh(f64::from(self)))
+                }
+            }
+
+            /// Inverse hyperbolic tangent.
+            ///
+            /// Returns NaR for numbers outside the open interval (-1, 1).
+            pub fn atanh(self) -> Self {
+                Self::from(libm::atanh(f64::from(self)))
+            }
+
+            /// Returns `e^(self) - 1`, accurate even if the number is close to zero.
+            pub fn exp_m1(self) -> Self {
+                Self::saturate(libm::expm1(f64::from(self)))
+            }
+
+            /// Returns `ln(1 + self)`, accurate even if the number is close to zero.
+            ///
+            /// Returns NaR for numbers less than or equal to -1.
+            pub fn ln_1p(self) -> Self {
+                if self <= -Self::ONE {
+                    Self::NAR
+                } else {
+                    Self::from(libm::log1p(f64::from(self)))
+                }
+            }
+
+            /// Cube root.
+            pub fn cbrt(self) -> Self {
+                Self::from(libm::cbrt(f64::from(self)))
+            }
+
+            /// Length of the hypotenuse of a right-angle triangle with legs `self`
+            /// and `other`.
+            pub fn hypot(self, other: Self) -> Self {
+                Self::from(libm::hypot(f64::from(self), f64::from(other)))
+            }
+
+            /// Raises the number to a posit power.
+            ///
+            /// NaR is returned if either argument is NaR, for zero raised to a negative
+            /// power, and for a negative number raised to a non-integer power. Otherwise
+            /// `x.powf(0)` and `1.powf(y)` are 1 for every `x` and `y`.
+            pub fn powf(self, other: Self) -> Self {
+                if self.is_nar() || other.is_nar() || (self.is_zero() && other < Self::ZERO) {
+                    return Self::NAR;
+                }
+                let f = libm::pow(f64::from(self), f64::from(other));
+                if f == 0. && !self.is_zero() {
+                    // underflow of the f64 range, posits never round to zero
+                    if f.is_sign_negative() {
+                        -Self::MIN_POSITIVE
+                    } else {
+                        Self::MIN_POSITIVE
+                    }
+                } else {
+                    Self::saturate(f)
+                }
+            }
+
+            /// Rounds a result evaluated in double precision, saturating to maxpos if
+            /// the evaluation overflowed the `f64` range.
+            fn saturate(f: f64) -> Self {
+                if f == f64::INFINITY {
+                    Self::MAX
+                } else if f == f64::NEG_INFINITY {
+                    Self::MIN
+                } else {
+                    Self::from(f)
+                }
+            }
+
+            /// Rounds a positive exponential, which never overflows to NaR nor
+            /// underflows to zero.
+            fn saturate_exp(f: f64) -> Self {
+                if f == 0. {
+                    Self::MIN_POSITIVE
+                } else if f.is_infinite() {
+                    Self::MAX
+                } else {
+                    Self::from(f)
+                }
+            }
+        }
+    };
+}
+
+/// Conversions of a posit type from and to `f32` and `f64`, and from the
+/// integers (except the 8 and 16 bit ones, see `impl_convert!`).
+#[macro_export]
+macro_rules! impl_xfloat_convert {
+    ($posit:ty, $n:literal, $es:literal) => {
+        impl From<f32> for $posit {
+            #[inline]
+            fn from(float: f32) -> Self {
+                Self::from(float as f64)
+            }
+        }
+
+        impl From<f64> for $posit {
+            #[inline]
+            fn from(float: f64) -> Self {
+                Self::from_bits($crate::convert::f64_to_posit_bits(float, $n, $es) as _)
+            }
+        }
+
+        impl From<$posit> for f32 {
+            #[inline]
+            fn from(p_a: $posit) -> Self {
+                let bits = $crate::convert::posit_to_ieee_bits(p_a.to_bits() as u64, $n, $es, 23, 127);
+                f32::from_bits(bits as u32)
+            }
+        }
+
+        impl From<$posit> for f64 {
+            #[inline]
+            fn from(p_a: $posit) -> Self {
+                let bits = $crate::convert::posit_to_ieee_bits(p_a.to_bits() as u64, $n, $es, 52, 1023);
+                f64::from_bits(bits)
+            }
+        }
+
+        $crate::impl_xfloat_from_int!($posit, $n, $es; i32, i64, i128);
+        $crate::impl_xfloat_from_uint!($posit, $n, $es; u32, u64, u128);
+    };
+}
+
+#[macro_export]
+macro_rules! impl_xfloat_from_int {
+    ($posit:ty, $n:literal, $es:literal; $($int:ty),*) => {$(
+        impl From<$int> for $posit {
+            #[inline]
+            fn from(a: $int) -> Self {
+                let bits = $crate::convert::u128_to_posit_bits(a < 0, (a as i128).unsigned_abs(), $n, $es);
+                Self::from_bits(bits as _)
+            }
+        }
+    )*}
+}
+
+#[macro_export]
+macro_rules! impl_xfloat_from_uint {
+    ($posit:ty, $n:literal, $es:literal; $($uint:ty),*) => {$(
+        impl From<$uint> for $posit {
+            #[inline]
+            fn from(a: $uint) -> Self {
+                Self::from_bits($crate::convert::u128_to_posit_bits(false, a as u128, $n, $es) as _)
+            }
+        }
+    )*}
+}
+
+/// Conversions of a posit type to the integers, which fail for NaR and for
+/// values out of range.
+#[macro_export]
+macro_rules! impl_int_convert {
+    (@try_from $generics:tt $posit:ty; $($int:ty),*) => {
+        $($crate::impl_int_convert!(@try_from $generics $posit, $int);)*
+    };
+    (@try_from [$($generics:tt)*] $posit:ty, $int:ty) => {
+        impl<$($generics)*> core::convert::TryFrom<$posit> for $int {
+            type Error = $crate::PositConversionError;
+            #[inline]
+            fn try_from(p_a: $posit) -> Result<Self, Self::Error> {
+                p_a.to_int_round()
+            }
+        }
+    };
+    ([$($generics:tt)*] $posit:ty, $n:expr, $es:expr) => {
+        impl<$($generics)*> $posit {
+            #[inline]
+            fn to_int<T>(self, rounding: $crate::convert::IntRounding) -> Result<T, $crate::PositConversionError>
+            where
+                T: core::convert::TryFrom<u128> + core::convert::TryFrom<i128>,
+            {
+                $crate::convert::posit_bits_to_int(self.to_bits() as u64, $n, $es, rounding)
+            }
+
+            /// Rounds to the largest integer less than or equal to the posit.
+            #[inline]
+            pub fn to_int_floor<T>(self) -> Result<T, $crate::PositConversionError>
+            where
+                T: core::convert::TryFrom<u128> + core::convert::TryFrom<i128>,
+            {
+                self.to_int($crate::convert::IntRounding::Floor)
+            }
+
+            /// Rounds to the smallest integer greater than or equal to the
+            /// posit.
+            #[inline]
+            pub fn to_int_ceil<T>(self) -> Result<T, $crate::PositConversionError>
+            where
+                T: core::convert::TryFrom<u128> + core::convert::TryFrom<i128>,
+            {
+                self.to_int($crate::convert::IntRounding::Ceil)
+            }
+
+            /// Rounds toward zero.
+            #[inline]
+            pub fn to_int_trunc<T>(self) -> Result<T, $crate::PositConversionError>
+            where
+                T: core::convert::TryFrom<u128> + core::convert::TryFrom<i128>,
+            {
+                self.to_int($crate::convert::IntRounding::Trunc)
+            }
+
+            /// Rounds to the nearest integer, ties to even, like `TryFrom`.
+            #[inline]
+            pub fn to_int_round<T>(self) -> Result<T, $crate::PositConversionError>
+            where
+                T: core::convert::TryFrom<u128> + core::convert::TryFrom<i128>,
+            {
+                self.to_int($crate::convert::IntRounding::Nearest)
+            }
+        }
+
+        $crate::impl_int_convert!(
+            @try_from [$($generics)*] $posit;
+            i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+        );
+    };
+    ($posit:ty, $n:expr, $es:expr) => {
+        $crate::impl_int_convert!([] $posit, $n, $es);
+    };
+}
+
+/// Exact `%`, `remainder`, `remquo`, `div_euclid` and `rem_euclid` of a posit
+/// type on its bits.
+#[macro_export]
+macro_rules! impl_rem {
+    ([$($generics:tt)*] $posit:ty, $n:expr, $es:expr) => {
+        impl<$($generics)*> $posit {
+            #[inline]
+            fn div_rem(self, rhs: Self, rounding: $crate::xfloat::QuotRounding) -> (Self, Self, i32) {
+                let (q, r, quo) = $crate::xfloat::div_rem(
+                    self.to_bits() as u64,
+                    rhs.to_bits() as u64,
+                    $n,
+                    $es,
+                    rounding,
+                );
+                (Self::from_bits(q as _), Self::from_bits(r as _), quo)
+            }
+
+            /// IEEE 754 remainder, `self - q * rhs` where `q` is `self / rhs`
+            /// rounded to the nearest integer, ties to even.
+            #[inline]
+            pub fn remainder(self, rhs: Self) -> Self {
+                self.div_rem(rhs, $crate::xfloat::QuotRounding::Nearest).1
+            }
+
+            /// The `remainder` and the lowest 31 bits of the integer quotient
+            /// with the sign of `self / rhs`, like C's `remquo`.
+            #[inline]
+            pub fn remquo(self, rhs: Self) -> (Self, i32) {
+                let (_, r, quo) = self.div_rem(rhs, $crate::xfloat::QuotRounding::Nearest);
+                (r, quo)
+            }
+
+            /// Euclidean division, the integer `q` such that
+            /// `self = q * rhs + self.rem_euclid(rhs)`, rounded to a posit.
+            #[inline]
+            pub fn div_euclid(self, rhs: Self) -> Self {
+                self.div_rem(rhs, $crate::xfloat::QuotRounding::Euclid).0
+            }
+
+            /// Least nonnegative remainder of `self (mod rhs)`.
+            #[inline]
+            pub fn rem_euclid(self, rhs: Self) -> Self {
+                self.div_rem(rhs, $crate::xfloat::QuotRounding::Euclid).1
+            }
+        }
+
+        impl<$($generics)*> core::ops::Rem for $posit {
+            type Output = Self;
+            /// Remainder of the division truncated toward zero, with the sign of
+            /// `self`, like `fmod`.
+            #[inline]
+            fn rem(self, other: Self) -> Self {
+                self.div_rem(other, $crate::xfloat::QuotRounding::Trunc).1
+            }
+        }
+    };
+    ($posit:ty, $n:expr, $es:expr) => {
+        $crate::impl_rem!([] $posit, $n, $es);
+    };
+}
+
+/// Rounding conversions between two posit types with `to_bits`/`from_bits`.
+#[macro_export]
+macro_rules! impl_xfloat_from_posit {
+    ($posit:ty, $n:literal, $es:literal; $($other:ty, $m:literal, $e:literal, $bits:ty);*) => {$(
+        impl From<$other> for $posit {
+            #[inline]
+            fn from(p_a: $other) -> Self {
+                Self::from_bits($crate::convert::convert_posit_bits(
+                    p_a.to_bits() as u64,
+                    $m,
+                    $e,
+                    $n,
+                    $es,
+                ) as _)
+            }
+        }
+
+        impl From<$posit> for $other {
+            #[inline]
+            fn from(p_a: $posit) -> Self {
+                Self::from_bits($crate::convert::convert_posit_bits(
+                    p_a.to_bits() as u64,
+                    $n,
+                    $es,
+                    $m,
+                    $e,
+                ) as $bits)
+            }
+        }
+    )*}
+}
+
+#[macro_export]
+macro_rules! impl_fmt {
+    ([$($generics:tt)*] $posit:ty, $n:expr, $es:expr) => {
+        impl<$($generics)*> core::fmt::Display for $posit {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::format::fmt_posit(self.to_bits() as u64, $n, $es, f, None)
+            }
+        }
+
+        impl<$($generics)*> core::fmt::LowerExp for $posit {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::format::fmt_posit(self.to_bits() as u64, $n, $es, f, Some("e"))
+            }
+        }
+
+        impl<$($generics)*> core::fmt::UpperExp for $posit {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $crate::format::fmt_posit(self.to_bits() as u64, $n, $es, f, Some("E"))
+            }
+        }
+    };
+    ($posit:ty, $n:expr, $es:expr) => {
+        $crate::impl_fmt!([] $posit, $n, $es);
+    };
+}
+
+#[macro_export]
+macro_rules! impl_fields {
+    ([$($generics:tt)*] $posit:ty, $n:expr, $es:expr) => {
+        impl<$($generics)*> $posit {
+            /// Splits the posit into the fields of its encoding, `None` for
+            /// zero and NaR.
+            #[inline]
+            pub fn decode(self) -> Option<$crate::PositFields> {
+                $crate::fields::decode(self.to_bits() as u64, $n, $es)
+            }
+
+            /// Builds the posit with the given fields, `None` if they are out
+            /// of range or need rounding.
+            #[inline]
+            pub fn from_fields(fields: $crate::PositFields) -> Option<Self> {
+                $crate::fields::encode(&fields, $n, $es).map(|bits| Self::from_bits(bits as _))
+            }
+        }
+    };
+    ($posit:ty, $n:expr, $es:expr) => {
+        $crate::impl_fields!([] $posit, $n, $es);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::real::Real;
+    use num_traits::{Float, FloatConst, Signed};
+
+    /// Calls every `Float`, `FloatConst`, `Signed` and `Real` method with `x`
+    /// and `y` and checks the ones with an exact result.
+    fn check<T>(x: T, y: T)
+    where
+        T: Float + FloatConst + Signed + core::fmt::Debug,
+    {
+        let nar = T::nan();
+        let unary = [
+            Float::floor,
+            Float::ceil,
+            Float::round,
+            Float::trunc,
+            Float::fract,
+            Float::abs,
+            Float::signum,
+            Float::recip,
+            Float::sqrt,
+            Float::exp,
+            Float::exp2,
+            Float::ln,
+            Float::log2,
+            Float::log10,
+            Float::to_degrees,
+            Float::to_radians,
+            Float::cbrt,
+            Float::sin,
+            Float::cos,
+            Float::tan,
+            Float::asin,
+            Float::acos,
+            Float::atan,
+            Float::exp_m1,
+            Float::ln_1p,
+            Float::sinh,
+            Float::cosh,
+            Float::tanh,
+            Float::asinh,
+            Float::acosh,
+            Float::atanh,
+            Real::floor,
+            Real::sqrt,
+            Real::exp,
+            Real::sin,
+            Real::atanh,
+        ];
+        for f in unary.iter() {
+            let r = f(x);
+            if x.is_nan() {
+                assert!(r.is_nan(), "{:?}", x);
+            }
+        }
+        let binary = [
+            Float::powf,
+            Float::log,
+            Float::max,
+            Float::min,
+            Float::abs_sub,
+            Float::hypot,
+            Float::atan2,
+            Float::copysign,
+            Real::powf,
+            Real::atan2,
+        ];
+        for f in binary.iter() {
+            f(x, y);
+        }
+        let (s, c) = Float::sin_cos(x);
+        assert_eq!((s.is_nan(), c.is_nan()), (x.is_nan(), x.is_nan()));
+        Float::mul_add(x, y, x);
+        for &n in [-3, -1, 0, 1, 2, 7].iter() {
+            Float::powi(x, n);
+        }
+        let (lo, hi) = if x < y { (x, y) } else { (y, x) };
+        if !lo.is_nan() {
+            assert!(Float::clamp(x, lo, hi) == x);
+        }
+        let _ = (x.is_nan(), x.is_infinite(), x.is_finite(), x.is_normal());
+        let _ = (x.is_subnormal(), x.classify(), x.to_f64(), x.to_i64());
+        let _ = (Signed::abs(&x), Signed::signum(&x), x.is_positive());
+
+        // the fields of `integer_decode` give back the value
+        let (m, e, sign) = x.integer_decode();
+        if x.is_nan() {
+            assert_eq!((m, e, sign), (0, 0, -1));
+        } else {
+            let f = sign as f64 * m as f64 * 2_f64.powi(e as i32);
+            assert_eq!(f, x.to_f64().unwrap(), "{:?}", x);
+        }
+
+        let d = Float::abs_sub(x, y);
+        assert!(Signed::abs_sub(&x, &y) == d);
+        if x.is_nan() || y.is_nan() {
+            assert!(d.is_nan());
+        } else if x <= y {
+            assert!(d.is_zero());
+        } else {
+            assert_eq!(d, x - y);
+        }
+
+        let consts = [
+            T::E(),
+            T::FRAC_1_PI(),
+            T::FRAC_1_SQRT_2(),
+            T::FRAC_2_PI(),
+            T::FRAC_2_SQRT_PI(),
+            T::FRAC_PI_2(),
+            T::FRAC_PI_3(),
+            T::FRAC_PI_4(),
+            T::FRAC_PI_6(),
+            T::FRAC_PI_8(),
+            T::LN_10(),
+            T::LN_2(),
+            T::LOG10_E(),
+            T::LOG2_E(),
+            T::PI(),
+            T::SQRT_2(),
+            T::TAU(),
+            T::LOG2_10(),
+            T::LOG10_2(),
+        ];
+        assert!(consts.iter().all(|c| c.is_sign_positive() && !c.is_nan()));
+        assert!(T::epsilon() > T::zero() && T::epsilon() < T::one());
+        assert!(nar.is_nan() && T::infinity().is_nan() && T::neg_zero().is_zero());
+        // NaR in, NaR out, as for `powi`, even with a zero exponent
+        assert!(Float::powf(nar, T::zero()).is_nan() && Float::powi(nar, 0).is_nan());
+        assert!(Float::powf(T::one(), nar).is_nan());
+    }
+
+    #[test]
+    fn num_traits_p8e0() {
+        use crate::P8E0;
+        for i in 0..0x100 {
+            for j in 0..0x100 {
+                check(P8E0::from_bits(i as u8), P8E0::from_bits(j as u8));
+            }
+        }
+    }
+
+    #[test]
+    fn num_traits_p16e1() {
+        use crate::P16E1;
+        for i in 0..0x1_0000 {
+            for &j in [
+                0,
+                1,
+                0x_4000,
+                0x_7fff,
+                0x_8000,
+                0x_c000,
+                0x_ffff,
+                i ^ 0x_5555,
+            ]
+            .iter()
+            {
+                check(P16E1::from_bits(i as u16), P16E1::from_bits(j as u16));
+            }
+        }
+    }
+
+    #[test]
+    fn num_traits_p32e2() {
+        use crate::P32E2;
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let special = [
+            P32E2::ZERO,
+            P32E2::NAR,
+            P32E2::MAX,
+            P32E2::MIN,
+            P32E2::MIN_POSITIVE,
+        ];
+        for &x in special.iter() {
+            for &y in special.iter() {
+                check(x, y);
+            }
+        }
+        for _ in 0..crate::NTESTS16 {
+            check(rng.gen::<P32E2>(), rng.gen());
+        }
+    }
+
+    #[test]
+    fn num_traits_standard() {
+        use crate::{P16E2, P64E2, P8E2};
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        for i in 0..0x100 {
+            for j in 0..0x100 {
+                check(P8E2::from_bits(i as u8), P8E2::from_bits(j as u8));
+            }
+        }
+        for _ in 0..crate::NTESTS8 {
+            check(rng.gen::<P16E2>(), rng.gen());
+            check(rng.gen::<P64E2>(), rng.gen());
+        }
+    }
+}
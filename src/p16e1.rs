@@ -4,7 +4,7 @@ use core::mem;
 mod convert;
 mod math;
 mod ops;
-crate::impl_num_traits!(P16E1);
+crate::impl_num_traits!(P16E1, 16, 1);
 #[cfg(feature = "approx")]
 crate::impl_ulps_eq!(P16E1, i16);
 #[cfg(feature = "approx")]
@@ -206,23 +206,6 @@ impl P16E1 {
         (k, tmp)
     }
 
-    #[inline]
-    fn calculate_scale(mut bits: u16) -> (u16, u16) {
-        let mut scale = 0_u16;
-        // Decode the posit, left-justifying as we go.
-        bits -= 0x4000; // Strip off first regime bit (which is a 1).
-        while (0x2000 & bits) != 0 {
-            // Increment scale by 2 for each regime sign bit.
-            scale += 2; // Regime sign bit is always 1 in this range.
-            bits = (bits - 0x2000) << 1; // Remove the bit; line up the next regime bit.
-        }
-        bits <<= 1; // Skip over termination bit, which is 0.
-        if (0x2000 & bits) != 0 {
-            scale += 1; // If exponent is 1, increment the scale.
-        }
-        (scale, bits)
-    }
-
     #[inline]
     pub(crate) fn calculate_regime(k: i8) -> (u16, bool, u32) {
         let len;
@@ -237,23 +220,24 @@ impl P16E1 {
 }
 
 impl core::str::FromStr for P16E1 {
-    type Err = core::num::ParseFloatError;
+    type Err = crate::ParsePositError;
     #[inline]
-    fn from_str(src: &str) -> Result<Self, core::num::ParseFloatError> {
-        Ok(Self::from(f64::from_str(src)?))
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_posit(src, 16, 1).map(|bits| Self::from_bits(bits as u16))
     }
 }
 
-use core::fmt;
-impl fmt::Display for P16E1 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", f64::from(*self))
-    }
-}
+crate::impl_fmt!(P16E1, 16, 1);
+crate::impl_fields!(P16E1, 16, 1);
 
+use core::fmt;
 impl fmt::Debug for P16E1 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "P16E1({})", self.0)
+        if f.alternate() {
+            crate::fields::debug(f, "P16E1", self.to_bits() as u64, 16, 1)
+        } else {
+            write!(f, "P16E1({})", self.0)
+        }
     }
 }
 
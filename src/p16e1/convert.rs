@@ -4,6 +4,9 @@ use core::convert::From;
 use core::{f32, f64};
 
 crate::impl_convert!(P16E1);
+crate::impl_int_convert!(P16E1, 16, 1);
+crate::impl_xfloat_from_int!(P16E1, 16, 1; i128);
+crate::impl_xfloat_from_uint!(P16E1, 16, 1; u128);
 
 impl From<i32> for P16E1 {
     #[inline]
@@ -104,197 +107,6 @@ fn convert_u64_to_p16bits(a: u64) -> u16 {
     }
 }
 
-impl From<P16E1> for i32 {
-    #[inline]
-    fn from(p_a: P16E1) -> Self {
-        let mut ui_a = p_a.to_bits(); // Copy of the input.
-
-        if ui_a == 0x8000 {
-            return i32::min_value();
-        }
-
-        let sign = ui_a > 0x8000; // sign is True if pA > NaR.
-        if sign {
-            ui_a = ui_a.wrapping_neg(); // A is now |A|.
-        }
-        let i_z = convert_p16bits_to_u32(ui_a);
-
-        i_z.with_sign(sign) as i32
-    }
-}
-
-impl From<P16E1> for u32 {
-    #[inline]
-    fn from(p_a: P16E1) -> Self {
-        let ui_a = p_a.to_bits(); // Copy of the input.
-
-        if ui_a >= 0x8000 {
-            return 0; //negative
-        }
-        convert_p16bits_to_u32(ui_a)
-    }
-}
-
-fn convert_p16bits_to_u32(ui_a: u16) -> u32 {
-    if ui_a <= 0x3000 {
-        // 0 <= |pA| <= 1/2 rounds to zero.
-        0
-    } else if ui_a < 0x4800 {
-        // 1/2 < x < 3/2 rounds to 1.
-        1
-    } else if ui_a <= 0x5400 {
-        // 3/2 <= x <= 5/2 rounds to 2.
-        2
-    } else {
-        let (scale, bits) = P16E1::calculate_scale(ui_a);
-
-        let mut i_z = ((bits as u32) | 0x2000) << 17; // Left-justify fraction in 32-bit result (one left bit padding)
-        let mut mask = 0x4000_0000_u32 >> scale; // Point to the last bit of the integer part.
-
-        let bit_last = (i_z & mask) != 0; // Extract the bit, without shifting it.
-        mask >>= 1;
-        let mut tmp = i_z & mask;
-        let bit_n_plus_one = tmp != 0; // "True" if nonzero.
-        i_z ^= tmp; // Erase the bit, if it was set.
-        tmp = i_z & (mask - 1); // tmp has any remaining bits. // This is bits_more
-        i_z ^= tmp; // Erase those bits, if any were set.
-
-        if bit_n_plus_one {
-            // logic for round to nearest, tie to even
-            if ((bit_last as u32) | tmp) != 0 {
-                i_z += mask << 1;
-            }
-        }
-        i_z >> (30 - scale) // Right-justify the integer.
-    }
-}
-
-fn convert_p16bits_to_u64(ui_a: u16) -> u64 {
-    if ui_a <= 0x3000 {
-        0
-    } else if ui_a < 0x4800 {
-        1
-    } else if ui_a <= 0x5400 {
-        2
-    } else {
-        let (scale, bits) = P16E1::calculate_scale(ui_a);
-
-        let mut i_z = ((bits as u64) | 0x2000) << 49;
-
-        let mut mask = 0x4000_0000_0000_0000_u64 >> scale;
-
-        let bit_last = (i_z & mask) != 0;
-        mask >>= 1;
-        let mut tmp = i_z & mask;
-        let bit_n_plus_one = tmp != 0;
-        i_z ^= tmp;
-        tmp = i_z & (mask - 1); // bits_more
-        i_z ^= tmp;
-
-        if bit_n_plus_one && (((bit_last as u64) | tmp) != 0) {
-            i_z += mask << 1;
-        }
-        i_z >> (62 - scale)
-    }
-}
-
-impl From<P16E1> for i64 {
-    #[inline]
-    fn from(p_a: P16E1) -> Self {
-        let mut ui_a = p_a.to_bits();
-
-        // NaR
-        if ui_a == 0x8000 {
-            return i64::min_value();
-        }
-
-        let sign = (ui_a & 0x_8000) != 0;
-        if sign {
-            ui_a = ui_a.wrapping_neg();
-        }
-
-        let i_z = convert_p16bits_to_u64(ui_a);
-
-        i_z.with_sign(sign) as i64
-    }
-}
-
-impl From<P16E1> for u64 {
-    #[inline]
-    fn from(p_a: P16E1) -> Self {
-        let ui_a = p_a.to_bits();
-
-        if ui_a >= 0x8000 {
-            return 0;
-        }
-        convert_p16bits_to_u64(ui_a)
-    }
-}
-
-fn check_extra_two_bits_p16(
-    mut float: f64,
-    mut temp: f64,
-    bits_n_plus_one: &mut bool,
-    bits_more: &mut bool,
-) {
-    temp /= 2.;
-    if temp <= float {
-        *bits_n_plus_one = true;
-        float -= temp;
-    }
-    if float > 0. {
-        *bits_more = true;
-    }
-}
-fn convert_fraction_p16(
-    mut float: f64,
-    mut frac_length: u8,
-    bits_n_plus_one: &mut bool,
-    bits_more: &mut bool,
-) -> u16 {
-    let mut frac = 0_u16;
-
-    if float == 0. {
-        return 0;
-    } else if float == f64::INFINITY {
-        return 0x8000;
-    }
-
-    float -= 1.; //remove hidden bit
-    if frac_length == 0 {
-        check_extra_two_bits_p16(float, 1., bits_n_plus_one, bits_more);
-    } else {
-        let mut temp = 1_f64;
-        loop {
-            temp /= 2.;
-            if temp <= float {
-                float -= temp;
-                frac_length -= 1;
-                frac = (frac << 1) + 1; //shift in one
-                if float == 0. {
-                    //put in the rest of the bits
-                    frac <<= frac_length as u8;
-                    break;
-                }
-
-                if frac_length == 0 {
-                    check_extra_two_bits_p16(float, temp, bits_n_plus_one, bits_more);
-                    break;
-                }
-            } else {
-                frac <<= 1; //shift in a zero
-                frac_length -= 1;
-                if frac_length == 0 {
-                    check_extra_two_bits_p16(float, temp, bits_n_plus_one, bits_more);
-                    break;
-                }
-            }
-        }
-    }
-
-    frac
-}
-
 impl From<f32> for P16E1 {
     fn from(float: f32) -> Self {
         Self::from(float as f64)
@@ -302,159 +114,9 @@ impl From<f32> for P16E1 {
 }
 
 impl From<f64> for P16E1 {
-    #[allow(clippy::cognitive_complexity)]
-    fn from(mut float: f64) -> Self {
-        let mut reg: u16;
-        let mut bit_n_plus_one = false;
-        let mut bits_more = false;
-        let mut frac = 0_u16;
-        let mut exp = 0_i8;
-
-        if float == 0. {
-            return Self::ZERO;
-        } else if !float.is_finite() {
-            return Self::NAR;
-        } else if float >= 268_435_456. {
-            //maxpos
-            return Self::MAX;
-        } else if float <= -268_435_456. {
-            // -maxpos
-            return Self::MIN;
-        }
-
-        let sign = float < 0.;
-
-        let u_z: u16 = if float == 1. {
-            0x4000
-        } else if float == -1. {
-            0xC000
-        } else if (float <= 3.725_290_298_461_914_e-9) && !sign {
-            //minpos
-            1
-        } else if (float >= -3.725_290_298_461_914_e-9) && sign {
-            //-minpos
-            0xFFFF
-        } else if (float > 1.) || (float < -1.) {
-            if sign {
-                //Make negative numbers positive for easier computation
-                float = -float;
-            }
-
-            reg = 1; //because k = m-1; so need to add back 1
-                     // minpos
-            if float <= 3.725_290_298_461_914_e-9 {
-                1
-            } else {
-                //regime
-                while float >= 4. {
-                    float *= 0.25;
-                    reg += 1;
-                }
-                if float >= 2. {
-                    float *= 0.5;
-                    exp += 1;
-                }
-
-                let frac_length = 13 - (reg as i8);
-
-                if frac_length < 0 {
-                    //reg == 14, means rounding bits is exp and just the rest.
-                    if float > 1. {
-                        bits_more = true;
-                    }
-                } else {
-                    frac = convert_fraction_p16(
-                        float,
-                        frac_length as u8,
-                        &mut bit_n_plus_one,
-                        &mut bits_more,
-                    );
-                }
-                if (reg == 14) && (frac > 0) {
-                    bits_more = true;
-                    frac = 0;
-                }
-                if reg > 14 {
-                    0x7FFF
-                } else {
-                    let regime = ((1_u16 << reg) - 1) << 1;
-                    let ex = if reg == 14 {
-                        0
-                    } else {
-                        (exp as u16) << (13 - reg)
-                    };
-                    let mut u_z = ((regime as u16) << (14 - reg)) + ex + frac;
-                    //n+1 frac bit is 1. Need to check if another bit is 1 too if not round to even
-                    if (reg == 14) && (exp != 0) {
-                        bit_n_plus_one = true;
-                    }
-                    u_z += ((bit_n_plus_one as u16) & (u_z & 1))
-                        | ((bit_n_plus_one & bits_more) as u16);
-                    u_z
-                }
-                .with_sign(sign)
-            }
-        } else if (float < 1.) || (float > -1.) {
-            if sign {
-                //Make negative numbers positive for easier computation
-                float = -float;
-            }
-            reg = 0;
-
-            //regime
-            while float < 1. {
-                float *= 4.;
-                reg += 1;
-            }
-            if float >= 2. {
-                float /= 2.;
-                exp += 1;
-            }
-            if reg == 14 {
-                bit_n_plus_one = exp != 0;
-                if frac > 1 {
-                    bits_more = true;
-                }
-            } else {
-                //only possible combination for reg=15 to reach here is 7FFF (maxpos) and FFFF (-minpos)
-                //but since it should be caught on top, so no need to handle
-                let frac_length = 13 - reg;
-                frac = convert_fraction_p16(
-                    float,
-                    frac_length as u8,
-                    &mut bit_n_plus_one,
-                    &mut bits_more,
-                );
-            }
-
-            if (reg == 14) && (frac > 0) {
-                bits_more = true;
-                frac = 0;
-            }
-            if reg > 14 {
-                0x1
-            } else {
-                let regime = 1_u16;
-                let ex = if reg == 14 {
-                    0
-                } else {
-                    (exp as u16) << (13 - reg)
-                };
-                let mut u_z = ((regime as u16) << (14 - reg)) + ex + frac;
-                //n+1 frac bit is 1. Need to check if another bit is 1 too if not round to even
-                if (reg == 14) && (exp != 0) {
-                    bit_n_plus_one = true;
-                }
-                u_z +=
-                    ((bit_n_plus_one as u16) & (u_z & 1)) | ((bit_n_plus_one & bits_more) as u16);
-                u_z
-            }
-            .with_sign(sign)
-        } else {
-            //NaR - for NaN, INF and all other combinations
-            0x8000
-        };
-        Self::from_bits(u_z)
+    #[inline]
+    fn from(float: f64) -> Self {
+        Self::from_bits(crate::convert::f64_to_posit_bits(float, 16, 1) as u16)
     }
 }
 
@@ -524,26 +186,57 @@ fn convert_p16_f32() {
     }
 }
 
+#[test]
+fn convert_p16_f32_rounding() {
+    let from_f32 = |f: f32| P16E1::from(f).to_bits() as u64;
+    crate::convert::test_f32_rounding(16, 1, from_f32, 0..0x8000);
+}
+
 #[test]
 fn convert_p16_i32() {
+    use crate::PositConversionError;
+    use core::convert::TryFrom;
     for n in -0x_8000_i16..0x_7fff {
         let p = P16E1::new(n);
-        let f = f64::from(p).round();
-        if p % P16E1::new(0x_3000) == P16E1::ZERO {
+        if p.is_nar() {
+            assert_eq!(i32::try_from(p), Err(PositConversionError::NaR));
             continue;
         }
-        assert_eq!(i32::from(p), f as i32);
+        let f_a = f64::from(p);
+        let f = f_a.round();
+        // ties to even
+        let f = if (f - f_a).abs() == 0.5 {
+            2. * (f_a / 2.).round()
+        } else {
+            f
+        };
+        assert_eq!(i32::try_from(p), Ok(f as i32));
+        assert_eq!(p.to_int_floor(), Ok(f_a.floor() as i32));
+        assert_eq!(p.to_int_ceil(), Ok(f_a.ceil() as i32));
+        assert_eq!(p.to_int_trunc(), Ok(f_a.trunc() as i32));
+        if f < 0. {
+            assert_eq!(u32::try_from(p), Err(PositConversionError::Negative));
+        } else {
+            assert_eq!(u32::try_from(p), Ok(f as u32));
+        }
+        if !(-128. ..=127.).contains(&f) {
+            assert_eq!(i8::try_from(p), Err(PositConversionError::Overflow));
+        } else {
+            assert_eq!(i8::try_from(p), Ok(f as i8));
+        }
     }
 }
 
 #[test]
 fn convert_p16_i64() {
+    use core::convert::TryFrom;
     for n in -0x_8000_i16..0x_7fff {
         let p = P16E1::new(n);
-        let f = f64::from(p).round();
-        if p % P16E1::new(0x_3000) == P16E1::ZERO {
+        if p.is_nar() {
             continue;
         }
-        assert_eq!(i64::from(p), f as i64);
+        let i = i64::try_from(p).unwrap();
+        assert_eq!(i128::try_from(p), Ok(i as i128));
+        assert_eq!(P16E1::from(i as i128), p.round());
     }
 }
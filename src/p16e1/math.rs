@@ -1,7 +1,6 @@
 use super::P16E1;
 
-const HALF: P16E1 = P16E1::new(0x_3000);
-const TWO: P16E1 = P16E1::new(0x_5000);
+crate::impl_trig_pi!(P16E1);
 
 impl crate::MathConsts for P16E1 {
     const E: Self = Self::new(0x_55bf);
@@ -38,138 +37,104 @@ impl P16E1 {
         self - self.trunc()
     }
     #[inline]
-    pub fn div_euclid(self, rhs: Self) -> Self {
-        let q = (self / rhs).trunc();
-        if self % rhs < Self::ZERO {
-            return if rhs > Self::ZERO {
-                q - Self::ONE
-            } else {
-                q + Self::ONE
-            };
-        }
-        q
-    }
-    #[inline]
-    pub fn rem_euclid(self, rhs: Self) -> Self {
-        let r = self % rhs;
-        if r < Self::ZERO {
-            r + rhs.abs()
-        } else {
-            r
-        }
-    }
-    #[inline]
-    pub fn powi(self, _n: i32) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn powf(self, _n: Self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn log(self, _base: Self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn log10(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn cbrt(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn hypot(self, _other: Self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn sin(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn cos(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn tan(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn asin(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn acos(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn atan(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn atan2(self, _other: Self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
     pub fn sin_cos(self) -> (Self, Self) {
         (self.sin(), self.cos())
     }
-    #[inline]
-    pub fn exp_m1(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn ln_1p(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn sinh(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn cosh(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn tanh(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn asinh(self) -> Self {
-        if self.is_nar() {
-            self
-        } else {
-            (self + ((self * self) + Self::ONE).sqrt()).ln()
-        }
-    }
-    #[inline]
-    pub fn acosh(self) -> Self {
-        match self {
-            x if x < Self::ONE => Self::NAR,
-            x => (x + ((x * x) - Self::ONE).sqrt()).ln(),
-        }
-    }
-    #[inline]
-    pub fn atanh(self) -> Self {
-        HALF * ((TWO * self) / (Self::ONE - self)).ln_1p()
-    }
 }
 
 mod acos_pi;
 mod asin_pi;
 mod atan_pi;
+mod cbrt;
 mod ceil;
 mod cos_pi;
 mod exp;
 mod exp2;
+mod exp_m1;
 mod floor;
+mod hyperbolic;
+mod hypot;
 mod ln;
+mod ln_1p;
+mod log;
 mod log2;
 mod mul_add;
+mod powf;
+mod powi;
 mod round;
 mod sin_pi;
+mod special;
 mod sqrt;
 mod tan_pi;
+mod trig;
+
+/// `2^t` in double precision from the `exp2` kernel, which is taken of the
+/// fractional part of `t` rounded to a posit, corrected to first order.
+fn exp2_f64(t: f64) -> f64 {
+    let n = libm::floor(t);
+    let f = t - n;
+    let p = P16E1::from(f);
+    let e = f64::from(p.exp2()) * (1. + core::f64::consts::LN_2 * (f - f64::from(p)));
+    libm::scalbn(e, n as i32)
+}
+
+/// Checks `fun` on every posit against the correctly rounded `exact` value,
+/// where `exact` returns `None` for a NaR result.
+#[cfg(test)]
+fn test_reference<E>(fun: fn(P16E1) -> P16E1, exact: E)
+where
+    E: Fn(&Reference, &num_bigint::BigInt, P16E1) -> Option<reference::Exact>,
+{
+    test_ulp(fun, 0, exact);
+}
+
+/// Checks `fun` on every posit against the correctly rounded `exact` value,
+/// where `exact` returns `None` for a NaR result, allowing an error of at
+/// most `ulp` steps between neighbouring posits.
+#[cfg(test)]
+fn test_ulp<E>(fun: fn(P16E1) -> P16E1, ulp: u32, exact: E)
+where
+    E: Fn(&Reference, &num_bigint::BigInt, P16E1) -> Option<reference::Exact>,
+{
+    let r = Reference::new();
+    for i in -0x_8000_i32..0x_8000 {
+        let p_a = P16E1::new(i as i16);
+        let expected = if p_a.is_nar() {
+            None
+        } else {
+            exact(&r, &reference::fixed(p_a.to_bits() as u64, 16, 1), p_a)
+        };
+        let expected = match expected {
+            Some(value) => P16E1::from_bits(reference::round(&value, 16, 1).unwrap() as u16),
+            None => P16E1::NAR,
+        };
+        assert!(
+            ulp_distance(fun(p_a), expected) <= ulp,
+            "x = {}: {:?} != {:?}",
+            f64::from(p_a),
+            fun(p_a),
+            expected
+        );
+    }
+}
+
+/// Number of steps between two posits, or `u32::MAX` if exactly one of
+/// them is NaR.
+#[cfg(test)]
+fn ulp_distance(a: P16E1, b: P16E1) -> u32 {
+    if a.is_nar() || b.is_nar() {
+        if a == b {
+            0
+        } else {
+            u32::MAX
+        }
+    } else {
+        ((a.to_bits() as i16 as i32) - (b.to_bits() as i16 as i32)).unsigned_abs()
+    }
+}
+
+#[cfg(test)]
+use crate::dd::reference::{self, Reference};
 
 mod kernel {
     #[inline]
@@ -192,4 +157,20 @@ mod kernel {
         }
         res
     }
+
+    /// Integer cube root, rounded down.
+    #[inline]
+    pub fn icbrt(f: u64) -> u64 {
+        let mut n = f;
+        let mut res = 0_u64;
+        for s in (0..22).rev() {
+            res <<= 1;
+            let b = 3 * res * (res + 1) + 1;
+            if (n >> (3 * s)) >= b {
+                n -= b << (3 * s);
+                res += 1;
+            }
+        }
+        res
+    }
 }
@@ -0,0 +1,37 @@
+use super::P16E1;
+use crate::convert::{decode_posit, encode_posit};
+
+impl P16E1 {
+    /// Cube root.
+    ///
+    /// The root of the significand is taken exactly in integer arithmetic,
+    /// so the result is correctly rounded.
+    pub fn cbrt(self) -> Self {
+        if self.is_zero() || self.is_nar() {
+            return self;
+        }
+        let (sign, scale, frac) = decode_posit(self.to_bits() as u64, 16, 1);
+        // x = m 2^(3q + r) with a 13-bit significand m and 0 <= r < 3, and
+        // m 2^(r + 45) below 2^60 has a root of at least 19 bits
+        let e = scale - 12;
+        let (q, r) = (e.div_euclid(3), e.rem_euclid(3));
+        let m = (frac >> 51) << (r + 45);
+        let root = super::kernel::icbrt(m);
+        let top = 63 - root.leading_zeros() as i32;
+        let bits = encode_posit(
+            sign,
+            q - 15 + top,
+            (root as u128) << (127 - top),
+            root * root * root != m,
+            16,
+            1,
+        );
+        Self::from_bits(bits as u16)
+    }
+}
+
+#[test]
+fn test_cbrt() {
+    super::test_reference(P16E1::cbrt, |r, x, _| Some(r.cbrt(x)));
+    assert_eq!(P16E1::from(-27).cbrt(), P16E1::from(-3));
+}
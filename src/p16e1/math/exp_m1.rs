@@ -0,0 +1,33 @@
+use super::P16E1;
+use crate::dd::{self, DD};
+
+impl P16E1 {
+    /// Returns `e^(self) - 1`, correctly rounded, accurate even if the number
+    /// is close to zero.
+    ///
+    /// Large results saturate to maxpos.
+    pub fn exp_m1(self) -> Self {
+        let x = f64::from(self);
+        if x > 20. {
+            Self::MAX
+        } else if x < -20. {
+            -Self::ONE
+        } else {
+            // NaR is NaN here, and NaN again after `libm`
+            let accurate = || dd::exp_m1(DD::from(x));
+            Self::from_bits(dd::round_correctly(libm::expm1(x), accurate, 16, 1) as u16)
+        }
+    }
+}
+
+#[test]
+fn test_exp_m1() {
+    use num_bigint::BigInt;
+    // beyond ±64 the results round like those at ±64, to maxpos and -1
+    let max = BigInt::from(64) << crate::dd::reference::PREC;
+    let min = -max.clone();
+    super::test_reference(P16E1::exp_m1, |r, x, _| Some(r.exp_m1(x.clamp(&min, &max))));
+    assert_eq!(P16E1::MIN_POSITIVE.exp_m1(), P16E1::MIN_POSITIVE);
+    assert_eq!(P16E1::MAX.exp_m1(), P16E1::MAX);
+    assert_eq!(P16E1::MIN.exp_m1(), -P16E1::ONE);
+}
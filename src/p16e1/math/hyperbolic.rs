@@ -0,0 +1,140 @@
+use super::{exp2_f64, P16E1};
+use core::f64::consts::{LN_2, LOG2_E};
+
+// The hyperbolic functions are built on the `exp2` and `ln` kernels, with the
+// glue in double precision and a single rounding at the end, so a result can
+// be one posit away from the correctly rounded value. The sine of small
+// arguments comes from its series instead, as the difference of the
+// exponentials would cancel.
+impl P16E1 {
+    /// Hyperbolic sine and cosine of `|x|` in double precision.
+    fn sinh_cosh_f64(self) -> (f64, f64) {
+        // far beyond the saturation of the posits, but within the doubles
+        let x = f64::from(self.abs()).min(64.);
+        let e = exp2_f64(x * LOG2_E);
+        let cosh = (e + 1. / e) / 2.;
+        if x < 1. {
+            let x2 = x * x;
+            // the terms up to x^11 / 11!
+            let mut sinh = 1.;
+            for k in (1..6).rev() {
+                sinh = 1. + x2 / f64::from(2 * k * (2 * k + 1)) * sinh;
+            }
+            (x * sinh, cosh)
+        } else {
+            ((e - 1. / e) / 2., cosh)
+        }
+    }
+
+    /// Hyperbolic sine.
+    pub fn sinh(self) -> Self {
+        if self.is_nar() {
+            return Self::NAR;
+        }
+        let sinh = self.sinh_cosh_f64().0;
+        Self::from(if self < Self::ZERO { -sinh } else { sinh })
+    }
+
+    /// Hyperbolic cosine.
+    pub fn cosh(self) -> Self {
+        if self.is_nar() {
+            return Self::NAR;
+        }
+        Self::from(self.sinh_cosh_f64().1)
+    }
+
+    /// Hyperbolic tangent.
+    pub fn tanh(self) -> Self {
+        if self.is_nar() {
+            return Self::NAR;
+        }
+        let (sinh, cosh) = self.sinh_cosh_f64();
+        let tanh = sinh / cosh;
+        Self::from(if self < Self::ZERO { -tanh } else { tanh })
+    }
+
+    /// Inverse hyperbolic sine.
+    pub fn asinh(self) -> Self {
+        if self.is_nar() {
+            return Self::NAR;
+        }
+        let x = f64::from(self.abs());
+        let asinh = ln_f64(x + libm::sqrt(x * x + 1.));
+        Self::from(if self < Self::ZERO { -asinh } else { asinh })
+    }
+
+    /// Inverse hyperbolic cosine.
+    ///
+    /// Returns NaR for numbers less than one.
+    pub fn acosh(self) -> Self {
+        if self < Self::ONE {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::from(ln_f64(x + libm::sqrt(x * x - 1.)))
+    }
+
+    /// Inverse hyperbolic tangent.
+    ///
+    /// Returns NaR for numbers outside the open interval (-1, 1).
+    pub fn atanh(self) -> Self {
+        if self.is_nar() || self.abs() >= Self::ONE {
+            return Self::NAR;
+        }
+        let x = f64::from(self.abs());
+        let atanh = ln_f64((1. + x) / (1. - x)) / 2.;
+        Self::from(if self < Self::ZERO { -atanh } else { atanh })
+    }
+}
+
+/// Natural logarithm of a positive double from the `ln` kernel, corrected to
+/// second order for the rounding of the argument to a posit.
+fn ln_f64(w: f64) -> f64 {
+    if w > 65536. {
+        return ln_f64(w / 65536.) + 16. * LN_2;
+    }
+    let p = P16E1::from(w);
+    let f = f64::from(p);
+    let u = (w - f) / f;
+    f64::from(p.ln()) + u - u * u / 2.
+}
+
+#[cfg(test)]
+use super::test_ulp;
+
+#[cfg(test)]
+fn clamp(x: &num_bigint::BigInt) -> num_bigint::BigInt {
+    // far beyond the saturation of the posits
+    let max = num_bigint::BigInt::from(64) << crate::dd::reference::PREC;
+    x.clamp(&-&max, &max).clone()
+}
+
+#[test]
+fn test_sinh() {
+    test_ulp(P16E1::sinh, 1, |r, x, _| Some(r.sinh(&clamp(x))));
+}
+
+#[test]
+fn test_cosh() {
+    test_ulp(P16E1::cosh, 1, |r, x, _| Some(r.cosh(&clamp(x))));
+}
+
+#[test]
+fn test_tanh() {
+    test_ulp(P16E1::tanh, 1, |r, x, _| Some(r.tanh(&clamp(x))));
+}
+
+#[test]
+fn test_asinh() {
+    test_ulp(P16E1::asinh, 1, |r, x, _| Some(r.asinh(x)));
+}
+
+#[test]
+fn test_acosh() {
+    test_ulp(P16E1::acosh, 1, |r, x, _| r.acosh(x));
+}
+
+#[test]
+fn test_atanh() {
+    test_ulp(P16E1::atanh, 1, |r, x, _| r.atanh(x));
+}
@@ -0,0 +1,78 @@
+use super::P16E1;
+use crate::convert::{decode_posit, encode_posit};
+
+impl P16E1 {
+    /// Length of the hypotenuse of a right-angle triangle with legs `self`
+    /// and `other`, without intermediate rounding.
+    ///
+    /// The sum of the squares is exact in integer arithmetic, so the result
+    /// is correctly rounded.
+    pub fn hypot(self, other: Self) -> Self {
+        if self.is_nar() || other.is_nar() {
+            return Self::NAR;
+        }
+        let (a, b) = if self.abs() >= other.abs() {
+            (self.abs(), other.abs())
+        } else {
+            (other.abs(), self.abs())
+        };
+        if b.is_zero() {
+            return a;
+        }
+        // the 13-bit significands, a = ma 2^(sa - 12)
+        let (_, sa, fa) = decode_posit(a.to_bits() as u64, 16, 1);
+        let (_, sb, fb) = decode_posit(b.to_bits() as u64, 16, 1);
+        let d = sa - sb;
+        if d > 12 {
+            // b^2 / 2a^2 is far below half an ulp of a
+            return a;
+        }
+        let (ma, mb) = (fa >> 51, fb >> 51);
+        // a^2 + b^2 = s 4^(sb - 12), below 2^51
+        let s = ((ma * ma) << (2 * d)) + mb * mb;
+        let h = (55 - (64 - s.leading_zeros() as i32)) / 2;
+        let s = s << (2 * h);
+        let root = super::kernel::isqrt(s);
+        let top = 63 - root.leading_zeros() as i32;
+        let bits = encode_posit(
+            false,
+            sb - 12 - h + top,
+            (root as u128) << (127 - top),
+            root * root != s,
+            16,
+            1,
+        );
+        Self::from_bits(bits as u16)
+    }
+}
+
+#[test]
+fn test_hypot() {
+    use crate::dd::reference::{self, Reference};
+    use rand::Rng;
+    let r = Reference::new();
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let p_a: P16E1 = rng.gen();
+        let p_b: P16E1 = rng.gen();
+        let p = p_a.hypot(p_b);
+        if p_a.is_nar() || p_b.is_nar() {
+            assert_eq!(p, P16E1::NAR);
+        } else {
+            let (num, den, e) = r.hypot(
+                &reference::fixed(p_a.to_bits() as u64, 16, 1),
+                &reference::fixed(p_b.to_bits() as u64, 16, 1),
+            );
+            // the root is either exact or irrational, and then far from the
+            // rounding boundaries compared to its truncation
+            let expected = crate::p64e2::reference::round(&num, &den, e, 16, 1);
+            assert_eq!(p, P16E1::from_bits(expected as u16), "{:?} {:?}", p_a, p_b);
+        }
+    }
+    assert_eq!(P16E1::from(3).hypot(P16E1::from(-4)), P16E1::from(5));
+    assert_eq!(P16E1::MAX.hypot(P16E1::MAX), P16E1::MAX);
+    assert_eq!(
+        P16E1::MIN_POSITIVE.hypot(P16E1::MIN_POSITIVE),
+        P16E1::MIN_POSITIVE
+    );
+}
@@ -0,0 +1,30 @@
+use super::P16E1;
+use crate::dd;
+
+impl P16E1 {
+    /// Returns `ln(1 + self)`, correctly rounded, accurate even if the number
+    /// is close to zero.
+    ///
+    /// Returns NaR for numbers less than or equal to -1 and for NaR.
+    pub fn ln_1p(self) -> Self {
+        if self <= -Self::ONE {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        let bits = dd::round_correctly(libm::log1p(x), || dd::ln_1p(x), 16, 1);
+        Self::from_bits(bits as u16)
+    }
+}
+
+#[test]
+fn test_ln_1p() {
+    super::test_reference(P16E1::ln_1p, |r, x, p| {
+        if p <= -P16E1::ONE {
+            None
+        } else {
+            Some(r.ln_1p(x))
+        }
+    });
+    assert_eq!(P16E1::MIN_POSITIVE.ln_1p(), P16E1::MIN_POSITIVE);
+    assert_eq!((-P16E1::MIN_POSITIVE).ln_1p(), -P16E1::MIN_POSITIVE);
+}
@@ -0,0 +1,74 @@
+use super::P16E1;
+use crate::dd;
+
+impl P16E1 {
+    /// Base-10 logarithm, correctly rounded.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    pub fn log10(self) -> Self {
+        Self::from_bits(dd::log10(f64::from(self), 16, 1) as u16)
+    }
+
+    /// Logarithm of the number with respect to an arbitrary base, correctly
+    /// rounded.
+    ///
+    /// Returns NaR if either argument is zero, negative or NaR, or if the
+    /// base is one.
+    pub fn log(self, base: Self) -> Self {
+        Self::from_bits(dd::log(f64::from(self), f64::from(base), 16, 1) as u16)
+    }
+}
+
+#[test]
+fn test_log10() {
+    super::test_reference(P16E1::log10, |r, x, p| {
+        if p <= P16E1::ZERO {
+            None
+        } else {
+            Some(r.log10(x))
+        }
+    });
+    assert_eq!(P16E1::from(1000).log10(), P16E1::from(3));
+}
+
+#[test]
+fn test_log() {
+    use crate::dd::reference::{self, Reference};
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let r = Reference::new();
+    let fixed = |p: P16E1| reference::fixed(p.to_bits() as u64, 16, 1);
+    for _ in 0..crate::NTESTS8 * 10 {
+        let p_a: P16E1 = rng.gen();
+        let p_b: P16E1 = rng.gen();
+        let exact = if p_a <= P16E1::ZERO || p_b <= P16E1::ZERO {
+            None
+        } else {
+            r.log(&fixed(p_a), &fixed(p_b))
+        };
+        let expected = match exact {
+            Some(value) => P16E1::from_bits(reference::round(&value, 16, 1).unwrap() as u16),
+            None => P16E1::NAR,
+        };
+        assert_eq!(p_a.log(p_b), expected, "{:?}.log({:?})", p_a, p_b);
+    }
+    for &(a, b, l) in &[
+        (8., 2., 3.),
+        (0.125, 2., -3.),
+        (1024., 2., 10.),
+        (8., 4., 1.5),
+        (27., 9., 1.5),
+        (1000., 10., 3.),
+        (1., 7., 0.),
+    ] {
+        assert_eq!(P16E1::from(a).log(P16E1::from(b)), P16E1::from(l));
+    }
+    let ten = P16E1::from(10);
+    assert_eq!(ten.log(P16E1::ONE), P16E1::NAR);
+    assert_eq!((-ten).log(ten), P16E1::NAR);
+    assert_eq!(ten.log(-ten), P16E1::NAR);
+    assert_eq!(P16E1::ZERO.log(ten), P16E1::NAR);
+    assert_eq!(P16E1::NAR.log(ten), P16E1::NAR);
+    assert_eq!(P16E1::ZERO.log10(), P16E1::NAR);
+    assert_eq!(P16E1::NAR.log10(), P16E1::NAR);
+}
@@ -0,0 +1,165 @@
+use super::{exp2_f64, P16E1};
+
+impl P16E1 {
+    /// Raises the number to a posit power.
+    ///
+    /// NaR is returned if either argument is NaR, for zero raised to a negative
+    /// power, and for a negative number raised to a non-integer power. Otherwise
+    /// `x.powf(0)` and `1.powf(y)` are 1 for every `x` and `y`.
+    ///
+    /// Integer powers are the correctly rounded `powi`. Otherwise the power is
+    /// split as `x^y = x^n 2^(f log2(x))` with the integer part `n` of `y`,
+    /// where `x^n` is exact to double precision and the rest is built on the
+    /// `log2` and `exp2` kernels, whose errors are scaled by `|f| < 1` only. The result can be one posit away from the correctly
+    /// rounded value.
+    pub fn powf(self, other: Self) -> Self {
+        if self.is_nar() || other.is_nar() {
+            return Self::NAR;
+        } else if other.is_zero() || self == Self::ONE {
+            return Self::ONE;
+        } else if self.is_zero() {
+            return if other < Self::ZERO {
+                Self::NAR
+            } else {
+                Self::ZERO
+            };
+        }
+        let y = f64::from(other);
+        let n = libm::trunc(y);
+        let f = y - n;
+        // |y| < 2^28
+        let n = n as i32;
+        if f == 0. {
+            self.powi(n)
+        } else if self < Self::ZERO {
+            Self::NAR
+        } else {
+            // log2(x) = e + log2(m) with m in [1, 2), exact as a posit
+            let x = f64::from(self);
+            let e = libm::ilogb(x);
+            let m = Self::from(libm::scalbn(x, -e));
+            let t = f * (f64::from(e) + f64::from(m.log2()));
+            let p = powi_f64(x, n) * exp2_f64(t);
+            // posits saturate rather than overflow or underflow
+            if p == f64::INFINITY {
+                Self::MAX
+            } else if p == 0. {
+                Self::MIN_POSITIVE
+            } else {
+                Self::from(p)
+            }
+        }
+    }
+}
+
+/// `x^n` in double precision by repeated squaring, with an error of a few
+/// ulps of a double. It overflows to infinity and underflows to zero only far
+/// beyond the range of the posits.
+fn powi_f64(x: f64, n: i32) -> f64 {
+    let mut k = n.unsigned_abs();
+    let mut base = x;
+    let mut acc = 1.;
+    while k != 0 {
+        if k & 1 != 0 {
+            acc *= base;
+        }
+        base *= base;
+        k >>= 1;
+    }
+    if n < 0 {
+        1. / acc
+    } else {
+        acc
+    }
+}
+
+/// The correctly rounded `x^y`, including the special cases, but for exact
+/// powers on a rounding boundary.
+#[cfg(test)]
+fn reference_powf(r: &crate::dd::reference::Reference, p_a: P16E1, p_b: P16E1) -> P16E1 {
+    use crate::dd::reference;
+    let (f_a, f_b) = (f64::from(p_a), f64::from(p_b));
+    let integer = f_b == libm::trunc(f_b);
+    if p_a.is_nar() || p_b.is_nar() {
+        return P16E1::NAR;
+    } else if p_b.is_zero() || p_a == P16E1::ONE {
+        return P16E1::ONE;
+    } else if f_a < 0. && !integer {
+        return P16E1::NAR;
+    } else if p_a.is_zero() {
+        return if f_b < 0. { P16E1::NAR } else { P16E1::ZERO };
+    }
+    // Exact powers such as 5.0625^1.75 = 2187/128 may lie on a rounding
+    // boundary, so the reference is rounded directly, either way for those.
+    let (num, den, e) = r.pow(
+        &reference::fixed(p_a.abs().to_bits() as u64, 16, 1),
+        &reference::fixed(p_b.to_bits() as u64, 16, 1),
+    );
+    let p = P16E1::from_bits(crate::p64e2::reference::round(&num, &den, e, 16, 1) as u16);
+    if f_a < 0. && f_b % 2. != 0. {
+        -p
+    } else {
+        p
+    }
+}
+
+#[test]
+fn test_powf() {
+    use rand::Rng;
+    let r = crate::dd::reference::Reference::new();
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let p_a: P16E1 = rng.gen();
+        let p_b: P16E1 = rng.gen();
+        let p = p_a.powf(p_b);
+        let expected = reference_powf(&r, p_a, p_b);
+        assert!(
+            super::ulp_distance(p, expected) <= 1,
+            "{:?}^{:?}: {:?} != {:?}",
+            p_a,
+            p_b,
+            p,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_powf_exhaustive() {
+    let r = crate::dd::reference::Reference::new();
+    for &y in &[0.5, 2., -1., 3., -2.5, 0.1, 1.75, -0.3] {
+        let p_b = P16E1::from(y);
+        for i in -0x_8000_i32..0x_8000 {
+            let p_a = P16E1::new(i as i16);
+            let p = p_a.powf(p_b);
+            let expected = reference_powf(&r, p_a, p_b);
+            assert!(
+                super::ulp_distance(p, expected) <= 1,
+                "{:?}^{}: {:?} != {:?}",
+                p_a,
+                y,
+                p,
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn test_powf_special() {
+    let two = P16E1::from(2);
+    assert_eq!(P16E1::NAR.powf(P16E1::ZERO), P16E1::NAR);
+    assert_eq!(P16E1::ONE.powf(P16E1::NAR), P16E1::NAR);
+    assert_eq!(P16E1::NAR.powf(P16E1::ZERO), P16E1::NAR.powi(0));
+    assert_eq!(P16E1::NAR.powf(two), P16E1::NAR);
+    assert_eq!(P16E1::ZERO.powf(two), P16E1::ZERO);
+    assert_eq!(P16E1::ZERO.powf(-two), P16E1::NAR);
+    assert_eq!((-two).powf(P16E1::from(0.5)), P16E1::NAR);
+    assert_eq!((-two).powf(P16E1::from(3)), P16E1::from(-8));
+    assert_eq!(two.powf(P16E1::from(1000)), P16E1::MAX);
+    assert_eq!(two.powf(P16E1::from(-1000)), P16E1::MIN_POSITIVE);
+    assert_eq!(P16E1::from(4).powf(P16E1::from(0.5)), two);
+    let big = P16E1::from(1 << 20);
+    assert_eq!((-big).powf(P16E1::from(-55)), -P16E1::MIN_POSITIVE);
+    assert_eq!((-big).powf(P16E1::from(55)), P16E1::MIN);
+}
@@ -0,0 +1,44 @@
+use super::P16E1;
+
+impl P16E1 {
+    /// Raises a number to an integer power.
+    ///
+    /// The result is correctly rounded unless the exact power lies within about
+    /// `n^2 2^-124` (relative) of a rounding boundary, where it may be one ulp
+    /// smaller in magnitude. `x.powi(0)` is 1 for every real `x`, NaR is
+    /// propagated and zero raised to a negative power is NaR.
+    pub fn powi(self, n: i32) -> Self {
+        let bits =
+            crate::xfloat::powi(self.to_bits() as u64, n, Self::SIZE as u32, Self::ES as u32);
+        Self::from_bits(bits as u16)
+    }
+}
+
+#[test]
+fn test_powi() {
+    for i in -0x_8000_i32..0x_8000 {
+        let p_a = P16E1::new(i as i16);
+        for &n in &[-17, -4, -3, -2, -1, 0, 1, 2, 3, 5, 8, 13] {
+            let expected = crate::xfloat::powi_exact(p_a.to_bits() as u64, n, 16, 1);
+            assert_eq!(
+                p_a.powi(n),
+                P16E1::from_bits(expected as u16),
+                "{:?}^{}",
+                p_a,
+                n
+            );
+        }
+    }
+}
+
+#[test]
+fn test_powi_special() {
+    let two = P16E1::from(2);
+    assert_eq!(P16E1::NAR.powi(0), P16E1::NAR);
+    assert_eq!(P16E1::ZERO.powi(0), P16E1::ONE);
+    assert_eq!(P16E1::ZERO.powi(-3), P16E1::NAR);
+    assert_eq!((-two).powi(5), P16E1::from(-32));
+    assert_eq!(two.powi(-3), P16E1::from(0.125));
+    assert_eq!(two.powi(100), P16E1::MAX);
+    assert_eq!(two.powi(-100), P16E1::MIN_POSITIVE);
+}
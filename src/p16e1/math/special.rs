@@ -0,0 +1,37 @@
+use super::P16E1;
+
+crate::impl_special!(P16E1);
+
+#[test]
+fn test_special() {
+    use crate::p64e2::special::libm_reference;
+    for i in i16::MIN..=i16::MAX {
+        let p = P16E1::new(i);
+        let [erf, erfc, tgamma, lgamma] = libm_reference(f64::from(p));
+        assert_eq!(p.erf(), P16E1::from(erf), "{:?}", p);
+        assert_eq!(p.erfc(), P16E1::from(erfc), "{:?}", p);
+        assert_eq!(p.tgamma(), P16E1::from(tgamma), "{:?}", p);
+        assert_eq!(p.lgamma(), P16E1::from(lgamma), "{:?}", p);
+    }
+}
+
+#[test]
+fn test_reference() {
+    use crate::dd::reference::Reference;
+    use crate::p64e2::special::check_reference;
+    use crate::P64E2;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let r = Reference::new();
+    let round = |bits| P16E1::from(P64E2::from_bits(bits)).to_bits() as u64;
+    for _ in 0..crate::NTESTS8 {
+        let p: P16E1 = rng.gen();
+        let results = [
+            p.erf().to_bits() as u64,
+            p.erfc().to_bits() as u64,
+            p.tgamma().to_bits() as u64,
+            p.lgamma().to_bits() as u64,
+        ];
+        check_reference(&r, p.to_bits() as u64, results, (16, 1), round);
+    }
+}
@@ -0,0 +1,213 @@
+use super::P16E1;
+use crate::dd::{self, DD};
+use core::f64::consts::PI;
+
+// The radian-argument functions are built on the `*_pi` kernels. The
+// argument is reduced by π/2 in double-double and the quotient by π rounded
+// to a posit for the kernel, and the rounding error of that argument is
+// corrected to first order with the derivative. The inverse functions refine
+// `atan_pi` by a Newton step with `tan_pi`. Every result is rounded once
+// more from double precision, so it can be one posit away from the correctly
+// rounded value.
+impl P16E1 {
+    /// Sine and cosine in double precision, from `sin_pi` and `cos_pi`.
+    fn sin_cos_f64(self) -> (f64, f64) {
+        // x = q π/2 + π (t + d), where t is a posit and d is tiny
+        let (q, r) = dd::rem_frac_pi_2(f64::from(self));
+        let r = r / dd::PI;
+        let t = Self::from_bits(r.to_posit_bits(16, 1) as u16);
+        let d = PI * (r - DD::from(f64::from(t))).hi;
+        let (s, c) = (f64::from(t.sin_pi()), f64::from(t.cos_pi()));
+        let (s, c) = (s + d * c, c - d * s);
+        match q {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        }
+    }
+
+    /// Sine of the number in radians.
+    pub fn sin(self) -> Self {
+        if self.is_nar() {
+            Self::NAR
+        } else {
+            Self::from(self.sin_cos_f64().0)
+        }
+    }
+
+    /// Cosine of the number in radians.
+    pub fn cos(self) -> Self {
+        if self.is_nar() {
+            Self::NAR
+        } else {
+            Self::from(self.sin_cos_f64().1)
+        }
+    }
+
+    /// Tangent of the number in radians.
+    pub fn tan(self) -> Self {
+        if self.is_nar() {
+            return Self::NAR;
+        }
+        let (q, r) = dd::rem_frac_pi_2(f64::from(self));
+        let r = r / dd::PI;
+        let t = Self::from_bits(r.to_posit_bits(16, 1) as u16);
+        let d = PI * (r - DD::from(f64::from(t))).hi;
+        let tan = f64::from(t.tan_pi());
+        let tan = tan + d * (1. + tan * tan);
+        Self::from(if q & 1 == 0 { tan } else { -1. / tan })
+    }
+
+    /// Arcsine in radians, in the range [-pi/2, pi/2].
+    ///
+    /// Returns NaR if the number is outside the range [-1, 1].
+    pub fn asin(self) -> Self {
+        if self.is_nar() || self.abs() > Self::ONE {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::from(atan2_pi_f64(x, libm::sqrt(1. - x * x)) * PI)
+    }
+
+    /// Arccosine in radians, in the range [0, pi].
+    ///
+    /// Returns NaR if the number is outside the range [-1, 1].
+    pub fn acos(self) -> Self {
+        if self.is_nar() || self.abs() > Self::ONE {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::from(atan2_pi_f64(libm::sqrt(1. - x * x), x) * PI)
+    }
+
+    /// Arctangent in radians, in the range [-pi/2, pi/2].
+    pub fn atan(self) -> Self {
+        if self.is_nar() {
+            return Self::NAR;
+        }
+        Self::from(atan2_pi_f64(f64::from(self), 1.) * PI)
+    }
+
+    /// Four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians.
+    ///
+    /// Returns NaR if both numbers are zero.
+    pub fn atan2(self, other: Self) -> Self {
+        if self.is_nar() || other.is_nar() || (self.is_zero() && other.is_zero()) {
+            return Self::NAR;
+        }
+        Self::from(atan2_pi_f64(f64::from(self), f64::from(other)) * PI)
+    }
+}
+
+/// Four quadrant arctangent of `y / x` in half turns, in double precision.
+///
+/// `atan_pi` is taken of the quotient no greater than one, rounded to a
+/// posit, and refined by one Newton step with `tan_pi`, which corrects both
+/// roundings of the kernel argument and result. Below 1/16 the posits are
+/// too coarse for that step, and the series is summed instead.
+fn atan2_pi_f64(y: f64, x: f64) -> f64 {
+    let swap = y.abs() > x.abs();
+    let q = if swap { x / y } else { y / x };
+    let a = if q.abs() < 0.0625 {
+        let q2 = q * q;
+        q * (1. - q2 * (1. / 3. - q2 * (1. / 5. - q2 * (1. / 7. - q2 / 9.)))) / PI
+    } else {
+        let a = P16E1::from(q).atan_pi();
+        let tan = f64::from(a.tan_pi());
+        f64::from(a) + (q - tan) / (PI * (1. + q * q))
+    };
+    // atan(1/q) = ±π/2 - atan(q)
+    let a = if swap { 0.5_f64.copysign(q) - a } else { a };
+    if x >= 0. {
+        a
+    } else if y >= 0. {
+        a + 1.
+    } else {
+        a - 1.
+    }
+}
+
+#[cfg(test)]
+use super::test_ulp;
+
+#[test]
+fn test_sin() {
+    test_ulp(P16E1::sin, 1, |r, x, _| Some(r.sin(x)));
+}
+
+#[test]
+fn test_cos() {
+    test_ulp(P16E1::cos, 1, |r, x, _| Some(r.cos(x)));
+}
+
+#[test]
+fn test_tan() {
+    test_ulp(P16E1::tan, 1, |r, x, _| Some(r.tan(x)));
+}
+
+#[test]
+fn test_asin() {
+    test_ulp(P16E1::asin, 1, |r, x, p| {
+        if p.abs() > P16E1::ONE {
+            None
+        } else {
+            Some(r.asin(x))
+        }
+    });
+}
+
+#[test]
+fn test_acos() {
+    test_ulp(P16E1::acos, 1, |r, x, p| {
+        if p.abs() > P16E1::ONE {
+            None
+        } else {
+            Some(r.acos(x))
+        }
+    });
+}
+
+#[test]
+fn test_atan() {
+    test_ulp(P16E1::atan, 1, |r, x, _| Some(r.atan(x)));
+}
+
+#[test]
+fn test_atan2() {
+    use crate::dd::reference::{self, Reference};
+    use rand::Rng;
+    let r = Reference::new();
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let p_a: P16E1 = rng.gen();
+        let p_b: P16E1 = rng.gen();
+        let p = p_a.atan2(p_b);
+        if p_a.is_nar() || p_b.is_nar() {
+            assert_eq!(p, P16E1::NAR);
+            continue;
+        }
+        let exact = r.atan2(
+            &reference::fixed(p_a.to_bits() as u64, 16, 1),
+            &reference::fixed(p_b.to_bits() as u64, 16, 1),
+        );
+        let expected = match exact {
+            Some(value) => P16E1::from_bits(reference::round(&value, 16, 1).unwrap() as u16),
+            None => P16E1::NAR,
+        };
+        assert!(
+            super::ulp_distance(p, expected) <= 1,
+            "{:?} {:?}: {:?} != {:?}",
+            p_a,
+            p_b,
+            p,
+            expected
+        );
+    }
+    use crate::MathConsts;
+    let one = P16E1::ONE;
+    assert_eq!(one.atan2(P16E1::ZERO), P16E1::FRAC_PI_2);
+    assert_eq!(P16E1::ZERO.atan2(-one), P16E1::PI);
+    assert_eq!(P16E1::ZERO.atan2(P16E1::ZERO), P16E1::NAR);
+    assert_eq!(P16E1::NAR.atan2(one), P16E1::NAR);
+}
@@ -413,12 +413,7 @@ impl ops::Div for P16E1 {
     }
 }
 
-impl ops::Rem for P16E1 {
-    type Output = Self;
-    fn rem(self, other: Self) -> Self {
-        self - (self / other).trunc() * other
-    }
-}
+crate::impl_rem!(P16E1, 16, 1);
 
 #[cfg(test)]
 fn test_ops(fun: fn(P16E1, P16E1, f64, f64) -> (P16E1, f64)) {
@@ -453,3 +448,23 @@ fn mul() {
 fn div() {
     test_ops(|p_a, p_b, f_a, f_b| (p_a / p_b, f_a / f_b));
 }
+
+/// Compares the remainders with the exact ones rounded by the reference, for
+/// quotients of any size.
+#[test]
+fn rem() {
+    use crate::p64e2::reference::div_rem;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS8 * 10 {
+        let p_a: P16E1 = rng.gen();
+        let p_b: P16E1 = rng.gen();
+        let [trunc, nearest, euclid] = div_rem(p_a.to_bits() as u64, p_b.to_bits() as u64, 16, 1);
+        let p = |bits: u64| P16E1::from_bits(bits as u16);
+        assert_eq!(p_a % p_b, p(trunc.1), "{:?} {:?}", p_a, p_b);
+        assert_eq!(p_a.remainder(p_b), p(nearest.1));
+        assert_eq!(p_a.remquo(p_b), (p(nearest.1), nearest.2));
+        assert_eq!(p_a.div_euclid(p_b), p(euclid.0));
+        assert_eq!(p_a.rem_euclid(p_b), p(euclid.1));
+    }
+}
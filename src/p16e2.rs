@@ -0,0 +1,221 @@
+use crate::convert::{decode_posit, encode_posit};
+
+mod convert;
+mod math;
+mod ops;
+crate::impl_num_traits!(P16E2, 16, 2);
+#[cfg(feature = "approx")]
+crate::impl_ulps_eq!(P16E2, i16);
+#[cfg(feature = "approx")]
+use approx::AbsDiffEq;
+#[cfg(feature = "approx")]
+crate::impl_signed_abs_diff_eq!(P16E2, P16E2::ZERO);
+#[cfg(feature = "approx")]
+crate::impl_relative_eq!(P16E2, i16);
+
+#[cfg(feature = "alga")]
+crate::impl_lattice!(P16E2);
+#[cfg(feature = "alga")]
+crate::impl_real!(P16E2);
+#[cfg(feature = "alga")]
+crate::impl_complex!(P16E2);
+#[cfg(feature = "alga")]
+crate::impl_alga!(P16E2);
+#[cfg(feature = "alga")]
+use alga::general::{Additive, Multiplicative};
+
+/// Standard 16-bit posit (posit16, es = 2).
+#[cfg_attr(feature = "alga", derive(alga_derive::Alga))]
+#[cfg_attr(feature = "alga", alga_traits(Field(Additive, Multiplicative)))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct P16E2(i16);
+
+impl P16E2 {
+    pub const SIZE: usize = 16;
+    pub const ES: usize = 2;
+    pub const USEED: usize = 16;
+
+    /// Machine epsilon (4.8828125e-4).
+    pub const EPSILON: Self = Self::new(0x_a00);
+
+    /// Smallest finite value (-72057594037927936).
+    pub const MIN: Self = Self::new(-0x_7FFF);
+
+    /// Smallest positive normal value (1.3877787807814457_e-17).
+    pub const MIN_POSITIVE: Self = Self::new(0x_1);
+
+    /// Largest finite value (72057594037927936).
+    pub const MAX: Self = Self::new(0x_7FFF);
+
+    /// Not a Real (NaR).
+    pub const NAR: Self = Self::new(-0x_8000);
+
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self::NAR;
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Self::NAR;
+
+    /// Zero.
+    pub const ZERO: Self = Self::new(0);
+
+    /// Identity.
+    pub const ONE: Self = Self::new(0x_4000);
+
+    #[inline]
+    pub const fn new(i: i16) -> Self {
+        Self(i)
+    }
+    #[inline]
+    pub const fn from_bits(v: u16) -> Self {
+        Self(v as i16)
+    }
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0 as u16
+    }
+    #[inline]
+    pub fn abs(self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+    #[inline]
+    pub fn is_nar(self) -> bool {
+        self == Self::NAR
+    }
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.is_nar()
+    }
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.is_nar()
+    }
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        !self.is_nar()
+    }
+    #[inline]
+    pub fn is_normal(self) -> bool {
+        !self.is_nar()
+    }
+    #[inline]
+    pub fn classify(self) -> core::num::FpCategory {
+        use core::num::FpCategory::*;
+        match self {
+            Self::ZERO => Zero,
+            Self::NAR => Nan,
+            _ => Normal,
+        }
+    }
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self < Self::ZERO
+    }
+    #[inline]
+    pub fn copysign(self, other: Self) -> Self {
+        if ((self.to_bits() ^ other.to_bits()) & Self::SIGN_MASK) != 0 {
+            -self
+        } else {
+            self
+        }
+    }
+    #[inline]
+    pub fn signum(self) -> Self {
+        match self.0 {
+            n if n == Self::NAR.0 => Self::NAR,
+            n if n > 0 => Self::ONE,
+            0 => Self::ZERO,
+            _ => -Self::ONE,
+        }
+    }
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+    #[inline]
+    pub fn to_degrees(self) -> Self {
+        const PIS_IN_180: P16E2 = P16E2::new(0x_6729);
+        self * PIS_IN_180
+    }
+    #[inline]
+    pub fn to_radians(self) -> Self {
+        const PI_180: P16E2 = P16E2::new(0x_1878);
+        self * PI_180
+    }
+}
+
+impl P16E2 {
+    pub const SIGN_MASK: u16 = 0x_8000;
+
+    /// Unpacks a nonzero, non-NaR posit into its sign, scale and fraction,
+    /// with the hidden bit of the fraction at bit 63.
+    #[inline]
+    pub(crate) fn unpack(self) -> (bool, i32, u64) {
+        decode_posit(self.to_bits() as u64, 16, 2)
+    }
+
+    /// Rounds `(-1)^sign * frac * 2^(scale - 127)`, with the hidden bit of
+    /// `frac` at bit 127, to the nearest posit.
+    #[inline]
+    pub(crate) fn encode(sign: bool, scale: i32, frac: u128, sticky: bool) -> Self {
+        Self::from_bits(encode_posit(sign, scale, frac, sticky, 16, 2) as u16)
+    }
+}
+
+impl core::str::FromStr for P16E2 {
+    type Err = crate::ParsePositError;
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_posit(src, 16, 2).map(|bits| Self::from_bits(bits as u16))
+    }
+}
+
+crate::impl_fmt!(P16E2, 16, 2);
+crate::impl_fields!(P16E2, 16, 2);
+
+use core::fmt;
+impl fmt::Debug for P16E2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            crate::fields::debug(f, "P16E2", self.to_bits() as u64, 16, 2)
+        } else {
+            write!(f, "P16E2({})", self.0)
+        }
+    }
+}
+
+impl crate::AssociatedQuire<Self> for P16E2 {
+    type Q = crate::Q16E2;
+}
+
+impl crate::polynom::poly::Poly<Self> for P16E2 {}
+impl crate::Polynom<Self> for P16E2 {}
+
+impl crate::polynom::poly::Poly<[Self; 1]> for P16E2 {}
+impl crate::Polynom<[Self; 1]> for P16E2 {}
+impl crate::polynom::poly::Poly<[Self; 2]> for P16E2 {}
+impl crate::Polynom<[Self; 2]> for P16E2 {}
+impl crate::polynom::poly::Poly<[Self; 3]> for P16E2 {}
+impl crate::Polynom<[Self; 3]> for P16E2 {}
+impl crate::polynom::poly::Poly<[Self; 4]> for P16E2 {}
+impl crate::Polynom<[Self; 4]> for P16E2 {}
+
+#[cfg(any(feature = "rand", test))]
+impl rand::distributions::Distribution<P16E2> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> P16E2 {
+        let s = rng.gen_range(-0x_7fff_i16, 0x_7fff);
+        P16E2::new(s)
+    }
+}
@@ -0,0 +1,94 @@
+use super::P16E2;
+use crate::{P16E1, P32E2, P8E0, P8E2};
+
+crate::impl_convert!(P16E2);
+crate::impl_int_convert!(P16E2, 16, 2);
+crate::impl_xfloat_convert!(P16E2, 16, 2);
+crate::impl_xfloat_from_posit!(
+    P16E2, 16, 2;
+    P8E0, 8, 0, u8;
+    P16E1, 16, 1, u16;
+    P32E2, 32, 2, u32;
+    P8E2, 8, 2, u8
+);
+
+#[test]
+fn convert_p16e2_f64() {
+    use crate::p64e2::reference::{round, value};
+    for i in 0..0x1_0000_u32 {
+        let p_a = P16E2::from_bits(i as u16);
+        let f_a = f64::from(p_a);
+        if p_a.is_nar() {
+            assert!(f_a.is_nan());
+            continue;
+        }
+        let (m, e) = value(i as u128, 16, 2);
+        assert_eq!(
+            f_a,
+            num_traits::ToPrimitive::to_f64(&m).unwrap() * 2_f64.powi(e)
+        );
+        assert_eq!(f32::from(p_a), f_a as f32);
+        assert_eq!(P16E2::from(f_a), p_a);
+        // the midpoints to the next posit and the numbers around them
+        if i < 0x7FFF {
+            let (m, e) = value(((i as u128) << 1) | 1, 17, 2);
+            let mid = num_traits::ToPrimitive::to_f64(&m).unwrap() * 2_f64.powi(e);
+            for f in &[mid, mid * (1. - 1e-9), mid * (1. + 1e-9)] {
+                let m = num_bigint::BigInt::from((f * 2_f64.powi(36 - e)) as i64);
+                assert_eq!(
+                    P16E2::from(*f).to_bits(),
+                    round(&m, &1.into(), e - 36, 16, 2) as u16
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn convert_p16e2_int() {
+    use crate::PositConversionError;
+    use core::convert::TryFrom;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let i: i32 = rng.gen();
+        assert_eq!(P16E2::from(i), P16E2::from(i as f64));
+        let u: u64 = rng.gen();
+        assert_eq!(P16E2::from(u), P16E2::from(u as f64));
+        let p_a: P16E2 = rng.gen();
+        let f_a = f64::from(p_a);
+        if f_a.abs() < 1e15 && (f_a - f_a.trunc()).abs() != 0.5 {
+            assert_eq!(i64::try_from(p_a), Ok(f_a.round() as i64));
+        }
+    }
+    assert_eq!(i64::try_from(P16E2::NAR), Err(PositConversionError::NaR));
+    assert_eq!(
+        i32::try_from(P16E2::MAX),
+        Err(PositConversionError::Overflow)
+    );
+    assert_eq!(i64::try_from(P16E2::MAX), Ok(1 << 56));
+    assert_eq!(
+        u16::try_from(-P16E2::ONE),
+        Err(PositConversionError::Negative)
+    );
+    assert_eq!(u16::try_from(-P16E2::from(0.5)), Ok(0));
+    assert_eq!(P16E2::from(-2.5).to_int_floor(), Ok(-3));
+    assert_eq!(P16E2::from(-2.5).to_int_ceil(), Ok(-2));
+    assert_eq!(P16E2::from(-2.5).to_int_round(), Ok(-2));
+    assert_eq!(P16E2::from(-2.5).to_int_trunc(), Ok(-2));
+}
+
+#[test]
+fn convert_p16e2_posits() {
+    for i in 0..0x1_0000_u32 {
+        let p_a = P16E2::from_bits(i as u16);
+        let f_a = f64::from(p_a);
+        assert_eq!(f64::from(P32E2::from(p_a)).to_bits(), f_a.to_bits());
+        assert_eq!(P16E2::from(crate::P64E2::from(p_a)), p_a);
+        assert_eq!(P8E2::from(p_a), P8E2::from(f_a));
+        assert_eq!(P16E1::from(p_a), P16E1::from(f_a));
+        assert_eq!(P8E0::from(p_a), P8E0::from(f_a));
+        let p_b = P16E1::from_bits(i as u16);
+        assert_eq!(P16E2::from(p_b), P16E2::from(f64::from(p_b)));
+    }
+}
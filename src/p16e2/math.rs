@@ -0,0 +1,84 @@
+use super::P16E2;
+
+impl crate::MathConsts for P16E2 {
+    const E: Self = Self::new(0x_4ae0);
+    const FRAC_1_PI: Self = Self::new(0x_3230);
+    const FRAC_1_SQRT_2: Self = Self::new(0x_3b50);
+    const FRAC_2_PI: Self = Self::new(0x_3a30);
+    const FRAC_2_SQRT_PI: Self = Self::new(0x_4107);
+    const FRAC_PI_2: Self = Self::new(0x_4491);
+    const FRAC_PI_3: Self = Self::new(0x_4061);
+    const FRAC_PI_4: Self = Self::new(0x_3c91);
+    const FRAC_PI_6: Self = Self::new(0x_3861);
+    const FRAC_PI_8: Self = Self::new(0x_3491);
+    const LN_10: Self = Self::new(0x_4936);
+    const LN_2: Self = Self::new(0x_3b17);
+    const LOG10_E: Self = Self::new(0x_35e6);
+    const LOG2_E: Self = Self::new(0x_438b);
+    const PI: Self = Self::new(0x_4c91);
+    const SQRT_2: Self = Self::new(0x_4350);
+    const LOG2_10: Self = Self::new(0x_4d4a);
+    const LOG10_2: Self = Self::new(0x_31a2);
+}
+
+crate::impl_xfloat_math!(P16E2, 16, 2);
+
+#[test]
+fn test_consts() {
+    use crate::MathConsts;
+    use core::f64::consts;
+    let c = |f: f64| P16E2::from(f);
+    assert_eq!(c(consts::E), MathConsts::E);
+    assert_eq!(c(consts::PI), MathConsts::PI);
+    assert_eq!(c(consts::LN_2), MathConsts::LN_2);
+    assert_eq!(c(consts::SQRT_2), MathConsts::SQRT_2);
+    assert_eq!(c(consts::FRAC_1_PI), MathConsts::FRAC_1_PI);
+    assert_eq!(c(consts::LOG2_10), MathConsts::LOG2_10);
+}
+
+#[test]
+fn test_math_exhaustive() {
+    for i in 0..0x1_0000_u32 {
+        let p_a = P16E2::from_bits(i as u16);
+        if p_a.is_nar() {
+            continue;
+        }
+        let f_a = f64::from(p_a);
+        assert_eq!(p_a.sqrt(), P16E2::from(f_a.sqrt()));
+        // exponentials saturate to maxpos and minpos
+        let exp = f_a.exp().clamp(f64::MIN_POSITIVE, f64::MAX);
+        assert_eq!(p_a.exp(), P16E2::from(exp));
+        assert_eq!(p_a.ln(), P16E2::from(f_a.ln()));
+        assert_eq!(p_a.trunc(), P16E2::from(f_a.trunc()));
+        assert_eq!(p_a.floor(), P16E2::from(f_a.floor()));
+        assert_eq!(p_a.ceil(), P16E2::from(f_a.ceil()));
+        if (f_a - f_a.trunc()).abs() != 0.5 {
+            assert_eq!(p_a.round(), P16E2::from(f_a.round()));
+        }
+    }
+    let nar = P16E2::NAR;
+    assert_eq!(
+        (nar.sqrt(), nar.exp(), nar.ln(), nar.round()),
+        (nar, nar, nar, nar)
+    );
+}
+
+#[test]
+fn test_mul_add() {
+    use crate::p64e2::reference::{round, value};
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let (p_a, p_b, p_c): (P16E2, P16E2, P16E2) = (rng.gen(), rng.gen(), rng.gen());
+        let (a, ea) = value(p_a.to_bits() as u128, 16, 2);
+        let (b, eb) = value(p_b.to_bits() as u128, 16, 2);
+        let (c, ec) = value(p_c.to_bits() as u128, 16, 2);
+        let ep = ea + eb;
+        let e = ep.min(ec);
+        let sum = ((a * b) << (ep - e) as usize) + (c << (ec - e) as usize);
+        assert_eq!(
+            p_a.mul_add(p_b, p_c).to_bits(),
+            round(&sum, &1.into(), e, 16, 2) as u16
+        );
+    }
+}
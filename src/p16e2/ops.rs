@@ -0,0 +1,57 @@
+use super::P16E2;
+
+crate::impl_xfloat_ops!(P16E2, 16, 2);
+
+/// Products of two `P16E2` fit in an `f64`, and sums and quotients are never
+/// close enough to a rounding boundary to be rounded twice.
+#[cfg(test)]
+fn test_ops(fun: fn(P16E2, P16E2) -> P16E2, fun_f64: fn(f64, f64) -> f64) {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let p_a: P16E2 = rng.gen();
+        let p_b: P16E2 = rng.gen();
+        let f = fun_f64(f64::from(p_a), f64::from(p_b));
+        assert_eq!(fun(p_a, p_b), P16E2::from(f), "{:?} {:?}", p_a, p_b);
+    }
+}
+
+#[test]
+fn add() {
+    test_ops(|p_a, p_b| p_a + p_b, |f_a, f_b| f_a + f_b);
+}
+
+#[test]
+fn sub() {
+    test_ops(|p_a, p_b| p_a - p_b, |f_a, f_b| f_a - f_b);
+}
+
+#[test]
+fn mul() {
+    test_ops(|p_a, p_b| p_a * p_b, |f_a, f_b| f_a * f_b);
+}
+
+#[test]
+fn div() {
+    test_ops(|p_a, p_b| p_a / p_b, |f_a, f_b| f_a / f_b);
+}
+
+/// Compares the remainders with the exact ones rounded by the reference, for
+/// quotients of any size.
+#[test]
+fn rem() {
+    use crate::p64e2::reference::div_rem;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS8 * 10 {
+        let p_a: P16E2 = rng.gen();
+        let p_b: P16E2 = rng.gen();
+        let [trunc, nearest, euclid] = div_rem(p_a.to_bits() as u64, p_b.to_bits() as u64, 16, 2);
+        let p = |bits: u64| P16E2::from_bits(bits as u16);
+        assert_eq!(p_a % p_b, p(trunc.1), "{:?} {:?}", p_a, p_b);
+        assert_eq!(p_a.remainder(p_b), p(nearest.1));
+        assert_eq!(p_a.remquo(p_b), (p(nearest.1), nearest.2));
+        assert_eq!(p_a.div_euclid(p_b), p(euclid.0));
+        assert_eq!(p_a.rem_euclid(p_b), p(euclid.1));
+    }
+}
@@ -3,7 +3,7 @@ use core::mem;
 mod convert;
 mod math;
 mod ops;
-crate::impl_num_traits!(P32E2);
+crate::impl_num_traits!(P32E2, 32, 2);
 #[cfg(feature = "approx")]
 crate::impl_ulps_eq!(P32E2, i32);
 #[cfg(feature = "approx")]
@@ -206,24 +206,6 @@ impl P32E2 {
         (k, tmp)
     }
 
-    #[inline]
-    fn calculate_scale(mut bits: u32) -> (u32, u32) {
-        let mut scale = 0_u32;
-        bits -= 0x4000_0000;
-        while (0x2000_0000 & bits) != 0 {
-            scale += 4;
-            bits = (bits - 0x2000_0000) << 1;
-        }
-        bits <<= 1; // Skip over termination bit, which is 0.
-        if (0x2000_0000 & bits) != 0 {
-            scale += 2; // If first exponent bit is 1, increment the scale.
-        }
-        if (0x1000_0000 & bits) != 0 {
-            scale += 1;
-        }
-        (scale, bits)
-    }
-
     #[inline]
     pub(crate) fn calculate_regime(k: i8) -> (u32, bool, u32) {
         let len;
@@ -242,23 +224,24 @@ impl P32E2 {
 }
 
 impl core::str::FromStr for P32E2 {
-    type Err = core::num::ParseFloatError;
+    type Err = crate::ParsePositError;
     #[inline]
-    fn from_str(src: &str) -> Result<Self, core::num::ParseFloatError> {
-        Ok(Self::from(f64::from_str(src)?))
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_posit(src, 32, 2).map(|bits| Self::from_bits(bits as u32))
     }
 }
 
-use core::fmt;
-impl fmt::Display for P32E2 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", f64::from(*self))
-    }
-}
+crate::impl_fmt!(P32E2, 32, 2);
+crate::impl_fields!(P32E2, 32, 2);
 
+use core::fmt;
 impl fmt::Debug for P32E2 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "P32E2({})", self.0)
+        if f.alternate() {
+            crate::fields::debug(f, "P32E2", self.to_bits() as u64, 32, 2)
+        } else {
+            write!(f, "P32E2({})", self.0)
+        }
     }
 }
 
@@ -4,6 +4,9 @@ use core::convert::From;
 use core::f64;
 
 crate::impl_convert!(P32E2);
+crate::impl_int_convert!(P32E2, 32, 2);
+crate::impl_xfloat_from_int!(P32E2, 32, 2; i128);
+crate::impl_xfloat_from_uint!(P32E2, 32, 2; u128);
 
 impl From<f32> for P32E2 {
     fn from(float: f32) -> Self {
@@ -12,166 +15,9 @@ impl From<f32> for P32E2 {
 }
 
 impl From<f64> for P32E2 {
-    #[allow(clippy::cognitive_complexity)]
-    fn from(mut float: f64) -> Self {
-        let mut reg: u32;
-        let mut frac = 0_u32;
-        let mut exp = 0_i32;
-        let mut bit_n_plus_one = false;
-        let mut bits_more = false;
-
-        if float == 0. {
-            return Self::ZERO;
-        } else if !float.is_finite() {
-            return Self::NAR;
-        } else if float >= 1.329_227_995_784_916_e36 {
-            //maxpos
-            return Self::MAX;
-        } else if float <= -1.329_227_995_784_916_e36 {
-            // -maxpos
-            return Self::MIN;
-        }
-
-        let sign = float < 0.;
-
-        let u_z: u32 = if float == 1. {
-            0x4000_0000
-        } else if float == -1. {
-            0xC000_0000
-        } else if (float <= 7.523_163_845_262_64_e-37) && !sign {
-            //minpos
-            0x1
-        } else if (float >= -7.523_163_845_262_64_e-37) && sign {
-            //-minpos
-            0xFFFF_FFFF
-        } else if (float > 1.) || (float < -1.) {
-            if sign {
-                //Make negative numbers positive for easier computation
-                float = -float;
-            }
-
-            reg = 1; //because k = m-1; so need to add back 1
-                     // minpos
-            if float <= 7.523_163_845_262_64_e-37 {
-                1
-            } else {
-                //regime
-                while float >= 16. {
-                    float *= 0.0625; // float/=16;
-                    reg += 1;
-                }
-                while float >= 2. {
-                    float *= 0.5;
-                    exp += 1;
-                }
-
-                let frac_length = 28 - (reg as i8);
-
-                if frac_length < 0 {
-                    //in both cases, reg=29 and 30, e is n+1 bit and frac are sticky bits
-                    if reg == 29 {
-                        bit_n_plus_one = (exp & 0x1) != 0;
-                        exp >>= 1; //taken care of by the pack algo
-                    } else {
-                        //reg=30
-                        bit_n_plus_one = (exp >> 1) != 0;
-                        bits_more = (exp & 0x1) != 0;
-                        exp = 0;
-                    }
-                    if float != 1. {
-                        //because of hidden bit
-                        bits_more = true;
-                        frac = 0;
-                    }
-                } else {
-                    frac = crate::convert_fraction_p32(
-                        float,
-                        frac_length as u16,
-                        &mut bit_n_plus_one,
-                        &mut bits_more,
-                    );
-                }
-
-                if reg > 30 {
-                    0x7FFF_FFFF
-                } else {
-                    //rounding off fraction bits
-
-                    let regime = ((1 << reg) - 1) << 1;
-                    if reg <= 28 {
-                        exp <<= 28 - reg;
-                    }
-                    let u_z = ((regime as u32) << (30 - reg)) + (exp as u32) + (frac as u32);
-                    u_z + (((bit_n_plus_one as u32) & (u_z & 1))
-                        | ((bit_n_plus_one & bits_more) as u32))
-                }
-                .with_sign(sign)
-            }
-        } else if (float < 1.) || (float > -1.) {
-            if sign {
-                //Make negative numbers positive for easier computation
-                float = -float;
-            }
-            reg = 0;
-
-            //regime
-            while float < 1. {
-                float *= 16.;
-                reg += 1;
-            }
-
-            while float >= 2. {
-                float *= 0.5;
-                exp += 1;
-            }
-
-            //only possible combination for reg=15 to reach here is 7FFF (maxpos) and FFFF (-minpos)
-            //but since it should be caught on top, so no need to handle
-            let frac_length = 28 - (reg as i8);
-            if frac_length < 0 {
-                //in both cases, reg=29 and 30, e is n+1 bit and frac are sticky bits
-                if reg == 29 {
-                    bit_n_plus_one = (exp & 0x1) != 0;
-                    exp >>= 1; //taken care of by the pack algo
-                } else {
-                    //reg=30
-                    bit_n_plus_one = (exp >> 1) != 0;
-                    bits_more = (exp & 0x1) != 0;
-                    exp = 0;
-                }
-                if float != 1. {
-                    //because of hidden bit
-                    bits_more = true;
-                    frac = 0;
-                }
-            } else {
-                frac = crate::convert_fraction_p32(
-                    float,
-                    frac_length as u16,
-                    &mut bit_n_plus_one,
-                    &mut bits_more,
-                );
-            }
-
-            if reg > 30 {
-                0x1
-            } else {
-                //rounding off fraction bits
-
-                let regime = 1_u32;
-                if reg <= 28 {
-                    exp <<= 28 - reg;
-                }
-                let u_z = ((regime as u32) << (30 - reg)) + (exp as u32) + (frac as u32);
-                u_z + (((bit_n_plus_one as u32) & (u_z & 1))
-                    | ((bit_n_plus_one & bits_more) as u32))
-            }
-            .with_sign(sign)
-        } else {
-            //NaR - for NaN, INF and all other combinations
-            0x8000_0000
-        };
-        Self::from_bits(u_z)
+    #[inline]
+    fn from(float: f64) -> Self {
+        Self::from_bits(crate::convert::f64_to_posit_bits(float, 32, 2) as u32)
     }
 }
 
@@ -206,171 +52,6 @@ impl From<P32E2> for f64 {
     }
 }
 
-impl From<P32E2> for i32 {
-    #[inline]
-    fn from(p_a: P32E2) -> Self {
-        if p_a.is_nar() {
-            return i32::min_value();
-        }
-
-        let mut ui_a = p_a.to_bits();
-
-        let sign = (ui_a & 0x8000_0000) != 0;
-        if sign {
-            ui_a = ui_a.wrapping_neg();
-        }
-
-        if ui_a > 0x_7faf_ffff {
-            return if sign {
-                i32::min_value()
-            } else {
-                i32::max_value()
-            };
-        };
-
-        let i_z = convert_p32bits_to_u32(ui_a);
-
-        i_z.with_sign(sign) as i32
-    }
-}
-
-impl From<P32E2> for u32 {
-    #[inline]
-    fn from(p_a: P32E2) -> Self {
-        if p_a.is_nar() {
-            return 0x8000_0000; // Error: Should be u32::max_value()
-        }
-
-        let ui_a = p_a.to_bits();
-
-        //negative
-        if ui_a > 0x8000_0000 {
-            return 0;
-        }
-        convert_p32bits_to_u32(ui_a)
-    }
-}
-
-fn convert_p32bits_to_u32(ui_a: u32) -> u32 {
-    if ui_a <= 0x3800_0000 {
-        0 // 0 <= |pA| <= 1/2 rounds to zero.
-    } else if ui_a < 0x4400_0000 {
-        1 // 1/2 < x < 3/2 rounds to 1.
-    } else if ui_a <= 0x4A00_0000 {
-        2 // 3/2 <= x <= 5/2 rounds to 2. // For speed. Can be commented out
-    } else if ui_a > 0x7FAF_FFFF {
-        //overflow so return max integer value
-        0x7FFF_FFFF
-    } else {
-        let (scale, bits) = P32E2::calculate_scale(ui_a);
-
-        let mut i_z64 = (((bits as u64) | 0x1000_0000) & 0x1FFF_FFFF) << 34; // Left-justify fraction in 32-bit result (one left bit padding)
-        let mut mask = 0x4000_0000_0000_0000_u64 >> scale; // Point to the last bit of the integer part.
-
-        let bit_last = i_z64 & mask; // Extract the bit, without shifting it.
-        mask >>= 1;
-        let mut tmp = i_z64 & mask;
-        let bit_n_plus_one = tmp != 0; // "True" if nonzero.
-        i_z64 ^= tmp; // Erase the bit, if it was set.
-        tmp = i_z64 & (mask - 1); // tmp has any remaining bits. // This is bits_more
-        i_z64 ^= tmp; // Erase those bits, if any were set.
-
-        if bit_n_plus_one {
-            // logic for round to nearest, tie to even
-            if (bit_last | tmp) != 0 {
-                i_z64 += mask << 1;
-            }
-        }
-
-        (i_z64 >> (62 - scale)) as u32 // Right-justify the integer.
-    }
-}
-
-impl From<P32E2> for i64 {
-    #[inline]
-    fn from(p_a: P32E2) -> Self {
-        let mut ui_a = p_a.to_bits();
-
-        if ui_a == 0x8000_0000 {
-            return i64::min_value();
-        }
-
-        let sign = (ui_a & 0x8000_0000) != 0;
-        if sign {
-            ui_a = ui_a.wrapping_neg();
-        }
-
-        if ui_a > 0x_7fff_afff {
-            return if sign {
-                i64::min_value()
-            } else {
-                i64::max_value()
-            };
-        };
-
-        let i_z = convert_p32bits_to_u64(ui_a);
-
-        i_z.with_sign(sign) as i64
-    }
-}
-
-impl From<P32E2> for u64 {
-    #[inline]
-    fn from(p_a: P32E2) -> Self {
-        let ui_a = p_a.to_bits();
-
-        //NaR
-        if ui_a == 0x8000_0000 {
-            0x8000_0000_0000_0000
-        //negative
-        } else if ui_a > 0x8000_0000 {
-            0
-        } else {
-            convert_p32bits_to_u64(ui_a)
-        }
-    }
-}
-
-fn convert_p32bits_to_u64(ui_a: u32) -> u64 {
-    if ui_a <= 0x3800_0000 {
-        0 // 0 <= |pA| <= 1/2 rounds to zero.
-    } else if ui_a < 0x4400_0000 {
-        1 // 1/2 < x < 3/2 rounds to 1.
-    } else if ui_a <= 0x4A00_0000 {
-        2 // 3/2 <= x <= 5/2 rounds to 2. // For speed. Can be commented out
-    } else if ui_a > 0x7FFF_BFFF {
-        0xFFFF_FFFF_FFFF_FFFF
-    } else {
-        let (scale, bits) = P32E2::calculate_scale(ui_a);
-
-        let mut i_z: u64 = (((bits as u64) | 0x1000_0000) & 0x1FFF_FFFF) << 34; // Left-justify fraction in 32-bit result (one left bit padding)
-
-        if scale < 62 {
-            let mut mask = 0x4000_0000_0000_0000_u64 >> scale; // Point to the last bit of the integer part.
-
-            let bit_last = i_z & mask; // Extract the bit, without shifting it.
-            mask >>= 1;
-            let mut tmp = i_z & mask;
-            let bit_n_plus_one = tmp != 0; // "True" if nonzero.
-            i_z ^= tmp; // Erase the bit, if it was set.
-            tmp = i_z & (mask - 1); // tmp has any remaining bits. // This is bits_more
-            i_z ^= tmp; // Erase those bits, if any were set.
-
-            if bit_n_plus_one {
-                // logic for round to nearest, tie to even
-                if (bit_last | tmp) != 0 {
-                    i_z += mask << 1;
-                }
-            }
-            i_z >> (62 - scale) // Right-justify the integer.
-        } else if scale > 62 {
-            i_z << (scale - 62)
-        } else {
-            i_z
-        }
-    }
-}
-
 impl From<i32> for P32E2 {
     #[inline]
     fn from(mut i_a: i32) -> Self {
@@ -504,36 +185,75 @@ fn convert_p32_f64() {
     }
 }
 
+#[test]
+fn convert_p32_f32_rounding() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let from_f32 = |f: f32| P32E2::from(f).to_bits() as u64;
+    let bodies = (0..crate::NTESTS16).map(|_| rng.gen_range(1, 0x8000_0000));
+    crate::convert::test_f32_rounding(32, 2, from_f32, bodies);
+    // the largest regimes, where an f32 has more bits than the posit
+    crate::convert::test_f32_rounding(32, 2, from_f32, 0x7000_0000..0x7000_1000);
+    crate::convert::test_f32_rounding(32, 2, from_f32, 0x7FFF_F000..0x8000_0000);
+    crate::convert::test_f32_rounding(32, 2, from_f32, 1..0x1000);
+}
+
 #[test]
 fn convert_p32_i32() {
+    use crate::PositConversionError;
+    use core::convert::TryFrom;
     use rand::Rng;
     let mut rng = rand::thread_rng();
     for _ in 0..100_000 {
         let p: P32E2 = rng.gen();
-        let f = f64::from(p).round();
-        if p % P32E2::new(0x_3800_0000) == P32E2::ZERO {
+        if p.is_nar() {
+            assert_eq!(i32::try_from(p), Err(PositConversionError::NaR));
             continue;
         }
-        if f as i32 == i32::min_value() {
-            continue;
+        let f_a = f64::from(p);
+        let f = f_a.round();
+        // ties to even
+        let f = if (f - f_a).abs() == 0.5 {
+            2. * (f_a / 2.).round()
+        } else {
+            f
+        };
+        if f < i32::MIN as f64 || f > i32::MAX as f64 {
+            assert_eq!(i32::try_from(p), Err(PositConversionError::Overflow));
+        } else {
+            assert_eq!(i32::try_from(p), Ok(f as i32));
+        }
+        if f < 0. {
+            assert_eq!(u32::try_from(p), Err(PositConversionError::Negative));
         }
-        assert_eq!(i32::from(p), f as i32);
     }
+    assert_eq!(i32::try_from(P32E2::from(i32::MIN)), Ok(i32::MIN));
+    assert_eq!(u64::try_from(-P32E2::MIN_POSITIVE), Ok(0));
 }
 
 #[test]
 fn convert_p32_i64() {
+    use crate::PositConversionError;
     use rand::Rng;
     let mut rng = rand::thread_rng();
     for _ in 0..100_000 {
         let p: P32E2 = rng.gen();
-        let f = f64::from(p).round();
-        if p % P32E2::new(0x_3800_0000) == P32E2::ZERO {
+        if p.is_nar() {
             continue;
         }
-        if f as i64 == i64::min_value() {
-            continue;
+        let f_a = f64::from(p);
+        // values below 2^63 are exact in f64
+        if f_a.abs() < 9.2e18 {
+            assert_eq!(p.to_int_floor(), Ok(f_a.floor() as i64));
+            assert_eq!(p.to_int_ceil(), Ok(f_a.ceil() as i64));
+            assert_eq!(p.to_int_trunc(), Ok(f_a.trunc() as i64));
+        } else {
+            assert_eq!(p.to_int_trunc::<i64>(), Err(PositConversionError::Overflow));
+            assert_eq!(p.to_int_trunc(), Ok(f_a as i128));
         }
-        assert_eq!(i64::from(p), f as i64);
     }
+    assert_eq!(P32E2::MAX.to_int_round(), Ok(1_u128 << 120));
+    assert_eq!(P32E2::MIN.to_int_round(), Ok(-(1_i128 << 120)));
+    assert_eq!(P32E2::from(i128::MIN), P32E2::MIN);
+    assert_eq!(P32E2::from(u128::MAX), P32E2::MAX);
 }
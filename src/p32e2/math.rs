@@ -40,63 +40,10 @@ impl P32E2 {
         self - self.trunc()
     }
     #[inline]
-    pub fn div_euclid(self, rhs: Self) -> Self {
-        let q = (self / rhs).trunc();
-        if self % rhs < Self::ZERO {
-            return if rhs > Self::ZERO {
-                q - Self::ONE
-            } else {
-                q + Self::ONE
-            };
-        }
-        q
-    }
-    #[inline]
-    pub fn rem_euclid(self, rhs: Self) -> Self {
-        let r = self % rhs;
-        if r < Self::ZERO {
-            r + rhs.abs()
-        } else {
-            r
-        }
-    }
-    #[inline]
-    pub fn powi(self, _n: i32) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn powf(self, other: Self) -> Self {
-        sleef::pow(self, other)
-    }
-    #[inline]
-    pub fn exp(self) -> Self {
-        sleef::exp(self)
-    }
-    #[inline]
-    pub fn exp2(self) -> Self {
-        sleef::exp2(self)
-    }
-    #[inline]
     pub fn exp10(self) -> Self {
         sleef::exp10(self)
     }
     #[inline]
-    pub fn ln(self) -> Self {
-        sleef::ln(self)
-    }
-    #[inline]
-    pub fn log(self, _base: Self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn log2(self) -> Self {
-        sleef::log2(self)
-    }
-    #[inline]
-    pub fn log10(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
     pub fn cbrt(self) -> Self {
         sleef::cbrt(self)
     }
@@ -105,46 +52,10 @@ impl P32E2 {
         sleef::hypot(self, other)
     }
     #[inline]
-    pub fn sin(self) -> Self {
-        sleef::sin(self)
-    }
-    #[inline]
-    pub fn cos(self) -> Self {
-        sleef::cos(self)
-    }
-    #[inline]
-    pub fn tan(self) -> Self {
-        sleef::tan(self)
-    }
-    #[inline]
-    pub fn asin(self) -> Self {
-        sleef::asin(self)
-    }
-    #[inline]
-    pub fn acos(self) -> Self {
-        sleef::acos(self)
-    }
-    #[inline]
-    pub fn atan(self) -> Self {
-        sleef::atan(self)
-    }
-    #[inline]
     pub fn atan2(self, other: Self) -> Self {
         sleef::atan2(self, other)
     }
     #[inline]
-    pub fn sin_cos(self) -> (Self, Self) {
-        sleef::sin_cos(self)
-    }
-    #[inline]
-    pub fn exp_m1(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn ln_1p(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
     pub fn sinh(self) -> Self {
         sleef::sinh(self)
     }
@@ -173,12 +84,24 @@ impl P32E2 {
     }
     #[inline]
     pub fn atanh(self) -> Self {
-        HALF * ((TWO * self) / (Self::ONE - self)).ln_1p()
+        // atanh is odd, and ln_1p is better conditioned for positive arguments
+        let x = self.abs();
+        let y = HALF * ((TWO * x) / (Self::ONE - x)).ln_1p();
+        if self < Self::ZERO {
+            -y
+        } else {
+            y
+        }
     }
 }
 
 mod ceil;
+mod elementary;
 mod floor;
+mod log;
 mod mul_add;
+mod powi;
 mod round;
+mod special;
 mod sqrt;
+mod trig_pi;
@@ -0,0 +1,659 @@
+//! Correctly rounded elementary functions.
+//!
+//! Every function is first evaluated in double precision by `libm`, whose
+//! error of about an ulp of `f64` is far below the spacing of the posits.
+//! Only if that result lies within 2^-50 (relative) of a rounding boundary is
+//! the function evaluated again in double-double arithmetic and rounded from
+//! there, with a large argument reduced modulo π/2 exactly.
+//!
+//! The tests below measure the error against a 400-bit reference on random
+//! arguments, on arguments whose results lie within 2^-36 of a rounding
+//! boundary and on hand-picked hard cases: it is 0 ulp for every function.
+
+use super::P32E2;
+use crate::dd::{self, DD};
+
+impl P32E2 {
+    /// Rounds `f(self)` from a `libm` approximation, or if that is too close
+    /// to a rounding boundary, from an accurate double-double evaluation.
+    fn round_correctly<F>(approx: f64, accurate: F) -> Self
+    where
+        F: FnOnce() -> DD,
+    {
+        Self::from_bits(dd::round_correctly(approx, accurate, 32, 2) as u32)
+    }
+
+    /// Returns `e^(self)`, correctly rounded.
+    ///
+    /// Large results saturate to maxpos and small ones to minpos.
+    pub fn exp(self) -> Self {
+        let x = f64::from(self);
+        if x > 84. {
+            Self::MAX
+        } else if x < -84. {
+            Self::MIN_POSITIVE
+        } else {
+            // NaR is NaN here, and NaN again after `libm`
+            Self::round_correctly(libm::exp(x), || dd::exp(DD::from(x)))
+        }
+    }
+
+    /// Returns `2^(self)`, correctly rounded.
+    ///
+    /// Large results saturate to maxpos and small ones to minpos.
+    pub fn exp2(self) -> Self {
+        let x = f64::from(self);
+        if x > 121. {
+            Self::MAX
+        } else if x < -121. {
+            Self::MIN_POSITIVE
+        } else if libm::trunc(x) == x {
+            // exact, but may be a rounding boundary in the longest regimes
+            Self::from(libm::exp2(x))
+        } else {
+            Self::round_correctly(libm::exp2(x), || dd::exp(dd::LN2.mul_f64(x)))
+        }
+    }
+
+    /// Returns the natural logarithm of the number, correctly rounded.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    pub fn ln(self) -> Self {
+        if self <= Self::ZERO {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::round_correctly(libm::log(x), || dd::ln(x))
+    }
+
+    /// Returns the base 2 logarithm of the number, correctly rounded.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    pub fn log2(self) -> Self {
+        if self <= Self::ZERO {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::round_correctly(libm::log2(x), || {
+            let (e, ln_m) = dd::ln_parts(x);
+            DD::from(e as f64) + ln_m * dd::LOG2_E
+        })
+    }
+
+    /// Returns `e^(self) - 1`, correctly rounded, accurate even if the number
+    /// is close to zero.
+    ///
+    /// Large results saturate to maxpos.
+    pub fn exp_m1(self) -> Self {
+        let x = f64::from(self);
+        if x > 84. {
+            Self::MAX
+        } else if x < -84. {
+            -Self::ONE
+        } else {
+            Self::round_correctly(libm::expm1(x), || dd::exp_m1(DD::from(x)))
+        }
+    }
+
+    /// Returns `ln(1 + self)`, correctly rounded, accurate even if the number
+    /// is close to zero.
+    ///
+    /// Returns NaR for numbers less than or equal to -1.
+    pub fn ln_1p(self) -> Self {
+        if self <= -Self::ONE {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::round_correctly(libm::log1p(x), || dd::ln_1p(x))
+    }
+
+    /// Sine of the number in radians, correctly rounded.
+    pub fn sin(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::sin(x), || dd::sin_cos(x).0)
+    }
+
+    /// Cosine of the number in radians, correctly rounded.
+    pub fn cos(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::cos(x), || dd::sin_cos(x).1)
+    }
+
+    /// Tangent of the number in radians, correctly rounded.
+    pub fn tan(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::tan(x), || {
+            let (s, c) = dd::sin_cos(x);
+            s / c
+        })
+    }
+
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    /// Arcsine in radians, in the range [-pi/2, pi/2], correctly rounded.
+    ///
+    /// Returns NaR if the number is outside the range [-1, 1].
+    pub fn asin(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::asin(x), || dd::asin(x))
+    }
+
+    /// Arccosine in radians, in the range [0, pi], correctly rounded.
+    ///
+    /// Returns NaR if the number is outside the range [-1, 1].
+    pub fn acos(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::acos(x), || dd::acos(x))
+    }
+
+    /// Arctangent in radians, in the range [-pi/2, pi/2], correctly rounded.
+    pub fn atan(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::atan(x), || dd::atan(DD::from(x)))
+    }
+
+    /// Raises the number to a posit power, correctly rounded.
+    ///
+    /// NaR is returned if either argument is NaR, for zero raised to a negative
+    /// power, and for a negative number raised to a non-integer power. Otherwise
+    /// `x.powf(0)` and `1.powf(y)` are 1 for every `x` and `y`. Large results
+    /// saturate to maxpos and small ones to minpos.
+    pub fn powf(self, other: Self) -> Self {
+        let y = f64::from(other);
+        if self.is_nar() || other.is_nar() {
+            Self::NAR
+        } else if other.is_zero() || self == Self::ONE {
+            Self::ONE
+        } else if self.is_zero() {
+            if y > 0. {
+                Self::ZERO
+            } else {
+                Self::NAR
+            }
+        } else if libm::trunc(y) == y {
+            if y.abs() < 2_147_483_648. {
+                self.powi(y as i32)
+            } else {
+                // such large posits are even
+                self.abs().powf_positive(y)
+            }
+        } else if self < Self::ZERO {
+            Self::NAR
+        } else {
+            self.powf_positive(y)
+        }
+    }
+
+    /// Returns `self^y` for a positive `self`, where `y` is either not an
+    /// integer or too large for `powi`.
+    fn powf_positive(self, y: f64) -> Self {
+        // y = m / 2^k with an odd m
+        let (mut m, mut k) = (y, 0);
+        while libm::trunc(m) != m {
+            m *= 2.;
+            k += 1;
+        }
+        // The power is a dyadic rational, and so possibly a posit or a
+        // rounding boundary, only if the number has an exact 2^k-th root.
+        if k > 0 {
+            let root = (0..k).try_fold(self, |x, _| {
+                let r = x.sqrt();
+                let (f, g) = (f64::from(r), f64::from(x));
+                if f * f == g && libm::fma(f, f, -g) == 0. {
+                    Some(r)
+                } else {
+                    None
+                }
+            });
+            if let Some(root) = root {
+                return root.powi(m as i32);
+            }
+        }
+        let x = f64::from(self);
+        let t = y * libm::log(x);
+        if t > 84. {
+            Self::MAX
+        } else if t < -84. {
+            Self::MIN_POSITIVE
+        } else {
+            Self::round_correctly(libm::pow(x, y), || dd::exp(dd::ln(x).mul_f64(y)))
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::dd::reference;
+
+/// Exact fixed point value of a posit.
+#[cfg(test)]
+pub(super) fn fixed(p: P32E2) -> num_bigint::BigInt {
+    reference::fixed(p.to_bits() as u64, 32, 2)
+}
+
+/// Worst error in ulps over `args` of `fun` and of `accurate`, the
+/// double-double evaluation that `fun` falls back to, against `exact`, which
+/// returns `None` outside the domain of the function, with the argument where
+/// it occurs and the number of arguments checked.
+#[cfg(test)]
+pub(super) fn max_ulp<T, I, F, A, E>(
+    args: I,
+    fun: F,
+    accurate: A,
+    exact: E,
+) -> (u32, Option<T>, usize)
+where
+    T: Copy,
+    I: IntoIterator<Item = T>,
+    F: Fn(T) -> P32E2,
+    A: Fn(T) -> DD,
+    E: Fn(T) -> Option<reference::Exact>,
+{
+    let mut worst = (0, None, 0);
+    for arg in args {
+        // skips arguments too close to a rounding boundary to tell
+        let correct = match exact(arg).and_then(|value| reference::round(&value, 32, 2)) {
+            Some(bits) => bits,
+            None => continue,
+        };
+        worst.2 += 1;
+        let ulp = |bits: u32| (bits as i32).wrapping_sub(correct as i32).unsigned_abs();
+        let ulp = ulp(fun(arg).to_bits()).max(ulp(accurate(arg).to_posit_bits(32, 2) as u32));
+        if ulp > worst.0 {
+            worst.0 = ulp;
+            worst.1 = Some(arg);
+        }
+    }
+    worst
+}
+
+/// Checks that `fun` and `accurate` are correctly rounded on random
+/// arguments, on arguments whose `approx` results lie within 2^-36 of a
+/// rounding boundary and on the posits around the `targeted` numbers.
+#[cfg(test)]
+pub(super) fn test_accuracy<E>(
+    fun: fn(P32E2) -> P32E2,
+    approx: fn(f64) -> f64,
+    accurate: fn(f64) -> DD,
+    exact: E,
+    targeted: &[f64],
+) where
+    E: Fn(&reference::Reference, P32E2) -> Option<reference::Exact>,
+{
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let r = reference::Reference::new();
+    let exact = |p: P32E2| if p.is_nar() { None } else { exact(&r, p) };
+    let accurate = |p: P32E2| accurate(f64::from(p));
+    let check = |set: &str, (ulp, worst, checked): (u32, Option<P32E2>, usize)| {
+        assert!(checked > 0, "no {} arguments", set);
+        assert_eq!(
+            ulp, 0,
+            "max error of {} ulp on {} {} arguments, at {:?}",
+            ulp, checked, set, worst
+        );
+    };
+    let random = (0..crate::NTESTS8 * 5).map(|_| rng.gen::<P32E2>());
+    check("random", max_ulp(random, fun, accurate, exact));
+    let margin = 2_f64.powi(-36);
+    let hard = (0..crate::NTESTS32 / 4)
+        .map(|_| rng.gen::<P32E2>())
+        .filter(|&p| {
+            let f = approx(f64::from(p));
+            P32E2::from(f * (1. - margin)) != P32E2::from(f * (1. + margin))
+        });
+    check("hard", max_ulp(hard, fun, accurate, exact));
+    let targeted = targeted.iter().flat_map(|&f| {
+        let bits = P32E2::from(f).to_bits();
+        (0..5).map(move |d| P32E2::from_bits(bits.wrapping_add(d).wrapping_sub(2)))
+    });
+    check("targeted", max_ulp(targeted, fun, accurate, exact));
+}
+
+#[test]
+fn test_exp() {
+    let exact = |r: &reference::Reference, p: P32E2| {
+        // saturated beyond 84
+        if p.abs() > P32E2::from(84) {
+            None
+        } else {
+            Some(r.exp(&fixed(p)))
+        }
+    };
+    let targeted = [1e-9, -1e-9, 1e-5, 0.5, 1., -1., 10., 83., -83.];
+    test_accuracy(
+        P32E2::exp,
+        libm::exp,
+        |x| dd::exp(DD::from(x)),
+        exact,
+        &targeted,
+    );
+    assert_eq!(P32E2::from(84.5).exp(), P32E2::MAX);
+    assert_eq!(P32E2::from(-84.5).exp(), P32E2::MIN_POSITIVE);
+    assert_eq!(P32E2::MAX.exp(), P32E2::MAX);
+    assert_eq!(P32E2::MIN.exp(), P32E2::MIN_POSITIVE);
+    assert_eq!(P32E2::ZERO.exp(), P32E2::ONE);
+    assert_eq!(P32E2::NAR.exp(), P32E2::NAR);
+}
+
+#[test]
+fn test_exp2() {
+    let exact = |r: &reference::Reference, p: P32E2| {
+        if p.abs() > P32E2::from(121) {
+            None
+        } else {
+            Some(r.exp2(&fixed(p)))
+        }
+    };
+    let targeted = [1e-9, -1e-9, 0.5, -0.5, 100.5, 116.9, -116.9, 120.5];
+    test_accuracy(
+        P32E2::exp2,
+        libm::exp2,
+        |x| dd::exp(dd::LN2.mul_f64(x)),
+        exact,
+        &targeted,
+    );
+    // halfway between 2^116 and 2^118, rounded to the even bit pattern
+    assert_eq!(P32E2::from(117).exp2(), P32E2::from_bits(0x7FFF_FFFE));
+    assert_eq!(P32E2::from(-3).exp2(), P32E2::from(0.125));
+    assert_eq!(P32E2::from(121.5).exp2(), P32E2::MAX);
+    assert_eq!(P32E2::from(-121.5).exp2(), P32E2::MIN_POSITIVE);
+    assert_eq!(P32E2::NAR.exp2(), P32E2::NAR);
+}
+
+#[test]
+fn test_ln() {
+    let exact = |r: &reference::Reference, p: P32E2| {
+        if p <= P32E2::ZERO {
+            None
+        } else {
+            Some(r.ln(&fixed(p)))
+        }
+    };
+    // arguments close to one have the smallest results
+    let targeted = [1., 1. + 1e-8, 1. - 1e-8, 0.9999, 2., 1e-30, 1e30, 1e36];
+    test_accuracy(P32E2::ln, libm::log, dd::ln, exact, &targeted);
+    assert_eq!(P32E2::ONE.ln(), P32E2::ZERO);
+    assert_eq!(P32E2::ZERO.ln(), P32E2::NAR);
+    assert_eq!((-P32E2::ONE).ln(), P32E2::NAR);
+    assert_eq!(P32E2::NAR.ln(), P32E2::NAR);
+}
+
+#[test]
+fn test_log2() {
+    let exact = |r: &reference::Reference, p: P32E2| {
+        if p <= P32E2::ZERO {
+            None
+        } else {
+            Some(r.log2(&fixed(p)))
+        }
+    };
+    let targeted = [1., 1. + 1e-8, 1. - 1e-8, 3., 1e-30, 1e30];
+    let accurate = |x| {
+        let (e, ln_m) = dd::ln_parts(x);
+        DD::from(e as f64) + ln_m * dd::LOG2_E
+    };
+    test_accuracy(P32E2::log2, libm::log2, accurate, exact, &targeted);
+    assert_eq!(P32E2::from(1024).log2(), P32E2::from(10));
+    assert_eq!(P32E2::MIN_POSITIVE.log2(), P32E2::from(-120));
+    assert_eq!(P32E2::ZERO.log2(), P32E2::NAR);
+}
+
+#[test]
+fn test_exp_m1() {
+    let exact = |r: &reference::Reference, p: P32E2| {
+        // saturated beyond 84
+        if p.abs() > P32E2::from(84) {
+            None
+        } else {
+            Some(r.exp_m1(&fixed(p)))
+        }
+    };
+    // tiny arguments, where e^x rounds to one
+    let targeted = [1e-30, -1e-30, 1e-9, -1e-9, 1e-5, -0.3, 0.4, 20., -20.];
+    test_accuracy(
+        P32E2::exp_m1,
+        libm::expm1,
+        |x| dd::exp_m1(DD::from(x)),
+        exact,
+        &targeted,
+    );
+    assert_eq!(P32E2::MIN_POSITIVE.exp_m1(), P32E2::MIN_POSITIVE);
+    assert_eq!((-P32E2::MIN_POSITIVE).exp_m1(), -P32E2::MIN_POSITIVE);
+    assert_eq!(P32E2::from(84.5).exp_m1(), P32E2::MAX);
+    assert_eq!(P32E2::MIN.exp_m1(), -P32E2::ONE);
+    assert_eq!(P32E2::ZERO.exp_m1(), P32E2::ZERO);
+    assert_eq!(P32E2::NAR.exp_m1(), P32E2::NAR);
+}
+
+#[test]
+fn test_ln_1p() {
+    let exact = |r: &reference::Reference, p: P32E2| {
+        if p <= -P32E2::ONE {
+            None
+        } else {
+            Some(r.ln_1p(&fixed(p)))
+        }
+    };
+    let targeted = [1e-30, -1e-30, 1e-9, -1e-9, -0.25, 0.375, -0.999, 1e30];
+    test_accuracy(P32E2::ln_1p, libm::log1p, dd::ln_1p, exact, &targeted);
+    assert_eq!(P32E2::MIN_POSITIVE.ln_1p(), P32E2::MIN_POSITIVE);
+    assert_eq!((-P32E2::MIN_POSITIVE).ln_1p(), -P32E2::MIN_POSITIVE);
+    assert_eq!(P32E2::ZERO.ln_1p(), P32E2::ZERO);
+    assert_eq!((-P32E2::ONE).ln_1p(), P32E2::NAR);
+    assert_eq!(P32E2::from(-2).ln_1p(), P32E2::NAR);
+    assert_eq!(P32E2::NAR.ln_1p(), P32E2::NAR);
+}
+
+/// Multiples of π/2 and of π/4, where the trigonometric functions
+/// are close to zero, one or a pole.
+#[cfg(test)]
+const TRIG_TARGETS: [f64; 12] = [
+    1e-9,
+    core::f64::consts::FRAC_PI_4,
+    core::f64::consts::FRAC_PI_2,
+    -core::f64::consts::FRAC_PI_2,
+    core::f64::consts::PI,
+    3. * core::f64::consts::FRAC_PI_2,
+    100. * core::f64::consts::PI,
+    1e4 * core::f64::consts::PI,
+    -1e6 * core::f64::consts::PI,
+    1e9 * core::f64::consts::FRAC_PI_2,
+    1e22,
+    1e35,
+];
+
+#[test]
+fn test_sin() {
+    let exact = |r: &reference::Reference, p: P32E2| Some(r.sin(&fixed(p)));
+    test_accuracy(
+        P32E2::sin,
+        libm::sin,
+        |x| dd::sin_cos(x).0,
+        exact,
+        &TRIG_TARGETS,
+    );
+    assert_eq!(P32E2::ZERO.sin(), P32E2::ZERO);
+    assert_eq!(P32E2::NAR.sin(), P32E2::NAR);
+}
+
+#[test]
+fn test_cos() {
+    let exact = |r: &reference::Reference, p: P32E2| Some(r.cos(&fixed(p)));
+    test_accuracy(
+        P32E2::cos,
+        libm::cos,
+        |x| dd::sin_cos(x).1,
+        exact,
+        &TRIG_TARGETS,
+    );
+    assert_eq!(P32E2::ZERO.cos(), P32E2::ONE);
+    assert_eq!(P32E2::NAR.cos(), P32E2::NAR);
+}
+
+#[test]
+fn test_tan() {
+    let exact = |r: &reference::Reference, p: P32E2| Some(r.tan(&fixed(p)));
+    let accurate = |x| {
+        let (s, c) = dd::sin_cos(x);
+        s / c
+    };
+    test_accuracy(P32E2::tan, libm::tan, accurate, exact, &TRIG_TARGETS);
+    assert_eq!(P32E2::ZERO.tan(), P32E2::ZERO);
+    assert_eq!(P32E2::NAR.tan(), P32E2::NAR);
+}
+
+#[test]
+fn test_atan() {
+    let exact = |r: &reference::Reference, p: P32E2| Some(r.atan(&fixed(p)));
+    let targeted = [1e-9, 0.5, 1., -1., 2., 1e9, -1e30];
+    test_accuracy(
+        P32E2::atan,
+        libm::atan,
+        |x| dd::atan(DD::from(x)),
+        exact,
+        &targeted,
+    );
+    assert_eq!(P32E2::ZERO.atan(), P32E2::ZERO);
+    assert_eq!(P32E2::NAR.atan(), P32E2::NAR);
+}
+
+#[test]
+fn test_asin() {
+    let exact = |r: &reference::Reference, p: P32E2| {
+        if p.abs() > P32E2::ONE {
+            None
+        } else {
+            Some(r.asin(&fixed(p)))
+        }
+    };
+    let targeted = [1e-9, 0.5, -0.5, 0.999_999, 1., -1.];
+    test_accuracy(P32E2::asin, libm::asin, dd::asin, exact, &targeted);
+    use crate::MathConsts;
+    assert_eq!(P32E2::ONE.asin(), P32E2::FRAC_PI_2);
+    assert_eq!(P32E2::from(2).asin(), P32E2::NAR);
+    assert_eq!(P32E2::NAR.asin(), P32E2::NAR);
+}
+
+#[test]
+fn test_acos() {
+    let exact = |r: &reference::Reference, p: P32E2| {
+        if p.abs() > P32E2::ONE {
+            None
+        } else {
+            Some(r.acos(&fixed(p)))
+        }
+    };
+    let targeted = [1e-9, 0.5, -0.5, 0.999_999, -0.999_999, 1., -1.];
+    test_accuracy(P32E2::acos, libm::acos, dd::acos, exact, &targeted);
+    assert_eq!(P32E2::ONE.acos(), P32E2::ZERO);
+    assert_eq!((-P32E2::ONE).acos(), <P32E2 as crate::MathConsts>::PI);
+    assert_eq!(P32E2::from(-2).acos(), P32E2::NAR);
+}
+
+#[test]
+fn test_powf() {
+    use crate::p64e2::reference::{round, value};
+    use num_bigint::BigInt;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let r = reference::Reference::new();
+    let exact = |(x, y): (P32E2, P32E2)| {
+        let (fx, fy) = (f64::from(x), f64::from(y));
+        // saturated, or done by `powi`
+        if x <= P32E2::ZERO || (fy * libm::log(fx)).abs() > 84. || libm::trunc(fy) == fy {
+            None
+        } else {
+            Some(r.pow(&fixed(x), &fixed(y)))
+        }
+    };
+    let accurate = |(x, y): (P32E2, P32E2)| {
+        let y = f64::from(y);
+        dd::exp(dd::ln(f64::from(x)).mul_f64(y))
+    };
+    let check = |set: &str, (ulp, worst, checked): (u32, Option<(P32E2, P32E2)>, usize)| {
+        assert!(checked > 0, "no {} arguments", set);
+        assert_eq!(
+            ulp, 0,
+            "max error of {} ulp on {} {} arguments, at {:?}",
+            ulp, checked, set, worst
+        );
+    };
+    let random = (0..crate::NTESTS8 * 5).map(|_| {
+        let y = P32E2::from(rng.gen_range(-20., 20.));
+        (rng.gen::<P32E2>().abs(), y)
+    });
+    check(
+        "random",
+        max_ulp(random, |(x, y)| x.powf(y), accurate, exact),
+    );
+    let margin = 2_f64.powi(-36);
+    let hard = (0..crate::NTESTS32 / 4)
+        .map(|_| {
+            let y = P32E2::from(rng.gen_range(-20., 20.));
+            (rng.gen::<P32E2>().abs(), y)
+        })
+        .filter(|&(x, y)| {
+            let f = libm::pow(f64::from(x), f64::from(y));
+            P32E2::from(f * (1. - margin)) != P32E2::from(f * (1. + margin))
+        });
+    check("hard", max_ulp(hard, |(x, y)| x.powf(y), accurate, exact));
+    // bases close to one with large exponents
+    let near_one = (0..crate::NTESTS8 * 5).map(|_| {
+        let x = P32E2::from_bits(rng.gen_range(0x3FFF_F000, 0x4000_1000));
+        (x, P32E2::from(rng.gen_range(-1e6, 1e6)))
+    });
+    check(
+        "near one",
+        max_ulp(near_one, |(x, y)| x.powf(y), accurate, exact),
+    );
+
+    // Exact powers of a root, which may be posits or rounding boundaries.
+    // 657721 = 811^2, and 811^3 * 2^-30 lies halfway between two posits.
+    let x = P32E2::from(657_721. * 2_f64.powi(-20));
+    let expected = round(&BigInt::from(533_411_731), &BigInt::from(1), -30, 32, 2);
+    assert_eq!(x.powf(P32E2::from(1.5)).to_bits(), expected as u32);
+    for _ in 0..crate::NTESTS8 * 5 {
+        // a root with a short significand
+        let root =
+            P32E2::from(rng.gen_range(1, 1 << 12) as f64 * 2_f64.powi(rng.gen_range(-20, 20)));
+        let k = rng.gen_range(1, 3);
+        let x = root.powi(1 << k);
+        let (mr, er) = value(root.to_bits() as u128, 32, 2);
+        let (mx, ex) = value(x.to_bits() as u128, 32, 2);
+        if crate::p64e2::reference::cmp(&mx, ex, &num_traits::pow(mr.clone(), 1 << k), er << k)
+            != core::cmp::Ordering::Equal
+        {
+            continue;
+        }
+        let m: i32 = 2 * rng.gen_range(-10, 10) + 1;
+        let y = P32E2::from(m as f64 / (1 << k) as f64);
+        let power = num_traits::pow(mr, m.unsigned_abs() as usize);
+        let expected = if m > 0 {
+            round(&power, &BigInt::from(1), er * m, 32, 2)
+        } else {
+            round(&BigInt::from(1), &power, er * m, 32, 2)
+        };
+        assert_eq!(x.powf(y).to_bits(), expected as u32, "{:?}^{:?}", x, y);
+    }
+
+    let two = P32E2::from(2);
+    assert_eq!(P32E2::NAR.powf(P32E2::ZERO), P32E2::NAR);
+    assert_eq!(P32E2::ONE.powf(P32E2::NAR), P32E2::NAR);
+    assert_eq!(P32E2::NAR.powf(P32E2::ZERO), P32E2::NAR.powi(0));
+    assert_eq!(two.powf(P32E2::NAR), P32E2::NAR);
+    assert_eq!(P32E2::ZERO.powf(two), P32E2::ZERO);
+    assert_eq!(P32E2::ZERO.powf(-two), P32E2::NAR);
+    assert_eq!((-two).powf(P32E2::from(0.5)), P32E2::NAR);
+    assert_eq!((-two).powf(P32E2::from(3)), P32E2::from(-8));
+    assert_eq!(
+        two.powf(P32E2::from(0.5)),
+        <P32E2 as crate::MathConsts>::SQRT_2
+    );
+    assert_eq!(two.powf(P32E2::from(1000.5)), P32E2::MAX);
+    assert_eq!(two.powf(P32E2::from(-1000.5)), P32E2::MIN_POSITIVE);
+    assert_eq!((-two).powf(P32E2::from(1e10)), P32E2::MAX);
+    assert_eq!((-two).powf(P32E2::from(-1e10)), P32E2::MIN_POSITIVE);
+}
@@ -0,0 +1,97 @@
+use super::P32E2;
+use crate::dd;
+
+impl P32E2 {
+    /// Base-10 logarithm, correctly rounded.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    pub fn log10(self) -> Self {
+        Self::from_bits(dd::log10(f64::from(self), 32, 2) as u32)
+    }
+
+    /// Logarithm of the number with respect to an arbitrary base, correctly
+    /// rounded.
+    ///
+    /// Returns NaR if either argument is zero, negative or NaR, or if the
+    /// base is one.
+    pub fn log(self, base: Self) -> Self {
+        Self::from_bits(dd::log(f64::from(self), f64::from(base), 32, 2) as u32)
+    }
+}
+
+#[cfg(test)]
+use super::elementary::{fixed, max_ulp, test_accuracy};
+
+#[test]
+fn test_log10() {
+    test_accuracy(
+        P32E2::log10,
+        libm::log10,
+        |x| dd::ln(x) / dd::LN10,
+        |r, p| {
+            if p <= P32E2::ZERO {
+                None
+            } else {
+                Some(r.log10(&fixed(p)))
+            }
+        },
+        &[1e-30, 0.1, 0.5, 2., 3., 10., 1000., 1e30],
+    );
+    for i in 0..8 {
+        assert_eq!(P32E2::from(10_i32.pow(i)).log10(), P32E2::from(i));
+    }
+    assert_eq!(P32E2::ONE.log10(), P32E2::ZERO);
+    assert_eq!(P32E2::ZERO.log10(), P32E2::NAR);
+    assert_eq!((-P32E2::ONE).log10(), P32E2::NAR);
+    assert_eq!(P32E2::NAR.log10(), P32E2::NAR);
+}
+
+#[test]
+fn test_log() {
+    use crate::dd::reference::Reference;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let r = Reference::new();
+    let exact = |(p_a, p_b): (P32E2, P32E2)| {
+        if p_a <= P32E2::ZERO || p_b <= P32E2::ZERO {
+            None
+        } else {
+            r.log(&fixed(p_a), &fixed(p_b))
+        }
+    };
+    let fun = |(p_a, p_b): (P32E2, P32E2)| p_a.log(p_b);
+    let accurate = |(p_a, p_b): (P32E2, P32E2)| dd::ln(f64::from(p_a)) / dd::ln(f64::from(p_b));
+    let random = (0..crate::NTESTS8 * 5).map(|_| (rng.gen::<P32E2>(), rng.gen::<P32E2>()));
+    let (ulp, worst, checked) = max_ulp(random, fun, accurate, exact);
+    assert!(checked > 0);
+    assert_eq!(ulp, 0, "max error of {} ulp at {:?}", ulp, worst);
+    // bases next to one and powers of a common base
+    let near_one = (0..crate::NTESTS8).map(|_| {
+        let d = rng.gen_range(1, 64);
+        (
+            rng.gen::<P32E2>(),
+            P32E2::from_bits(P32E2::ONE.to_bits() + d),
+        )
+    });
+    let (ulp, worst, _) = max_ulp(near_one, fun, accurate, exact);
+    assert_eq!(ulp, 0, "max error of {} ulp at {:?}", ulp, worst);
+    for &(a, b, l) in &[
+        (8., 2., 3.),
+        (0.125, 2., -3.),
+        (1024., 2., 10.),
+        (8., 4., 1.5),
+        (27., 9., 1.5),
+        (1000., 10., 3.),
+        (1., 7., 0.),
+    ] {
+        assert_eq!(P32E2::from(a).log(P32E2::from(b)), P32E2::from(l));
+    }
+    let two = P32E2::from(2);
+    assert_eq!(two.log(P32E2::ONE), P32E2::NAR);
+    assert_eq!(two.log(-two), P32E2::NAR);
+    assert_eq!((-two).log(two), P32E2::NAR);
+    assert_eq!(P32E2::ZERO.log(two), P32E2::NAR);
+    assert_eq!(two.log(P32E2::ZERO), P32E2::NAR);
+    assert_eq!(P32E2::NAR.log(two), P32E2::NAR);
+    assert_eq!(two.log(P32E2::NAR), P32E2::NAR);
+}
@@ -0,0 +1,64 @@
+use super::P32E2;
+
+impl P32E2 {
+    /// Raises a number to an integer power.
+    ///
+    /// The result is correctly rounded unless the exact power lies within about
+    /// `n^2 2^-124` (relative) of a rounding boundary, where it may be one ulp
+    /// smaller in magnitude. `x.powi(0)` is 1 for every real `x`, NaR is
+    /// propagated and zero raised to a negative power is NaR.
+    pub fn powi(self, n: i32) -> Self {
+        let bits =
+            crate::xfloat::powi(self.to_bits() as u64, n, Self::SIZE as u32, Self::ES as u32);
+        Self::from_bits(bits as u32)
+    }
+}
+
+#[test]
+fn test_powi() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS32 / 10 {
+        let p_a: P32E2 = rng.gen();
+        let n = rng.gen_range(-40, 40);
+        let expected = crate::xfloat::powi_exact(p_a.to_bits() as u64, n, 32, 2);
+        assert_eq!(
+            p_a.powi(n),
+            P32E2::from_bits(expected as u32),
+            "{:?}^{}",
+            p_a,
+            n
+        );
+    }
+    // Values close to one survive large exponents
+    for _ in 0..1000 {
+        let p_a = P32E2::new(rng.gen_range(0x_3ff0_0000, 0x_4010_0000));
+        let n = rng.gen_range(-5000, 5000);
+        let expected = crate::xfloat::powi_exact(p_a.to_bits() as u64, n, 32, 2);
+        assert_eq!(
+            p_a.powi(n),
+            P32E2::from_bits(expected as u32),
+            "{:?}^{}",
+            p_a,
+            n
+        );
+    }
+}
+
+#[test]
+fn test_powi_special() {
+    let two = P32E2::from(2);
+    assert_eq!(P32E2::NAR.powi(0), P32E2::NAR);
+    assert_eq!(P32E2::NAR.powi(3), P32E2::NAR);
+    assert_eq!(P32E2::ZERO.powi(0), P32E2::ONE);
+    assert_eq!(P32E2::ZERO.powi(5), P32E2::ZERO);
+    assert_eq!(P32E2::ZERO.powi(-1), P32E2::NAR);
+    assert_eq!(two.powi(10), P32E2::from(1024));
+    assert_eq!((-two).powi(3), P32E2::from(-8));
+    assert_eq!(two.powi(-2), P32E2::from(0.25));
+    assert_eq!(two.powi(1000), P32E2::MAX);
+    assert_eq!(two.powi(-1000), P32E2::MIN_POSITIVE);
+    assert_eq!((-two).powi(-1001), -P32E2::MIN_POSITIVE);
+    assert_eq!(P32E2::ONE.powi(i32::MIN), P32E2::ONE);
+    assert_eq!((-P32E2::ONE).powi(i32::MAX), -P32E2::ONE);
+}
@@ -0,0 +1,72 @@
+use super::P32E2;
+
+crate::impl_special!(P32E2);
+
+#[test]
+fn test_special() {
+    use crate::dd::reference::Reference;
+    use crate::p64e2::special::check_reference;
+    use crate::P64E2;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let r = Reference::new();
+    let round = |bits| P32E2::from(P64E2::from_bits(bits)).to_bits() as u64;
+    for _ in 0..crate::NTESTS8 {
+        // mostly in the interesting range of the functions
+        let p = if rng.gen() {
+            P32E2::from(rng.gen_range(-40., 40.))
+        } else {
+            rng.gen()
+        };
+        let results = [
+            p.erf().to_bits() as u64,
+            p.erfc().to_bits() as u64,
+            p.tgamma().to_bits() as u64,
+            p.lgamma().to_bits() as u64,
+        ];
+        check_reference(&r, p.to_bits() as u64, results, (32, 2), round);
+    }
+}
+
+#[test]
+fn test_special_values() {
+    let two = P32E2::from(2);
+    for &p in &[
+        P32E2::NAR,
+        P32E2::ZERO,
+        -P32E2::ONE,
+        -two,
+        P32E2::from(-1e9),
+    ] {
+        assert_eq!(p.tgamma(), P32E2::NAR);
+        assert_eq!(p.lgamma(), P32E2::NAR);
+    }
+    assert_eq!(P32E2::NAR.erf(), P32E2::NAR);
+    assert_eq!(P32E2::NAR.erfc(), P32E2::NAR);
+    assert_eq!(P32E2::ZERO.erf(), P32E2::ZERO);
+    assert_eq!(P32E2::ZERO.erfc(), P32E2::ONE);
+    assert_eq!(P32E2::from(7).erf(), P32E2::ONE);
+    assert_eq!(P32E2::MIN.erf(), -P32E2::ONE);
+    assert_eq!(P32E2::MAX.erfc(), P32E2::MIN_POSITIVE);
+    assert_eq!(P32E2::MIN.erfc(), two);
+    assert_eq!(P32E2::ONE.tgamma(), P32E2::ONE);
+    assert_eq!(two.tgamma(), P32E2::ONE);
+    assert_eq!(P32E2::from(5).tgamma(), P32E2::from(24));
+    assert_eq!(
+        P32E2::from(0.5).tgamma(),
+        P32E2::from(core::f64::consts::PI.sqrt())
+    );
+    // tgamma(-0.64527...) lies 3.5e-17 relative to a rounding boundary
+    assert_eq!(
+        P32E2::from_bits(0x_c5ac_f8da).tgamma(),
+        P32E2::from_bits(0x_b06f_6925)
+    );
+    assert_eq!(P32E2::ONE.lgamma(), P32E2::ZERO);
+    assert_eq!(two.lgamma(), P32E2::ZERO);
+    // saturation with the sign of the exact result
+    assert_eq!(P32E2::from(35).tgamma(), P32E2::MAX);
+    assert_eq!(P32E2::MIN_POSITIVE.tgamma(), P32E2::MAX);
+    assert_eq!(P32E2::from(-40.5).tgamma(), -P32E2::MIN_POSITIVE);
+    assert_eq!(P32E2::from(-41.5).tgamma(), P32E2::MIN_POSITIVE);
+    assert_eq!((-P32E2::MIN_POSITIVE).tgamma(), P32E2::MIN);
+}
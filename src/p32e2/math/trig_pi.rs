@@ -0,0 +1,213 @@
+//! Correctly rounded trigonometric functions of angles in half-turns.
+//!
+//! The argument is reduced exactly to `x = q/2 + t` with `|t| <= 1/4`, and
+//! the functions are then rounded like the elementary ones: from `libm`, or
+//! near a rounding boundary from a double-double evaluation.
+
+use super::P32E2;
+use crate::dd;
+
+crate::impl_trig_pi!(P32E2);
+
+impl P32E2 {
+    /// Returns `sin(π x)`, correctly rounded.
+    ///
+    /// Returns zero at every integer.
+    pub fn sin_pi(self) -> Self {
+        Self::from_bits(dd::sin_pi(f64::from(self), 32, 2) as u32)
+    }
+
+    /// Returns `cos(π x)`, correctly rounded.
+    ///
+    /// Returns zero at every half-integer.
+    pub fn cos_pi(self) -> Self {
+        Self::from_bits(dd::cos_pi(f64::from(self), 32, 2) as u32)
+    }
+
+    /// Returns `tan(π x)`, correctly rounded.
+    ///
+    /// Returns NaR at the half-integers, the poles.
+    pub fn tan_pi(self) -> Self {
+        Self::from_bits(dd::tan_pi(f64::from(self), 32, 2) as u32)
+    }
+
+    /// Returns `asin(x) / π`, correctly rounded, in `[-1/2, 1/2]`.
+    ///
+    /// Returns NaR outside `[-1, 1]`.
+    pub fn asin_pi(self) -> Self {
+        Self::from_bits(dd::asin_pi(f64::from(self), 32, 2) as u32)
+    }
+
+    /// Returns `acos(x) / π`, correctly rounded, in `[0, 1]`.
+    ///
+    /// Returns NaR outside `[-1, 1]`.
+    pub fn acos_pi(self) -> Self {
+        Self::from_bits(dd::acos_pi(f64::from(self), 32, 2) as u32)
+    }
+
+    /// Returns `atan(x) / π`, correctly rounded, in `[-1/2, 1/2]`.
+    pub fn atan_pi(self) -> Self {
+        Self::from_bits(dd::atan_pi(f64::from(self), 32, 2) as u32)
+    }
+}
+
+#[cfg(test)]
+use super::elementary::{fixed, test_accuracy};
+#[cfg(test)]
+use crate::MathConsts;
+
+/// Multiples of 1/12, where the functions take simple values, and arguments
+/// far from zero.
+#[cfg(test)]
+const TARGETS: [f64; 14] = [
+    1. / 12.,
+    1. / 6.,
+    0.25,
+    1. / 3.,
+    0.5,
+    2. / 3.,
+    0.75,
+    1.,
+    -1.5,
+    2.25,
+    1e-30,
+    1e6 + 0.5,
+    -134_217_728.25,
+    1e30,
+];
+
+/// Where the arc functions take simple values.
+#[cfg(test)]
+const ARC_TARGETS: [f64; 9] = [
+    0.5,
+    -0.5,
+    core::f64::consts::FRAC_1_SQRT_2,
+    0.866_025_403_784_438_6,
+    1.,
+    -1.,
+    1e-30,
+    1.732_050_807_568_877_2,
+    1e30,
+];
+
+#[test]
+fn test_sin_pi() {
+    test_accuracy(
+        P32E2::sin_pi,
+        |x| dd::sin_cos_pi(x).0.hi,
+        |x| dd::sin_cos_pi(x).0,
+        |r, p| Some(r.sin_pi(&fixed(p))),
+        &TARGETS,
+    );
+    assert_eq!(P32E2::NAR.sin_pi(), P32E2::NAR);
+    for &x in &[0., 1., -3., 1e6, 1e30] {
+        assert_eq!(P32E2::from(x).sin_pi(), P32E2::ZERO);
+    }
+    assert_eq!(P32E2::from(0.5).sin_pi(), P32E2::ONE);
+    assert_eq!(P32E2::from(-2.5).sin_pi(), -P32E2::ONE);
+    assert_eq!(P32E2::from(0.25).sin_pi(), P32E2::FRAC_1_SQRT_2);
+}
+
+#[test]
+fn test_cos_pi() {
+    test_accuracy(
+        P32E2::cos_pi,
+        |x| dd::sin_cos_pi(x).1.hi,
+        |x| dd::sin_cos_pi(x).1,
+        |r, p| Some(r.cos_pi(&fixed(p))),
+        &TARGETS,
+    );
+    assert_eq!(P32E2::NAR.cos_pi(), P32E2::NAR);
+    for &x in &[0.5, -1.5, 1e6 + 0.5] {
+        assert_eq!(P32E2::from(x).cos_pi(), P32E2::ZERO);
+    }
+    assert_eq!(P32E2::ZERO.cos_pi(), P32E2::ONE);
+    assert_eq!(P32E2::from(1e30).cos_pi(), P32E2::ONE);
+    assert_eq!(P32E2::from(-3.).cos_pi(), -P32E2::ONE);
+    assert_eq!(P32E2::from(0.75).cos_pi(), -P32E2::FRAC_1_SQRT_2);
+}
+
+#[test]
+fn test_tan_pi() {
+    test_accuracy(
+        P32E2::tan_pi,
+        |x| {
+            let (s, c) = dd::sin_cos_pi(x);
+            (s / c).hi
+        },
+        |x| {
+            let (s, c) = dd::sin_cos_pi(x);
+            s / c
+        },
+        |r, p| r.tan_pi(&fixed(p)),
+        &TARGETS,
+    );
+    assert_eq!(P32E2::NAR.tan_pi(), P32E2::NAR);
+    for &x in &[0.5, -1.5, 1e6 + 0.5] {
+        assert_eq!(P32E2::from(x).tan_pi(), P32E2::NAR);
+    }
+    assert_eq!(P32E2::from(-7.).tan_pi(), P32E2::ZERO);
+    assert_eq!(P32E2::from(0.25).tan_pi(), P32E2::ONE);
+    assert_eq!(P32E2::from(0.75).tan_pi(), -P32E2::ONE);
+}
+
+#[test]
+fn test_asin_pi() {
+    test_accuracy(
+        P32E2::asin_pi,
+        |x| (dd::asin(x) / dd::PI).hi,
+        |x| dd::asin(x) / dd::PI,
+        |r, p| {
+            let x = fixed(p);
+            if p.abs() > P32E2::ONE {
+                None
+            } else {
+                Some(r.asin_pi(&x))
+            }
+        },
+        &ARC_TARGETS,
+    );
+    assert_eq!(P32E2::NAR.asin_pi(), P32E2::NAR);
+    assert_eq!(P32E2::from(1.5).asin_pi(), P32E2::NAR);
+    assert_eq!(P32E2::ZERO.asin_pi(), P32E2::ZERO);
+    assert_eq!(P32E2::ONE.asin_pi(), P32E2::from(0.5));
+    assert_eq!((-P32E2::ONE).asin_pi(), P32E2::from(-0.5));
+}
+
+#[test]
+fn test_acos_pi() {
+    test_accuracy(
+        P32E2::acos_pi,
+        |x| (dd::acos(x) / dd::PI).hi,
+        |x| dd::acos(x) / dd::PI,
+        |r, p| {
+            let x = fixed(p);
+            if p.abs() > P32E2::ONE {
+                None
+            } else {
+                Some(r.acos_pi(&x))
+            }
+        },
+        &ARC_TARGETS,
+    );
+    assert_eq!(P32E2::NAR.acos_pi(), P32E2::NAR);
+    assert_eq!(P32E2::from(-1.5).acos_pi(), P32E2::NAR);
+    assert_eq!(P32E2::ONE.acos_pi(), P32E2::ZERO);
+    assert_eq!(P32E2::ZERO.acos_pi(), P32E2::from(0.5));
+    assert_eq!((-P32E2::ONE).acos_pi(), P32E2::ONE);
+}
+
+#[test]
+fn test_atan_pi() {
+    test_accuracy(
+        P32E2::atan_pi,
+        |x| (dd::atan(dd::DD::from(x)) / dd::PI).hi,
+        |x| dd::atan(dd::DD::from(x)) / dd::PI,
+        |r, p| Some(r.atan_pi(&fixed(p))),
+        &ARC_TARGETS,
+    );
+    assert_eq!(P32E2::NAR.atan_pi(), P32E2::NAR);
+    assert_eq!(P32E2::ZERO.atan_pi(), P32E2::ZERO);
+    assert_eq!(P32E2::ONE.atan_pi(), P32E2::from(0.25));
+    assert_eq!(P32E2::MIN.atan_pi(), P32E2::from(-0.5));
+}
@@ -432,12 +432,7 @@ impl P32E2 {
     }
 }
 
-impl ops::Rem for P32E2 {
-    type Output = Self;
-    fn rem(self, other: Self) -> Self {
-        self - (self / other).trunc() * other
-    }
-}
+crate::impl_rem!(P32E2, 32, 2);
 
 #[cfg(test)]
 fn test_ops(fun: fn(P32E2, P32E2, f64, f64) -> (P32E2, f64)) {
@@ -472,3 +467,23 @@ fn mul() {
 fn div() {
     test_ops(|p_a, p_b, f_a, f_b| (p_a / p_b, f_a / f_b));
 }
+
+/// Compares the remainders with the exact ones rounded by the reference, for
+/// quotients of any size.
+#[test]
+fn rem() {
+    use crate::p64e2::reference::div_rem;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS8 * 10 {
+        let p_a: P32E2 = rng.gen();
+        let p_b: P32E2 = rng.gen();
+        let [trunc, nearest, euclid] = div_rem(p_a.to_bits() as u64, p_b.to_bits() as u64, 32, 2);
+        let p = |bits: u64| P32E2::from_bits(bits as u32);
+        assert_eq!(p_a % p_b, p(trunc.1), "{:?} {:?}", p_a, p_b);
+        assert_eq!(p_a.remainder(p_b), p(nearest.1));
+        assert_eq!(p_a.remquo(p_b), (p(nearest.1), nearest.2));
+        assert_eq!(p_a.div_euclid(p_b), p(euclid.0));
+        assert_eq!(p_a.rem_euclid(p_b), p(euclid.1));
+    }
+}
@@ -0,0 +1,387 @@
+use crate::convert::{decode_posit, encode_posit};
+
+mod convert;
+mod math;
+mod ops;
+pub(crate) mod special;
+crate::impl_num_traits!(P64E2, 64, 2);
+#[cfg(feature = "approx")]
+crate::impl_ulps_eq!(P64E2, i64);
+#[cfg(feature = "approx")]
+use approx::AbsDiffEq;
+#[cfg(feature = "approx")]
+crate::impl_signed_abs_diff_eq!(P64E2, P64E2::ZERO);
+#[cfg(feature = "approx")]
+crate::impl_relative_eq!(P64E2, i64);
+
+#[cfg(feature = "alga")]
+crate::impl_lattice!(P64E2);
+#[cfg(feature = "alga")]
+crate::impl_real!(P64E2);
+#[cfg(feature = "alga")]
+crate::impl_complex!(P64E2);
+#[cfg(feature = "alga")]
+crate::impl_alga!(P64E2);
+#[cfg(feature = "alga")]
+use alga::general::{Additive, Multiplicative};
+
+/// Standard 64-bit posit (posit64, es = 2).
+///
+/// Unlike the smaller types, the arithmetic unpacks the operands into
+/// 128-bit integers and rounds the exact result once.
+#[cfg_attr(feature = "alga", derive(alga_derive::Alga))]
+#[cfg_attr(feature = "alga", alga_traits(Field(Additive, Multiplicative)))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct P64E2(i64);
+
+impl P64E2 {
+    pub const SIZE: usize = 64;
+    pub const ES: usize = 2;
+    pub const USEED: usize = 16;
+
+    /// Machine epsilon (1.734723475976807e-18).
+    pub const EPSILON: Self = Self::new(0x_0000_a000_0000_0000);
+
+    /// Smallest finite value (-4.523128485832664_e74).
+    pub const MIN: Self = Self::new(-0x_7FFF_FFFF_FFFF_FFFF);
+
+    /// Smallest positive normal value (2.210859150104178_e-75).
+    pub const MIN_POSITIVE: Self = Self::new(0x_1);
+
+    /// Largest finite value (4.523128485832664_e74).
+    pub const MAX: Self = Self::new(0x_7FFF_FFFF_FFFF_FFFF);
+
+    /// Not a Real (NaR).
+    pub const NAR: Self = Self::new(-0x_8000_0000_0000_0000);
+
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self::NAR;
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Self::NAR;
+
+    /// Zero.
+    pub const ZERO: Self = Self::new(0);
+
+    /// Identity.
+    pub const ONE: Self = Self::new(0x_4000_0000_0000_0000);
+
+    #[inline]
+    pub const fn new(i: i64) -> Self {
+        Self(i)
+    }
+    #[inline]
+    pub const fn from_bits(v: u64) -> Self {
+        Self(v as i64)
+    }
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        self.0 as u64
+    }
+    #[inline]
+    pub fn abs(self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+    #[inline]
+    pub fn is_nar(self) -> bool {
+        self == Self::NAR
+    }
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.is_nar()
+    }
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.is_nar()
+    }
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        !self.is_nar()
+    }
+    #[inline]
+    pub fn is_normal(self) -> bool {
+        !self.is_nar()
+    }
+    #[inline]
+    pub fn classify(self) -> core::num::FpCategory {
+        use core::num::FpCategory::*;
+        match self {
+            Self::ZERO => Zero,
+            Self::NAR => Nan,
+            _ => Normal,
+        }
+    }
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self < Self::ZERO
+    }
+    #[inline]
+    pub fn copysign(self, other: Self) -> Self {
+        if ((self.to_bits() ^ other.to_bits()) & Self::SIGN_MASK) != 0 {
+            -self
+        } else {
+            self
+        }
+    }
+    #[inline]
+    pub fn signum(self) -> Self {
+        match self.0 {
+            n if n == Self::NAR.0 => Self::NAR,
+            n if n > 0 => Self::ONE,
+            0 => Self::ZERO,
+            _ => -Self::ONE,
+        }
+    }
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+    #[inline]
+    pub fn to_degrees(self) -> Self {
+        const PIS_IN_180: P64E2 = P64E2::new(0x_6729_7706_98f0_7dee);
+        self * PIS_IN_180
+    }
+    #[inline]
+    pub fn to_radians(self) -> Self {
+        const PI_180: P64E2 = P64E2::new(0x_1877_d1a8_94a7_4e45);
+        self * PI_180
+    }
+}
+
+impl P64E2 {
+    pub const SIGN_MASK: u64 = 0x_8000_0000_0000_0000;
+
+    /// Unpacks a nonzero, non-NaR posit into its sign, scale and fraction,
+    /// with the hidden bit of the fraction at bit 63.
+    #[inline]
+    pub(crate) fn unpack(self) -> (bool, i32, u64) {
+        decode_posit(self.to_bits(), 64, 2)
+    }
+
+    /// Rounds `(-1)^sign * frac * 2^(scale - 127)`, with the hidden bit of
+    /// `frac` at bit 127, to the nearest posit.
+    #[inline]
+    pub(crate) fn encode(sign: bool, scale: i32, frac: u128, sticky: bool) -> Self {
+        Self::from_bits(encode_posit(sign, scale, frac, sticky, 64, 2))
+    }
+}
+
+impl core::str::FromStr for P64E2 {
+    type Err = crate::ParsePositError;
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_posit(src, 64, 2).map(Self::from_bits)
+    }
+}
+
+crate::impl_fmt!(P64E2, 64, 2);
+crate::impl_fields!(P64E2, 64, 2);
+
+use core::fmt;
+impl fmt::Debug for P64E2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            crate::fields::debug(f, "P64E2", self.to_bits(), 64, 2)
+        } else {
+            write!(f, "P64E2({})", self.0)
+        }
+    }
+}
+
+impl crate::AssociatedQuire<Self> for P64E2 {
+    type Q = crate::Q64E2;
+}
+
+impl crate::polynom::poly::Poly<Self> for P64E2 {}
+impl crate::Polynom<Self> for P64E2 {}
+
+impl crate::polynom::poly::Poly<[Self; 1]> for P64E2 {}
+impl crate::Polynom<[Self; 1]> for P64E2 {}
+impl crate::polynom::poly::Poly<[Self; 2]> for P64E2 {}
+impl crate::Polynom<[Self; 2]> for P64E2 {}
+impl crate::polynom::poly::Poly<[Self; 3]> for P64E2 {}
+impl crate::Polynom<[Self; 3]> for P64E2 {}
+impl crate::polynom::poly::Poly<[Self; 4]> for P64E2 {}
+impl crate::Polynom<[Self; 4]> for P64E2 {}
+
+#[cfg(any(feature = "rand", test))]
+impl rand::distributions::Distribution<P64E2> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> P64E2 {
+        // every posit but NaR, maxpos included
+        let bits = rand::distributions::Uniform::new_inclusive(
+            -0x_7fff_ffff_ffff_ffff_i64,
+            0x_7fff_ffff_ffff_ffff,
+        );
+        P64E2::new(rng.sample(bits))
+    }
+}
+
+/// Exact rational values of posits, used as a test reference.
+#[cfg(test)]
+pub(crate) mod reference {
+    use num_bigint::BigInt;
+    use num_traits::{One, Signed, Zero};
+
+    /// Exact value `m * 2^e` of an `n`-bit posit with `es` exponent bits,
+    /// decoded bit by bit.
+    pub fn value(bits: u128, n: u32, es: u32) -> (BigInt, i32) {
+        let bits = bits & (u128::MAX >> (128 - n));
+        if bits == 0 {
+            return (BigInt::zero(), 0);
+        }
+        let neg = (bits >> (n - 1)) != 0;
+        let body = if neg {
+            bits.wrapping_neg() & (u128::MAX >> (128 - n))
+        } else {
+            bits
+        };
+        let bit = |i: i32| i >= 0 && ((body >> i) & 1) != 0;
+        let mut i = n as i32 - 2;
+        let first = bit(i);
+        let mut run = 0;
+        while i >= 0 && bit(i) == first {
+            run += 1;
+            i -= 1;
+        }
+        i -= 1; // terminating bit
+        let k = if first { run - 1 } else { -run };
+        let mut exp = 0;
+        for _ in 0..es {
+            exp = (exp << 1) | bit(i) as i32;
+            i -= 1;
+        }
+        let mut m = BigInt::one();
+        let mut e = (k << es) + exp;
+        while i >= 0 {
+            m = (m << 1) + bit(i) as u8;
+            e -= 1;
+            i -= 1;
+        }
+        (if neg { -m } else { m }, e)
+    }
+
+    /// Compares `a * 2^ea` with `b * 2^eb`.
+    pub fn cmp(a: &BigInt, ea: i32, b: &BigInt, eb: i32) -> core::cmp::Ordering {
+        let e = ea.min(eb);
+        let a = a << (ea - e) as usize;
+        let b = b << (eb - e) as usize;
+        a.cmp(&b)
+    }
+
+    /// Bits of the `n`-bit posit nearest to `(num / den) * 2^e`, found by a
+    /// bisection over the bit patterns. The rounding boundary between two
+    /// posits is the `n + 1`-bit posit in between, and numbers below minpos
+    /// round to minpos.
+    pub fn round(num: &BigInt, den: &BigInt, e: i32, n: u32, es: u32) -> u64 {
+        if num.is_zero() {
+            return 0;
+        }
+        let neg = num.is_negative() != den.is_negative();
+        let (num, den) = (num.abs(), den.abs());
+        // x compared with m * 2^em: num * 2^e <=> m * 2^em * den
+        let cmp_x = |m: &BigInt, em: i32| cmp(&num, e, &(m * &den), em);
+        let maxpos = u64::MAX >> (65 - n);
+        let (mut lo, mut hi) = (1, maxpos);
+        while lo < hi {
+            let mid = lo + (hi - lo).div_ceil(2);
+            let (m, em) = value(mid as u128, n, es);
+            if cmp_x(&m, em) == core::cmp::Ordering::Less {
+                hi = mid - 1;
+            } else {
+                lo = mid;
+            }
+        }
+        let mut bits = lo;
+        if bits < maxpos {
+            let (m, em) = value(((bits as u128) << 1) | 1, n + 1, es);
+            match cmp_x(&m, em) {
+                core::cmp::Ordering::Greater => bits += 1,
+                core::cmp::Ordering::Equal => bits += bits & 1,
+                core::cmp::Ordering::Less => {}
+            }
+        }
+        if neg {
+            bits.wrapping_neg() & (u64::MAX >> (64 - n))
+        } else {
+            bits
+        }
+    }
+
+    /// Integer quotients `q` of two `n`-bit posits with `es` exponent bits,
+    /// truncated, rounded to nearest even and chosen for a nonnegative
+    /// remainder, as the bits of `q` and of the remainder `a - q * b` and the
+    /// lowest 31 bits of `q` with its sign.
+    pub fn div_rem(a: u64, b: u64, n: u32, es: u32) -> [(u64, u64, i32); 3] {
+        use num_traits::ToPrimitive;
+        let nar = 1 << (n - 1);
+        if a == nar || b == nar || b == 0 {
+            return [(nar, nar, 0); 3];
+        }
+        let (ma, ea) = value(a as u128, n, es);
+        let (mb, eb) = value(b as u128, n, es);
+        let e = ea.min(eb);
+        let x = ma << (ea - e) as usize;
+        let y = mb << (eb - e) as usize;
+        let result = |q: BigInt| {
+            let r = &x - &q * &y;
+            let low = (q.abs() % (BigInt::one() << 31)).to_i32().unwrap();
+            let quo = if q.is_negative() { -low } else { low };
+            (
+                round(&q, &BigInt::one(), 0, n, es),
+                round(&r, &BigInt::one(), e, n, es),
+                quo,
+            )
+        };
+        // truncating division
+        let q = &x / &y;
+        let r = &x - &q * &y;
+        let away = if x.is_negative() == y.is_negative() {
+            BigInt::one()
+        } else {
+            -BigInt::one()
+        };
+        let twice = r.abs() * 2;
+        let odd = !(&q % BigInt::from(2)).is_zero();
+        let nearest = if twice > y.abs() || (twice == y.abs() && odd) {
+            &q + &away
+        } else {
+            q.clone()
+        };
+        let euclid = if r.is_negative() {
+            &q + &away
+        } else {
+            q.clone()
+        };
+        [result(q), result(nearest), result(euclid)]
+    }
+}
+
+#[test]
+fn test_consts() {
+    let value = |p: P64E2| f64::from(p);
+    assert_eq!(value(P64E2::MAX), 2_f64.powi(248));
+    assert_eq!(value(P64E2::MIN), -(2_f64.powi(248)));
+    assert_eq!(value(P64E2::MIN_POSITIVE), 2_f64.powi(-248));
+    assert_eq!(value(P64E2::EPSILON), 2_f64.powi(-59));
+    assert_eq!(
+        P64E2::ONE + P64E2::EPSILON,
+        P64E2::from_bits(P64E2::ONE.to_bits() + 1)
+    );
+    assert!(P64E2::NAR < P64E2::MIN);
+    let pi: P64E2 = crate::MathConsts::PI;
+    // the product with the rounded constant is within an ulp
+    assert!((P64E2::from(180.).to_radians().0 - pi.0).abs() <= 1);
+    assert_eq!(pi.to_degrees(), P64E2::from(180.));
+}
@@ -0,0 +1,137 @@
+use super::P64E2;
+use crate::{P16E1, P16E2, P32E2, P8E0, P8E2};
+
+crate::impl_convert!(P64E2);
+crate::impl_int_convert!(P64E2, 64, 2);
+crate::impl_xfloat_convert!(P64E2, 64, 2);
+crate::impl_xfloat_from_posit!(
+    P64E2, 64, 2;
+    P8E0, 8, 0, u8;
+    P16E1, 16, 1, u16;
+    P32E2, 32, 2, u32;
+    P8E2, 8, 2, u8;
+    P16E2, 16, 2, u16
+);
+
+#[test]
+fn convert_p64_f64() {
+    use super::reference::{round, value};
+    use num_bigint::BigInt;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let p_a: P64E2 = rng.gen();
+        let f_a = f64::from(p_a);
+        // f64 rounds the posit to its 53 leading bits
+        let (m, e) = value(p_a.to_bits() as u128, 64, 2);
+        let m = num_traits::ToPrimitive::to_f64(&m).unwrap();
+        assert_eq!(f_a, m * 2_f64.powi(e), "{:?}", p_a);
+        // and f64 -> P64E2 agrees with the reference
+        let bits = f_a.to_bits();
+        let mant = (bits & 0x000F_FFFF_FFFF_FFFF) | 0x0010_0000_0000_0000;
+        let exp = ((bits >> 52) & 0x7FF) as i32 - 1075;
+        let mant = if f_a < 0. {
+            -BigInt::from(mant)
+        } else {
+            BigInt::from(mant)
+        };
+        assert_eq!(
+            P64E2::from(f_a).to_bits(),
+            round(&mant, &1.into(), exp, 64, 2)
+        );
+    }
+    assert!(f64::from(P64E2::NAR).is_nan());
+    assert_eq!(P64E2::from(f64::NAN), P64E2::NAR);
+    assert_eq!(P64E2::from(f64::INFINITY), P64E2::NAR);
+    assert_eq!(P64E2::from(1e300), P64E2::MAX);
+    assert_eq!(P64E2::from(-1e-300), -P64E2::MIN_POSITIVE);
+    assert_eq!(f32::from(P64E2::MAX), f32::INFINITY);
+    assert_eq!(f32::from(P64E2::ONE), 1.);
+}
+
+#[test]
+fn convert_p64_int() {
+    use super::reference::round;
+    use crate::PositConversionError;
+    use core::convert::TryFrom;
+    use num_bigint::BigInt;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        // integers below 2^46 are exact
+        let i = rng.gen_range(-(1_i64 << 46), 1 << 46);
+        assert_eq!(i64::try_from(P64E2::from(i)), Ok(i));
+        assert_eq!(i128::try_from(P64E2::from(i as i128)), Ok(i as i128));
+        let u = rng.gen_range(0, 1_u64 << 46);
+        assert_eq!(u64::try_from(P64E2::from(u)), Ok(u));
+        let j: i32 = rng.gen();
+        assert_eq!(i32::try_from(P64E2::from(j)), Ok(j));
+        // larger integers are rounded
+        let k: i64 = rng.gen();
+        let p_k = P64E2::from(k);
+        assert_eq!(p_k.to_bits(), round(&BigInt::from(k), &1.into(), 0, 64, 2));
+        let k: u128 = rng.gen::<u128>() >> 1;
+        let p_k = P64E2::from(k);
+        assert_eq!(p_k.to_bits(), round(&BigInt::from(k), &1.into(), 0, 64, 2));
+        assert_eq!(P64E2::from(u128::try_from(p_k).unwrap()), p_k);
+        // and the directed roundings of a fraction bracket it
+        let p_a = P64E2::from(rng.gen_range(-1e12, 1e12));
+        let (floor, ceil) = (p_a.to_int_floor::<i64>(), p_a.to_int_ceil::<i64>());
+        assert_eq!(P64E2::from(floor.unwrap()), p_a.floor());
+        assert_eq!(P64E2::from(ceil.unwrap()), p_a.ceil());
+        assert_eq!(P64E2::from(p_a.to_int_trunc::<i64>().unwrap()), p_a.trunc());
+    }
+    assert_eq!(i64::try_from(P64E2::NAR), Err(PositConversionError::NaR));
+    assert_eq!(u64::try_from(P64E2::NAR), Err(PositConversionError::NaR));
+    assert_eq!(
+        i32::try_from(P64E2::MAX),
+        Err(PositConversionError::Overflow)
+    );
+    assert_eq!(
+        i128::try_from(P64E2::MIN),
+        Err(PositConversionError::Overflow)
+    );
+    assert_eq!(
+        u64::try_from(-P64E2::ONE),
+        Err(PositConversionError::Negative)
+    );
+    assert_eq!(i64::try_from(P64E2::from(2.5)), Ok(2));
+    assert_eq!(i64::try_from(P64E2::from(-3.5)), Ok(-4));
+    assert_eq!(P64E2::from(-3.5).to_int_ceil(), Ok(-3));
+    assert_eq!(P64E2::from(-0.25).to_int_floor(), Ok(-1));
+    assert_eq!(P64E2::from(0.25).to_int_ceil(), Ok(1));
+    assert_eq!(
+        u128::try_from(P64E2::from(u128::MAX)),
+        Err(PositConversionError::Overflow)
+    );
+    assert_eq!(i128::try_from(P64E2::from(i128::MIN)), Ok(i128::MIN));
+    assert_eq!(i128::try_from(P64E2::from(1_i128 << 100)), Ok(1 << 100));
+}
+
+#[test]
+fn convert_p64_posits() {
+    for i in 0..0x1_0000_u32 {
+        let p_a = P16E1::from_bits(i as u16);
+        let p_b = P64E2::from(p_a);
+        assert_eq!(f64::from(p_b).to_bits(), f64::from(p_a).to_bits());
+        assert_eq!(P16E1::from(p_b), p_a);
+    }
+    for i in 0..0x100_u32 {
+        let p_a = P8E0::from_bits(i as u8);
+        let p_b = P64E2::from(p_a);
+        assert_eq!(f64::from(p_b).to_bits(), f64::from(p_a).to_bits());
+        assert_eq!(P8E0::from(p_b), p_a);
+    }
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS32 {
+        let p_a: P32E2 = rng.gen();
+        let p_b = P64E2::from(p_a);
+        assert_eq!(f64::from(p_b), f64::from(p_a));
+        assert_eq!(P32E2::from(p_b), p_a);
+        // narrowing rounds like the conversion through f64 for values that
+        // f64 holds exactly
+        let p_c = P64E2::new(p_b.0 + rng.gen_range(-0xFFFF, 0xFFFF));
+        assert_eq!(P32E2::from(p_c), P32E2::from(f64::from(p_c)));
+    }
+}
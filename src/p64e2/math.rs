@@ -0,0 +1,113 @@
+use super::P64E2;
+
+impl crate::MathConsts for P64E2 {
+    const E: Self = Self::new(0x_4adf_8545_8a2b_b4aa);
+    const FRAC_1_PI: Self = Self::new(0x_322f_9836_e4e4_4153);
+    const FRAC_1_SQRT_2: Self = Self::new(0x_3b50_4f33_3f9d_e648);
+    const FRAC_2_PI: Self = Self::new(0x_3a2f_9836_e4e4_4153);
+    const FRAC_2_SQRT_PI: Self = Self::new(0x_4106_eba8_214d_b689);
+    const FRAC_PI_2: Self = Self::new(0x_4490_fdaa_2216_8c23);
+    const FRAC_PI_3: Self = Self::new(0x_4060_a91c_16b9_b2c2);
+    const FRAC_PI_4: Self = Self::new(0x_3c90_fdaa_2216_8c23);
+    const FRAC_PI_6: Self = Self::new(0x_3860_a91c_16b9_b2c2);
+    const FRAC_PI_8: Self = Self::new(0x_3490_fdaa_2216_8c23);
+    const LN_10: Self = Self::new(0x_4935_d8dd_daaa_8ac1);
+    const LN_2: Self = Self::new(0x_3b17_217f_7d1c_f79b);
+    const LOG10_E: Self = Self::new(0x_35e5_bd8a_9372_8719);
+    const LOG2_E: Self = Self::new(0x_438a_a3b2_95c1_7f0c);
+    const PI: Self = Self::new(0x_4c90_fdaa_2216_8c23);
+    const SQRT_2: Self = Self::new(0x_4350_4f33_3f9d_e648);
+    const LOG2_10: Self = Self::new(0x_4d49_a784_bcd1_b8b0);
+    const LOG10_2: Self = Self::new(0x_31a2_09a8_4fbc_ff7a);
+}
+
+crate::impl_xfloat_math!(P64E2, 64, 2);
+
+#[test]
+fn test_consts() {
+    use crate::MathConsts;
+    use core::f64::consts;
+    let c = |p: P64E2| f64::from(p);
+    assert_eq!(c(MathConsts::E), consts::E);
+    assert_eq!(c(MathConsts::PI), consts::PI);
+    assert_eq!(c(MathConsts::LN_2), consts::LN_2);
+    assert_eq!(c(MathConsts::SQRT_2), consts::SQRT_2);
+    assert_eq!(c(MathConsts::FRAC_1_PI), consts::FRAC_1_PI);
+}
+
+#[test]
+fn test_rounding() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS32 {
+        // numbers with a few integer bits, whose `f64` values are exact
+        let f = rng.gen_range(-1e6, 1e6);
+        let p_a = P64E2::from(f);
+        let f_a = f64::from(p_a);
+        assert_eq!(p_a.trunc(), P64E2::from(f_a.trunc()));
+        assert_eq!(p_a.floor(), P64E2::from(f_a.floor()));
+        assert_eq!(p_a.ceil(), P64E2::from(f_a.ceil()));
+        if (f_a - f_a.trunc()).abs() != 0.5 {
+            assert_eq!(p_a.round(), P64E2::from(f_a.round()));
+        }
+        assert_eq!(p_a.fract(), P64E2::from(f_a.fract()));
+    }
+    assert_eq!(P64E2::from(2.5).round(), P64E2::from(2.));
+    assert_eq!(P64E2::from(-3.5).round(), P64E2::from(-4.));
+    assert_eq!(P64E2::from(-0.5).round(), P64E2::ZERO);
+    assert_eq!(P64E2::MAX.floor(), P64E2::MAX);
+    assert_eq!(P64E2::MIN_POSITIVE.ceil(), P64E2::ONE);
+}
+
+#[test]
+fn test_sqrt_mul_add() {
+    use super::reference::{cmp, round, value};
+    use core::cmp::Ordering;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS8 * 10 {
+        let (p_a, p_b, p_c): (P64E2, P64E2, P64E2) = (rng.gen(), rng.gen(), rng.gen());
+        // the number lies between the squares of the midpoints around its root
+        if !p_a.is_zero() {
+            let (a, ea) = value(p_a.abs().to_bits() as u128, 64, 2);
+            let r = p_a.abs().sqrt().to_bits();
+            let (lo, elo) = value(((r as u128) << 1) - 1, 65, 2);
+            let (hi, ehi) = value(((r as u128) << 1) + 1, 65, 2);
+            assert_ne!(cmp(&a, ea, &(&lo * &lo), 2 * elo), Ordering::Less);
+            assert_ne!(cmp(&a, ea, &(&hi * &hi), 2 * ehi), Ordering::Greater);
+        }
+        let (a, ea) = value(p_a.to_bits() as u128, 64, 2);
+        let (b, eb) = value(p_b.to_bits() as u128, 64, 2);
+        let (c, ec) = value(p_c.to_bits() as u128, 64, 2);
+        let ep = ea + eb;
+        let e = ep.min(ec);
+        let sum = ((a * b) << (ep - e) as usize) + (c << (ec - e) as usize);
+        assert_eq!(
+            p_a.mul_add(p_b, p_c).to_bits(),
+            round(&sum, &1.into(), e, 64, 2)
+        );
+    }
+    assert_eq!(P64E2::from(2.).sqrt(), crate::MathConsts::SQRT_2);
+    assert_eq!(P64E2::from(4.).sqrt(), P64E2::from(2.));
+    assert_eq!((-P64E2::ONE).sqrt(), P64E2::NAR);
+}
+
+#[test]
+fn test_math() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let f = rng.gen_range(-100., 100.);
+        let p_a = P64E2::from(f);
+        let f_a = f64::from(p_a);
+        // one f64 rounding in between, an ulp of f64 is 2^7 ulps of P64E2 near one
+        let close = |p: P64E2, f: f64| (p.0 - P64E2::from(f).0).abs() <= 1 << 8;
+        assert!(close(p_a.exp(), f_a.exp()));
+        assert!(close(p_a.sin(), f_a.sin()));
+        assert!(close(p_a.abs().ln(), f_a.abs().ln()));
+        assert!(close(p_a.atan(), f_a.atan()));
+    }
+    assert_eq!(P64E2::from(1000.).exp(), P64E2::MAX);
+    assert_eq!(P64E2::from(-1000.).exp(), P64E2::MIN_POSITIVE);
+    assert_eq!(P64E2::ZERO.ln(), P64E2::NAR);
+}
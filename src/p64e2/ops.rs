@@ -0,0 +1,117 @@
+use super::P64E2;
+
+crate::impl_xfloat_ops!(P64E2, 64, 2);
+
+#[cfg(test)]
+type Exact = (num_bigint::BigInt, i32);
+
+/// Compares an operation with the exact result `num / den * 2^e` rounded by
+/// the reference.
+#[cfg(test)]
+fn test_ops(
+    fun: fn(P64E2, P64E2) -> P64E2,
+    exact: fn(Exact, Exact) -> (num_bigint::BigInt, num_bigint::BigInt, i32),
+) {
+    use super::reference::{round, value};
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS8 * 10 {
+        let p_a: P64E2 = rng.gen();
+        // neighbours of `p_a` exercise cancellation and ties
+        let p_b = if rng.gen() {
+            rng.gen()
+        } else {
+            P64E2::new(p_a.0.saturating_add(rng.gen_range(-1000, 1000)))
+        };
+        if p_a.is_zero() || p_b.is_zero() || p_b.is_nar() {
+            continue;
+        }
+        let (num, den, e) = exact(
+            value(p_a.to_bits() as u128, 64, 2),
+            value(p_b.to_bits() as u128, 64, 2),
+        );
+        assert_eq!(
+            fun(p_a, p_b).to_bits(),
+            round(&num, &den, e, 64, 2),
+            "{:?} {:?}",
+            p_a,
+            p_b
+        );
+    }
+}
+
+#[cfg(test)]
+fn exact_add((a, ea): Exact, (b, eb): Exact) -> (num_bigint::BigInt, num_bigint::BigInt, i32) {
+    let e = ea.min(eb);
+    let sum = (a << (ea - e) as usize) + (b << (eb - e) as usize);
+    (sum, 1.into(), e)
+}
+
+#[test]
+fn add() {
+    test_ops(|p_a, p_b| p_a + p_b, exact_add);
+}
+
+#[test]
+fn sub() {
+    test_ops(|p_a, p_b| p_a - p_b, |a, (b, eb)| exact_add(a, (-b, eb)));
+}
+
+#[test]
+fn mul() {
+    test_ops(
+        |p_a, p_b| p_a * p_b,
+        |(a, ea), (b, eb)| (a * b, 1.into(), ea + eb),
+    );
+}
+
+#[test]
+fn div() {
+    test_ops(|p_a, p_b| p_a / p_b, |(a, ea), (b, eb)| (a, b, ea - eb));
+}
+
+#[test]
+fn test_ops_special() {
+    let one = P64E2::ONE;
+    let eps = P64E2::EPSILON;
+    assert_eq!(one + eps / (one + one), one);
+    assert_eq!((one + eps) - one, eps);
+    assert_eq!(P64E2::MAX + P64E2::MAX, P64E2::MAX);
+    assert_eq!(
+        P64E2::MIN_POSITIVE * P64E2::MIN_POSITIVE,
+        P64E2::MIN_POSITIVE
+    );
+    assert_eq!(P64E2::MAX - P64E2::MAX, P64E2::ZERO);
+    assert_eq!(one / P64E2::ZERO, P64E2::NAR);
+    assert_eq!(P64E2::NAR + one, P64E2::NAR);
+    assert_eq!(P64E2::from(7.) % P64E2::from(2.), one);
+    assert_eq!(P64E2::from(-7.) % P64E2::from(2.), -one);
+    // the quotient of 2^200 by 3 needs more bits than any posit fraction
+    let (big, three) = (P64E2::from(2_f64.powi(200)), P64E2::from(3.));
+    assert_eq!(big % three, one);
+    assert_eq!(big.remainder(three), one);
+    assert_eq!((-big).rem_euclid(three), one + one);
+    assert_eq!(P64E2::from(5.5).remquo(one), (-P64E2::from(0.5), 6));
+    assert_eq!(P64E2::from(-4.5).remquo(-one), (-P64E2::from(0.5), 4));
+    assert_eq!(one % P64E2::ZERO, P64E2::NAR);
+}
+
+/// Compares the remainders with the exact ones rounded by the reference, for
+/// quotients of any size.
+#[test]
+fn rem() {
+    use crate::p64e2::reference::div_rem;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS8 * 10 {
+        let p_a: P64E2 = rng.gen();
+        let p_b: P64E2 = rng.gen();
+        let [trunc, nearest, euclid] = div_rem(p_a.to_bits(), p_b.to_bits(), 64, 2);
+        let p = P64E2::from_bits;
+        assert_eq!(p_a % p_b, p(trunc.1), "{:?} {:?}", p_a, p_b);
+        assert_eq!(p_a.remainder(p_b), p(nearest.1));
+        assert_eq!(p_a.remquo(p_b), (p(nearest.1), nearest.2));
+        assert_eq!(p_a.div_euclid(p_b), p(euclid.0));
+        assert_eq!(p_a.rem_euclid(p_b), p(euclid.1));
+    }
+}
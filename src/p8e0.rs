@@ -4,7 +4,7 @@ use core::mem;
 mod convert;
 mod math;
 mod ops;
-crate::impl_num_traits!(P8E0);
+crate::impl_num_traits!(P8E0, 8, 0);
 #[cfg(feature = "approx")]
 crate::impl_ulps_eq!(P8E0, i8);
 #[cfg(feature = "approx")]
@@ -193,20 +193,6 @@ impl P8E0 {
         (k, tmp)
     }
 
-    #[inline]
-    fn calculate_scale(mut bits: u8) -> (u8, u8) {
-        let mut scale = 0_u8;
-        // Decode the posit, left-justifying as we go.
-        bits -= 0x40; // Strip off first regime bit (which is a 1).
-        while (0x20 & bits) != 0 {
-            // Increment scale one for each regime sign bit.
-            scale += 1; // Regime sign bit is always 1 in this range.
-            bits = (bits - 0x20) << 1; // Remove the bit; line up the next regime bit.
-        }
-        bits <<= 1; // Skip over termination bit, which is 0.
-        (scale, bits)
-    }
-
     #[inline]
     pub(crate) fn calculate_regime(k: i8) -> (u8, bool, u32) {
         let len;
@@ -221,23 +207,24 @@ impl P8E0 {
 }
 
 impl core::str::FromStr for P8E0 {
-    type Err = core::num::ParseFloatError;
+    type Err = crate::ParsePositError;
     #[inline]
-    fn from_str(src: &str) -> Result<Self, core::num::ParseFloatError> {
-        Ok(Self::from(f64::from_str(src)?))
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_posit(src, 8, 0).map(|bits| Self::from_bits(bits as u8))
     }
 }
 
-use core::fmt;
-impl fmt::Display for P8E0 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", f64::from(*self))
-    }
-}
+crate::impl_fmt!(P8E0, 8, 0);
+crate::impl_fields!(P8E0, 8, 0);
 
+use core::fmt;
 impl fmt::Debug for P8E0 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "P8E0({})", self.0)
+        if f.alternate() {
+            crate::fields::debug(f, "P8E0", self.to_bits() as u64, 8, 0)
+        } else {
+            write!(f, "P8E0({})", self.0)
+        }
     }
 }
 
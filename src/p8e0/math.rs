@@ -1,8 +1,5 @@
 use super::P8E0;
 
-const HALF: P8E0 = P8E0::new(0x_20);
-const TWO: P8E0 = P8E0::new(0x_60);
-
 impl crate::MathConsts for P8E0 {
     const E: Self = Self::new(0x_66);
     const FRAC_1_PI: Self = Self::new(0x_14);
@@ -38,135 +35,54 @@ impl P8E0 {
         self - self.trunc()
     }
     #[inline]
-    pub fn div_euclid(self, rhs: Self) -> Self {
-        let q = (self / rhs).trunc();
-        if self % rhs < Self::ZERO {
-            return if rhs > Self::ZERO {
-                q - Self::ONE
-            } else {
-                q + Self::ONE
-            };
-        }
-        q
-    }
-    #[inline]
-    pub fn rem_euclid(self, rhs: Self) -> Self {
-        let r = self % rhs;
-        if r < Self::ZERO {
-            r + rhs.abs()
-        } else {
-            r
-        }
-    }
-    #[inline]
-    pub fn powi(self, _n: i32) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn powf(self, _n: Self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn exp2(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn log(self, _base: Self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn log2(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn log10(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn cbrt(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn hypot(self, _other: Self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn sin(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn cos(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn tan(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn asin(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn acos(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn atan(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn atan2(self, _other: Self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
     pub fn sin_cos(self) -> (Self, Self) {
         (self.sin(), self.cos())
     }
-    #[inline]
-    pub fn exp_m1(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn ln_1p(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn sinh(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn cosh(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn tanh(self) -> Self {
-        unimplemented!()
-    }
-    #[inline]
-    pub fn asinh(self) -> Self {
-        if self.is_nar() {
-            self
-        } else {
-            (self + ((self * self) + Self::ONE).sqrt()).ln()
-        }
-    }
-    #[inline]
-    pub fn acosh(self) -> Self {
-        match self {
-            x if x < Self::ONE => Self::NAR,
-            x => (x + ((x * x) - Self::ONE).sqrt()).ln(),
-        }
-    }
-    #[inline]
-    pub fn atanh(self) -> Self {
-        HALF * ((TWO * self) / (Self::ONE - self)).ln_1p()
-    }
 }
 
+mod cbrt;
 mod ceil;
 mod exp;
+mod exp2;
+mod exp_m1;
 mod floor;
+mod hyperbolic;
+mod hypot;
 mod ln;
+mod ln_1p;
+mod log;
+mod log2;
 mod mul_add;
+mod powf;
+mod powi;
 mod round;
+mod special;
 mod sqrt;
+mod trig;
+mod trig_pi;
+
+/// Checks `fun` on every posit against the correctly rounded `exact` value,
+/// where `exact` returns `None` for a NaR result.
+#[cfg(test)]
+fn test_reference<E>(fun: fn(P8E0) -> P8E0, exact: E)
+where
+    E: Fn(&Reference, &num_bigint::BigInt, P8E0) -> Option<reference::Exact>,
+{
+    let r = Reference::new();
+    for i in -0x80_i32..0x80 {
+        let p_a = P8E0::new(i as i8);
+        let expected = if p_a.is_nar() {
+            None
+        } else {
+            exact(&r, &reference::fixed(p_a.to_bits() as u64, 8, 0), p_a)
+        };
+        let expected = match expected {
+            Some(value) => P8E0::from_bits(reference::round(&value, 8, 0).unwrap() as u8),
+            None => P8E0::NAR,
+        };
+        assert_eq!(fun(p_a), expected, "x = {}", f64::from(p_a));
+    }
+}
+
+#[cfg(test)]
+use crate::dd::reference::{self, Reference};
@@ -0,0 +1,30 @@
+use super::P8E0;
+
+const CBRT8: [u8; 256] = [
+    0, 16, 20, 23, 25, 27, 29, 31, 32, 33, 34, 36, 37, 38, 39, 39, 40, 41, 42, 43, 43, 44, 45, 46,
+    46, 47, 47, 48, 49, 49, 50, 50, 51, 51, 52, 52, 53, 53, 54, 54, 55, 55, 56, 56, 56, 57, 57, 58,
+    58, 59, 59, 59, 60, 60, 60, 61, 61, 62, 62, 62, 63, 63, 63, 64, 64, 64, 65, 65, 65, 66, 66, 66,
+    66, 67, 67, 67, 68, 68, 68, 68, 69, 69, 69, 69, 70, 70, 70, 70, 71, 71, 71, 71, 71, 72, 72, 72,
+    72, 73, 74, 75, 75, 76, 77, 78, 78, 79, 79, 80, 81, 81, 82, 82, 83, 85, 87, 88, 90, 92, 93, 95,
+    96, 97, 98, 99, 100, 103, 105, 112, 128, 144, 151, 153, 156, 157, 158, 159, 160, 161, 163, 164,
+    166, 168, 169, 171, 173, 174, 174, 175, 175, 176, 177, 177, 178, 178, 179, 180, 181, 181, 182,
+    183, 184, 184, 184, 184, 185, 185, 185, 185, 185, 186, 186, 186, 186, 187, 187, 187, 187, 188,
+    188, 188, 188, 189, 189, 189, 190, 190, 190, 190, 191, 191, 191, 192, 192, 192, 193, 193, 193,
+    194, 194, 194, 195, 195, 196, 196, 196, 197, 197, 197, 198, 198, 199, 199, 200, 200, 200, 201,
+    201, 202, 202, 203, 203, 204, 204, 205, 205, 206, 206, 207, 207, 208, 209, 209, 210, 210, 211,
+    212, 213, 213, 214, 215, 216, 217, 217, 218, 219, 220, 222, 223, 224, 225, 227, 229, 231, 233,
+    236, 240,
+];
+
+impl P8E0 {
+    /// Cube root.
+    #[inline]
+    pub fn cbrt(self) -> Self {
+        Self::from_bits(CBRT8[self.to_bits() as usize])
+    }
+}
+
+#[test]
+fn test_cbrt() {
+    super::test_reference(P8E0::cbrt, |r, x, _| Some(r.cbrt(x)));
+}
@@ -0,0 +1,32 @@
+use super::P8E0;
+
+const EXP_M1_8: [u8; 256] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 9, 10, 11, 12, 13, 14, 16, 17, 18, 19, 21, 22, 23, 25, 26, 28, 29, 31,
+    32, 34, 35, 37, 38, 40, 42, 43, 45, 47, 48, 50, 52, 54, 56, 57, 59, 61, 63, 65, 66, 67, 68, 69,
+    70, 71, 72, 73, 74, 76, 77, 78, 79, 80, 82, 83, 84, 86, 87, 90, 93, 96, 97, 97, 98, 99, 100,
+    101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 112, 113, 113, 114, 114, 114, 115,
+    115, 115, 116, 116, 117, 119, 120, 121, 122, 122, 123, 124, 124, 125, 125, 126, 126, 126, 126,
+    126, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 128, 192,
+    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 193, 193, 193, 194, 194, 194,
+    194, 194, 195, 195, 196, 196, 197, 197, 198, 199, 200, 201, 201, 201, 202, 202, 202, 202, 203,
+    203, 203, 204, 204, 205, 205, 205, 206, 206, 207, 207, 208, 208, 209, 209, 210, 210, 211, 212,
+    212, 213, 213, 214, 215, 216, 216, 216, 217, 217, 217, 218, 218, 219, 219, 220, 220, 220, 221,
+    221, 222, 222, 223, 223, 224, 224, 225, 225, 226, 226, 227, 227, 228, 228, 229, 230, 230, 231,
+    231, 232, 233, 233, 234, 235, 235, 236, 237, 237, 238, 239, 240, 240, 241, 242, 243, 243, 244,
+    245, 246, 247, 248, 248, 249, 250, 251, 252, 253, 254, 255,
+];
+
+impl P8E0 {
+    /// Returns `e^(self) - 1`.
+    #[inline]
+    pub fn exp_m1(self) -> Self {
+        Self::from_bits(EXP_M1_8[self.to_bits() as usize])
+    }
+}
+
+#[test]
+fn test_exp_m1() {
+    super::test_reference(P8E0::exp_m1, |r, x, _| Some(r.exp_m1(x)));
+    assert_eq!(P8E0::MIN_POSITIVE.exp_m1(), P8E0::MIN_POSITIVE);
+    assert_eq!((-P8E0::MIN_POSITIVE).exp_m1(), -P8E0::MIN_POSITIVE);
+}
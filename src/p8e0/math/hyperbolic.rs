@@ -0,0 +1,167 @@
+use super::P8E0;
+
+const SINH8: [u8; 256] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 25, 26,
+    27, 28, 29, 30, 31, 32, 33, 34, 36, 37, 38, 39, 40, 41, 43, 44, 45, 46, 48, 49, 50, 51, 53, 54,
+    55, 57, 58, 59, 61, 62, 63, 64, 65, 66, 67, 67, 68, 69, 70, 71, 73, 74, 76, 78, 80, 81, 83, 85,
+    87, 89, 91, 93, 96, 96, 97, 98, 98, 99, 100, 100, 101, 102, 102, 103, 104, 105, 105, 106, 107,
+    108, 109, 112, 113, 115, 116, 118, 120, 120, 121, 122, 122, 123, 124, 124, 125, 125, 125, 126,
+    127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 128, 129, 129, 129, 129,
+    129, 129, 129, 129, 129, 129, 129, 129, 129, 129, 130, 131, 131, 131, 132, 132, 133, 134, 134,
+    135, 136, 136, 138, 140, 141, 143, 144, 147, 148, 149, 150, 151, 151, 152, 153, 154, 154, 155,
+    156, 156, 157, 158, 158, 159, 160, 160, 163, 165, 167, 169, 171, 173, 175, 176, 178, 180, 182,
+    183, 185, 186, 187, 188, 189, 189, 190, 191, 192, 193, 194, 195, 197, 198, 199, 201, 202, 203,
+    205, 206, 207, 208, 210, 211, 212, 213, 215, 216, 217, 218, 219, 220, 222, 223, 224, 225, 226,
+    227, 228, 229, 230, 231, 233, 234, 235, 236, 237, 238, 239, 240, 241, 242, 243, 244, 245, 246,
+    247, 248, 249, 250, 251, 252, 253, 254, 255,
+];
+
+const COSH8: [u8; 256] = [
+    64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 65, 65, 65, 65, 65, 65, 65, 65, 66, 66, 66, 66,
+    66, 66, 67, 67, 67, 67, 68, 68, 68, 68, 69, 69, 69, 69, 70, 70, 70, 71, 71, 71, 72, 72, 73, 73,
+    73, 74, 74, 75, 75, 76, 76, 77, 77, 78, 78, 79, 79, 80, 80, 81, 81, 83, 84, 85, 86, 88, 89, 91,
+    92, 94, 96, 96, 97, 97, 98, 98, 99, 99, 100, 101, 101, 102, 102, 103, 104, 104, 105, 106, 107,
+    108, 108, 109, 110, 112, 114, 115, 116, 118, 120, 120, 121, 122, 122, 123, 124, 124, 125, 125,
+    125, 126, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 128, 127, 127,
+    127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 127, 126, 125, 125, 125, 124, 124, 123,
+    122, 122, 121, 120, 120, 118, 116, 115, 114, 112, 110, 109, 108, 108, 107, 106, 105, 104, 104,
+    103, 102, 102, 101, 101, 100, 99, 99, 98, 98, 97, 97, 96, 96, 94, 92, 91, 89, 88, 86, 85, 84,
+    83, 81, 81, 80, 80, 79, 79, 78, 78, 77, 77, 76, 76, 75, 75, 74, 74, 73, 73, 73, 72, 72, 71, 71,
+    71, 70, 70, 70, 69, 69, 69, 69, 68, 68, 68, 68, 67, 67, 67, 67, 66, 66, 66, 66, 66, 66, 65, 65,
+    65, 65, 65, 65, 65, 65, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
+];
+
+const TANH8: [u8; 256] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 18, 19, 20, 21, 22, 23, 24,
+    25, 26, 26, 27, 28, 29, 30, 30, 31, 32, 33, 33, 34, 35, 35, 36, 37, 38, 38, 39, 39, 40, 41, 41,
+    42, 42, 43, 43, 44, 45, 45, 46, 46, 47, 47, 47, 48, 48, 49, 50, 50, 51, 52, 52, 53, 54, 54, 55,
+    55, 56, 56, 57, 57, 58, 58, 58, 59, 59, 59, 60, 60, 60, 60, 60, 61, 61, 61, 61, 61, 62, 62, 62,
+    63, 63, 63, 63, 63, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
+    64, 64, 64, 64, 64, 64, 128, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
+    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 193, 193, 193, 193, 193, 194, 194,
+    194, 195, 195, 195, 195, 195, 196, 196, 196, 196, 196, 197, 197, 197, 198, 198, 198, 199, 199,
+    200, 200, 201, 201, 202, 202, 203, 204, 204, 205, 206, 206, 207, 208, 208, 209, 209, 209, 210,
+    210, 211, 211, 212, 213, 213, 214, 214, 215, 215, 216, 217, 217, 218, 218, 219, 220, 221, 221,
+    222, 223, 223, 224, 225, 226, 226, 227, 228, 229, 230, 230, 231, 232, 233, 234, 235, 236, 237,
+    238, 238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255,
+];
+
+const ASINH8: [u8; 256] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 23, 24,
+    25, 26, 27, 28, 29, 30, 31, 32, 33, 33, 34, 35, 36, 37, 38, 39, 39, 40, 41, 42, 43, 44, 44, 45,
+    46, 47, 48, 48, 49, 50, 51, 51, 52, 53, 54, 54, 55, 56, 56, 58, 59, 61, 62, 63, 64, 65, 66, 66,
+    67, 67, 68, 69, 69, 70, 70, 71, 71, 72, 72, 73, 73, 74, 74, 75, 75, 76, 76, 77, 77, 78, 78, 80,
+    82, 83, 85, 86, 88, 89, 90, 91, 93, 94, 95, 96, 96, 97, 97, 98, 98, 99, 100, 101, 101, 102,
+    102, 104, 105, 107, 108, 111, 112, 114, 128, 142, 144, 145, 148, 149, 151, 152, 154, 154, 155,
+    155, 156, 157, 158, 158, 159, 159, 160, 160, 161, 162, 163, 165, 166, 167, 168, 170, 171, 173,
+    174, 176, 178, 178, 179, 179, 180, 180, 181, 181, 182, 182, 183, 183, 184, 184, 185, 185, 186,
+    186, 187, 187, 188, 189, 189, 190, 190, 191, 192, 193, 194, 195, 197, 198, 200, 200, 201, 202,
+    202, 203, 204, 205, 205, 206, 207, 208, 208, 209, 210, 211, 212, 212, 213, 214, 215, 216, 217,
+    217, 218, 219, 220, 221, 222, 223, 223, 224, 225, 226, 227, 228, 229, 230, 231, 232, 233, 233,
+    234, 235, 236, 237, 238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252,
+    253, 254, 255,
+];
+
+const ACOSH8: [u8; 256] = [
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 0, 16, 23, 28, 32, 35, 39, 42, 44, 47, 49, 52, 54, 56, 58,
+    60, 62, 63, 65, 65, 66, 67, 68, 68, 69, 70, 70, 71, 72, 72, 73, 74, 74, 76, 78, 80, 82, 84, 85,
+    87, 88, 90, 91, 92, 94, 95, 96, 96, 97, 97, 98, 99, 100, 100, 101, 102, 102, 104, 105, 107,
+    108, 111, 112, 114, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+];
+
+const ATANH8: [u8; 256] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 20, 21, 22, 23, 24, 25, 26,
+    28, 29, 30, 31, 33, 34, 35, 37, 38, 39, 41, 42, 44, 45, 47, 49, 50, 52, 54, 56, 58, 60, 62, 64,
+    66, 67, 68, 70, 71, 73, 75, 78, 80, 83, 87, 92, 97, 99, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 157, 159, 164, 169, 173, 176, 178, 181, 183, 185, 186, 188,
+    189, 190, 192, 194, 196, 198, 200, 202, 204, 206, 207, 209, 211, 212, 214, 215, 217, 218, 219,
+    221, 222, 223, 225, 226, 227, 228, 230, 231, 232, 233, 234, 235, 236, 238, 239, 240, 241, 242,
+    243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255,
+];
+
+impl P8E0 {
+    /// Hyperbolic sine.
+    #[inline]
+    pub fn sinh(self) -> Self {
+        Self::from_bits(SINH8[self.to_bits() as usize])
+    }
+
+    /// Hyperbolic cosine.
+    #[inline]
+    pub fn cosh(self) -> Self {
+        Self::from_bits(COSH8[self.to_bits() as usize])
+    }
+
+    /// Hyperbolic tangent.
+    #[inline]
+    pub fn tanh(self) -> Self {
+        Self::from_bits(TANH8[self.to_bits() as usize])
+    }
+
+    /// Inverse hyperbolic sine.
+    #[inline]
+    pub fn asinh(self) -> Self {
+        Self::from_bits(ASINH8[self.to_bits() as usize])
+    }
+
+    /// Inverse hyperbolic cosine.
+    ///
+    /// Returns NaR for numbers less than one.
+    #[inline]
+    pub fn acosh(self) -> Self {
+        Self::from_bits(ACOSH8[self.to_bits() as usize])
+    }
+
+    /// Inverse hyperbolic tangent.
+    ///
+    /// Returns NaR for numbers outside the open interval (-1, 1).
+    #[inline]
+    pub fn atanh(self) -> Self {
+        Self::from_bits(ATANH8[self.to_bits() as usize])
+    }
+}
+
+#[test]
+fn test_sinh() {
+    super::test_reference(P8E0::sinh, |r, x, _| Some(r.sinh(x)));
+}
+
+#[test]
+fn test_cosh() {
+    super::test_reference(P8E0::cosh, |r, x, _| Some(r.cosh(x)));
+}
+
+#[test]
+fn test_tanh() {
+    super::test_reference(P8E0::tanh, |r, x, _| Some(r.tanh(x)));
+}
+
+#[test]
+fn test_asinh() {
+    super::test_reference(P8E0::asinh, |r, x, _| Some(r.asinh(x)));
+}
+
+#[test]
+fn test_acosh() {
+    super::test_reference(P8E0::acosh, |r, x, _| r.acosh(x));
+}
+
+#[test]
+fn test_atanh() {
+    super::test_reference(P8E0::atanh, |r, x, _| r.atanh(x));
+}
@@ -0,0 +1,34 @@
+use super::P8E0;
+
+impl P8E0 {
+    /// Length of the hypotenuse of a right-angle triangle with legs `self`
+    /// and `other`, without intermediate rounding.
+    pub fn hypot(self, other: Self) -> Self {
+        // The sum of squares of two P8E0 numbers is exact in `f64`, and its
+        // root is either exact or irrational, so the correctly rounded double
+        // root rounds to the correct posit.
+        let (f_a, f_b) = (f64::from(self), f64::from(other));
+        Self::from(libm::sqrt(f_a * f_a + f_b * f_b))
+    }
+}
+
+#[test]
+fn test_hypot() {
+    use crate::dd::reference::{self, Reference};
+    let r = Reference::new();
+    let fixed = |p: P8E0| reference::fixed(p.to_bits() as u64, 8, 0);
+    for i in -0x80_i32..0x80 {
+        for j in -0x80_i32..0x80 {
+            let p_a = P8E0::new(i as i8);
+            let p_b = P8E0::new(j as i8);
+            let expected = if p_a.is_nar() || p_b.is_nar() {
+                P8E0::NAR
+            } else {
+                // exact roots may lie on a rounding boundary
+                let exact = r.hypot(&fixed(p_a), &fixed(p_b));
+                P8E0::from_bits(reference::round_even(&exact, 8, 0) as u8)
+            };
+            assert_eq!(p_a.hypot(p_b), expected, "{:?}.hypot({:?})", p_a, p_b);
+        }
+    }
+}
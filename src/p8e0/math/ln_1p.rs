@@ -0,0 +1,40 @@
+use super::P8E0;
+
+const LN_1P_8: [u8; 256] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 8, 9, 10, 11, 12, 13, 13, 14, 15, 16, 17, 17, 18, 19, 20, 20, 21,
+    22, 23, 23, 24, 25, 25, 26, 27, 27, 28, 29, 29, 30, 30, 31, 32, 32, 33, 33, 34, 35, 35, 36, 36,
+    37, 38, 38, 39, 39, 40, 40, 41, 41, 42, 42, 43, 43, 44, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53,
+    54, 55, 55, 56, 57, 58, 59, 59, 60, 61, 62, 63, 63, 64, 64, 65, 65, 65, 66, 66, 66, 67, 67, 68,
+    70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 87, 89, 92, 94, 96, 97, 97, 98, 99,
+    101, 102, 103, 106, 108, 112, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 144, 148, 152, 154, 156,
+    157, 158, 159, 161, 165, 168, 170, 173, 175, 178, 180, 182, 183, 185, 187, 188, 190, 191, 193,
+    196, 198, 201, 203, 205, 208, 210, 212, 214, 216, 217, 219, 221, 223, 224, 226, 228, 229, 231,
+    232, 233, 235, 236, 238, 239, 240, 241, 243, 244, 245, 246, 247, 249, 250, 251, 252, 253, 254,
+    255,
+];
+
+impl P8E0 {
+    /// Returns `ln(1 + self)`.
+    ///
+    /// Returns NaR for numbers less than or equal to -1 and for NaR.
+    #[inline]
+    pub fn ln_1p(self) -> Self {
+        Self::from_bits(LN_1P_8[self.to_bits() as usize])
+    }
+}
+
+#[test]
+fn test_ln_1p() {
+    super::test_reference(P8E0::ln_1p, |r, x, p| {
+        if p <= -P8E0::ONE {
+            None
+        } else {
+            Some(r.ln_1p(x))
+        }
+    });
+    assert_eq!(P8E0::MIN_POSITIVE.ln_1p(), P8E0::MIN_POSITIVE);
+    assert_eq!((-P8E0::MIN_POSITIVE).ln_1p(), -P8E0::MIN_POSITIVE);
+}
@@ -0,0 +1,74 @@
+use super::P8E0;
+
+const LOG10_8: [u8; 256] = [
+    128, 166, 176, 181, 185, 189, 191, 194, 198, 201, 204, 207, 209, 212, 214, 216, 217, 219, 221,
+    222, 224, 225, 226, 228, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 238, 239, 240, 241,
+    242, 242, 243, 244, 244, 245, 246, 246, 247, 247, 248, 249, 249, 250, 250, 251, 251, 252, 252,
+    253, 253, 254, 254, 255, 255, 255, 0, 1, 2, 2, 3, 4, 5, 5, 6, 7, 8, 8, 9, 9, 10, 11, 11, 12,
+    12, 13, 13, 14, 15, 15, 16, 16, 17, 17, 17, 18, 18, 19, 19, 21, 23, 24, 25, 27, 28, 29, 31, 32,
+    33, 34, 35, 36, 37, 38, 39, 42, 45, 47, 50, 52, 54, 56, 58, 64, 67, 69, 71, 76, 80, 90, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+];
+
+impl P8E0 {
+    /// Base-10 logarithm.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    #[inline]
+    pub fn log10(self) -> Self {
+        Self::from_bits(LOG10_8[self.to_bits() as usize])
+    }
+
+    /// Logarithm of the number with respect to an arbitrary base, correctly
+    /// rounded.
+    ///
+    /// Returns NaR if either argument is zero, negative or NaR, or if the
+    /// base is one.
+    pub fn log(self, base: Self) -> Self {
+        Self::from_bits(crate::dd::log(f64::from(self), f64::from(base), 8, 0) as u8)
+    }
+}
+
+#[test]
+fn test_log10() {
+    super::test_reference(P8E0::log10, |r, x, p| {
+        if p <= P8E0::ZERO {
+            None
+        } else {
+            Some(r.log10(x))
+        }
+    });
+    assert_eq!(P8E0::from(10).log10(), P8E0::ONE);
+}
+
+#[test]
+fn test_log() {
+    use crate::dd::reference::{self, Reference};
+    let r = Reference::new();
+    let fixed = |p: P8E0| reference::fixed(p.to_bits() as u64, 8, 0);
+    for i in -0x80_i32..0x80 {
+        for j in -0x80_i32..0x80 {
+            let p_a = P8E0::new(i as i8);
+            let p_b = P8E0::new(j as i8);
+            let exact = if p_a <= P8E0::ZERO || p_b <= P8E0::ZERO {
+                None
+            } else {
+                r.log(&fixed(p_a), &fixed(p_b))
+            };
+            let expected = match exact {
+                Some(value) => P8E0::from_bits(reference::round(&value, 8, 0).unwrap() as u8),
+                None => P8E0::NAR,
+            };
+            assert_eq!(p_a.log(p_b), expected, "{:?}.log({:?})", p_a, p_b);
+        }
+    }
+    let two = P8E0::from(2);
+    assert_eq!(P8E0::from(8).log(two), P8E0::from(3));
+    assert_eq!(P8E0::from(8).log(P8E0::from(4)), P8E0::from(1.5));
+}
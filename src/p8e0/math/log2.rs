@@ -0,0 +1,38 @@
+use super::P8E0;
+
+const LOG2_8: [u8; 256] = [
+    128, 140, 142, 143, 144, 147, 149, 150, 152, 153, 155, 156, 157, 158, 158, 159, 160, 163, 165,
+    168, 170, 173, 175, 177, 179, 181, 182, 184, 186, 187, 189, 191, 192, 195, 198, 200, 203, 205,
+    208, 210, 213, 215, 217, 219, 221, 223, 226, 227, 229, 231, 233, 235, 237, 239, 240, 242, 244,
+    245, 247, 248, 250, 252, 253, 255, 0, 3, 6, 8, 11, 13, 16, 18, 21, 23, 25, 27, 29, 31, 34, 35,
+    37, 39, 41, 43, 45, 47, 48, 50, 52, 53, 55, 56, 58, 60, 61, 63, 64, 67, 69, 72, 74, 77, 79, 81,
+    83, 85, 86, 88, 90, 91, 93, 95, 96, 97, 99, 100, 101, 102, 102, 103, 104, 107, 109, 110, 112,
+    113, 114, 116, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+];
+
+impl P8E0 {
+    /// Base-2 logarithm.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    #[inline]
+    pub fn log2(self) -> Self {
+        Self::from_bits(LOG2_8[self.to_bits() as usize])
+    }
+}
+
+#[test]
+fn test_log2() {
+    super::test_reference(P8E0::log2, |r, x, p| {
+        if p <= P8E0::ZERO {
+            None
+        } else {
+            Some(r.log2(x))
+        }
+    });
+}
@@ -0,0 +1,44 @@
+use super::P8E0;
+
+impl P8E0 {
+    /// Raises a number to an integer power.
+    ///
+    /// The result is correctly rounded unless the exact power lies within about
+    /// `n^2 2^-124` (relative) of a rounding boundary, where it may be one ulp
+    /// smaller in magnitude. `x.powi(0)` is 1 for every real `x`, NaR is
+    /// propagated and zero raised to a negative power is NaR.
+    pub fn powi(self, n: i32) -> Self {
+        let bits =
+            crate::xfloat::powi(self.to_bits() as u64, n, Self::SIZE as u32, Self::ES as u32);
+        Self::from_bits(bits as u8)
+    }
+}
+
+#[test]
+fn test_powi() {
+    for i in -0x80_i32..0x80 {
+        let p_a = P8E0::new(i as i8);
+        for n in -70..70 {
+            let expected = crate::xfloat::powi_exact(p_a.to_bits() as u64, n, 8, 0);
+            assert_eq!(
+                p_a.powi(n),
+                P8E0::from_bits(expected as u8),
+                "{:?}^{}",
+                p_a,
+                n
+            );
+        }
+    }
+}
+
+#[test]
+fn test_powi_special() {
+    let two = P8E0::from(2);
+    assert_eq!(P8E0::NAR.powi(0), P8E0::NAR);
+    assert_eq!(P8E0::ZERO.powi(0), P8E0::ONE);
+    assert_eq!(P8E0::ZERO.powi(-1), P8E0::NAR);
+    assert_eq!((-two).powi(3), P8E0::from(-8));
+    assert_eq!(two.powi(-2), P8E0::from(0.25));
+    assert_eq!(two.powi(7), P8E0::MAX);
+    assert_eq!(two.powi(-7), P8E0::MIN_POSITIVE);
+}
@@ -0,0 +1,22 @@
+use super::P8E0;
+
+crate::impl_special!(P8E0);
+
+#[test]
+fn test_special() {
+    use crate::dd::reference::Reference;
+    use crate::p64e2::special::check_reference;
+    use crate::P64E2;
+    let r = Reference::new();
+    let round = |bits| P8E0::from(P64E2::from_bits(bits)).to_bits() as u64;
+    for i in i8::MIN..=i8::MAX {
+        let p = P8E0::new(i);
+        let results = [
+            p.erf().to_bits() as u64,
+            p.erfc().to_bits() as u64,
+            p.tgamma().to_bits() as u64,
+            p.lgamma().to_bits() as u64,
+        ];
+        check_reference(&r, p.to_bits() as u64, results, (8, 0), round);
+    }
+}
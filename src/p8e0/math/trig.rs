@@ -0,0 +1,220 @@
+//! The unary functions are looked up in tables of the correctly rounded
+//! results for all 256 inputs. The binary `atan2` rounds the libm result in
+//! double precision instead: P8E0 arguments are exact in `f64`, and the
+//! 53-bit result turns out close enough to round every one of the 65536
+//! argument pairs correctly, as the exhaustive test against the fixed point
+//! reference shows.
+
+use super::P8E0;
+
+const SIN8: [u8; 256] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 23, 24,
+    25, 26, 27, 28, 29, 30, 31, 32, 32, 33, 34, 35, 36, 37, 37, 38, 39, 40, 41, 41, 42, 43, 44, 44,
+    45, 46, 46, 47, 48, 48, 49, 50, 50, 51, 52, 52, 53, 53, 54, 55, 56, 57, 58, 59, 59, 60, 61, 61,
+    62, 62, 63, 63, 63, 64, 64, 64, 64, 64, 64, 64, 64, 63, 63, 63, 62, 62, 61, 60, 60, 59, 58, 54,
+    50, 44, 38, 32, 24, 17, 9, 1, 249, 241, 234, 226, 219, 213, 208, 193, 195, 211, 238, 14, 42,
+    60, 63, 221, 222, 63, 238, 198, 35, 59, 128, 197, 221, 58, 18, 193, 34, 35, 193, 196, 214, 242,
+    18, 45, 61, 63, 48, 43, 37, 30, 22, 15, 7, 255, 247, 239, 232, 224, 218, 212, 206, 202, 198,
+    197, 196, 196, 195, 194, 194, 193, 193, 193, 192, 192, 192, 192, 192, 192, 192, 192, 193, 193,
+    193, 194, 194, 195, 195, 196, 197, 197, 198, 199, 200, 201, 202, 203, 203, 204, 204, 205, 206,
+    206, 207, 208, 208, 209, 210, 210, 211, 212, 212, 213, 214, 215, 215, 216, 217, 218, 219, 219,
+    220, 221, 222, 223, 224, 224, 225, 226, 227, 228, 229, 230, 231, 232, 233, 233, 234, 235, 236,
+    237, 238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255,
+];
+
+const COS8: [u8; 256] = [
+    64, 64, 64, 64, 64, 64, 64, 64, 64, 63, 63, 63, 63, 63, 62, 62, 62, 62, 61, 61, 61, 61, 60, 60,
+    60, 59, 59, 58, 58, 58, 57, 57, 56, 56, 55, 55, 54, 54, 53, 52, 52, 51, 51, 50, 49, 49, 48, 48,
+    47, 46, 45, 45, 44, 43, 43, 42, 41, 40, 39, 39, 38, 37, 36, 35, 35, 33, 31, 29, 28, 26, 24, 22,
+    20, 18, 16, 14, 12, 10, 9, 7, 5, 3, 1, 255, 253, 251, 249, 247, 245, 243, 241, 239, 237, 235,
+    233, 231, 229, 222, 216, 210, 205, 200, 197, 194, 193, 192, 192, 194, 196, 199, 203, 208, 214,
+    243, 18, 45, 61, 63, 48, 22, 247, 202, 54, 9, 195, 27, 53, 25, 128, 25, 53, 27, 195, 9, 54,
+    202, 247, 22, 48, 63, 61, 45, 18, 243, 214, 208, 203, 199, 196, 194, 192, 192, 193, 194, 197,
+    200, 205, 210, 216, 222, 229, 231, 233, 235, 237, 239, 241, 243, 245, 247, 249, 251, 253, 255,
+    1, 3, 5, 7, 9, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 29, 31, 33, 35, 35, 36, 37, 38, 39, 39,
+    40, 41, 42, 43, 43, 44, 45, 45, 46, 47, 48, 48, 49, 49, 50, 51, 51, 52, 52, 53, 54, 54, 55, 55,
+    56, 56, 57, 57, 58, 58, 58, 59, 59, 60, 60, 60, 61, 61, 61, 61, 62, 62, 62, 62, 63, 63, 63, 63,
+    63, 64, 64, 64, 64, 64, 64, 64, 64,
+];
+
+const TAN8: [u8; 256] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 20, 21, 22, 23, 24, 25, 26,
+    28, 29, 30, 31, 32, 34, 35, 36, 38, 39, 40, 42, 43, 45, 46, 48, 49, 51, 53, 54, 56, 58, 60, 62,
+    63, 65, 66, 67, 68, 69, 70, 72, 73, 74, 76, 77, 79, 80, 82, 85, 89, 94, 97, 98, 100, 102, 104,
+    107, 110, 113, 114, 116, 119, 121, 123, 125, 127, 130, 132, 134, 136, 139, 141, 143, 144, 147,
+    151, 153, 155, 157, 159, 172, 184, 194, 208, 220, 230, 239, 247, 255, 7, 15, 24, 34, 45, 58,
+    69, 113, 149, 192, 237, 14, 56, 102, 138, 41, 215, 118, 19, 159, 42, 99, 128, 157, 214, 97,
+    237, 138, 41, 215, 118, 154, 200, 242, 19, 64, 107, 143, 187, 198, 211, 222, 232, 241, 249, 1,
+    9, 17, 26, 36, 48, 62, 72, 84, 97, 99, 101, 103, 105, 109, 112, 113, 115, 117, 120, 122, 124,
+    126, 129, 131, 133, 135, 137, 140, 142, 143, 146, 149, 152, 154, 156, 158, 159, 162, 167, 171,
+    174, 176, 177, 179, 180, 182, 183, 184, 186, 187, 188, 189, 190, 191, 193, 194, 196, 198, 200,
+    202, 203, 205, 207, 208, 210, 211, 213, 214, 216, 217, 218, 220, 221, 222, 224, 225, 226, 227,
+    228, 230, 231, 232, 233, 234, 235, 236, 238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248,
+    249, 250, 251, 252, 253, 254, 255,
+];
+
+const ASIN8: [u8; 256] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 24, 25, 26,
+    27, 28, 29, 30, 31, 32, 34, 35, 36, 37, 38, 39, 41, 42, 43, 44, 46, 47, 49, 50, 51, 53, 54, 56,
+    57, 59, 61, 62, 64, 65, 66, 67, 68, 70, 71, 72, 74, 77, 82, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 174, 179, 182, 184, 185, 186, 188, 189, 190, 191, 192, 194, 195,
+    197, 199, 200, 202, 203, 205, 206, 207, 209, 210, 212, 213, 214, 215, 217, 218, 219, 220, 221,
+    222, 224, 225, 226, 227, 228, 229, 230, 231, 232, 234, 235, 236, 237, 238, 239, 240, 241, 242,
+    243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255,
+];
+
+const ACOS8: [u8; 256] = [
+    82, 82, 81, 81, 80, 80, 79, 79, 78, 78, 77, 77, 76, 76, 75, 75, 74, 74, 73, 73, 72, 72, 71, 71,
+    70, 69, 69, 68, 68, 67, 67, 66, 66, 65, 64, 63, 62, 61, 60, 59, 57, 56, 55, 53, 52, 51, 49, 48,
+    46, 45, 43, 42, 40, 38, 36, 34, 32, 30, 28, 25, 23, 20, 16, 11, 0, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 105, 104, 103, 103, 102, 102, 102, 101, 101, 101, 101,
+    100, 100, 100, 100, 100, 99, 99, 99, 99, 99, 98, 98, 98, 98, 98, 98, 97, 97, 97, 97, 97, 97,
+    97, 96, 96, 96, 96, 96, 95, 95, 94, 93, 93, 92, 92, 91, 91, 90, 90, 89, 89, 88, 88, 87, 87, 86,
+    86, 85, 85, 84, 84, 83, 83,
+];
+
+const ATAN8: [u8; 256] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 18, 19, 20, 21, 22, 23, 24,
+    25, 26, 26, 27, 28, 29, 30, 30, 31, 32, 33, 34, 34, 35, 36, 36, 37, 38, 39, 39, 40, 41, 41, 42,
+    42, 43, 44, 44, 45, 45, 46, 47, 47, 48, 48, 49, 49, 50, 50, 51, 52, 53, 54, 55, 56, 57, 57, 58,
+    59, 60, 60, 61, 62, 62, 63, 64, 64, 64, 65, 65, 65, 65, 66, 66, 66, 66, 67, 67, 67, 67, 67, 68,
+    69, 70, 70, 71, 71, 72, 72, 72, 73, 73, 73, 74, 74, 74, 74, 75, 76, 77, 77, 77, 78, 78, 78, 79,
+    80, 80, 80, 81, 81, 82, 128, 174, 175, 175, 176, 176, 176, 177, 178, 178, 178, 179, 179, 179,
+    180, 181, 182, 182, 182, 182, 183, 183, 183, 184, 184, 184, 185, 185, 186, 186, 187, 188, 189,
+    189, 189, 189, 189, 190, 190, 190, 190, 191, 191, 191, 191, 192, 192, 192, 193, 194, 194, 195,
+    196, 196, 197, 198, 199, 199, 200, 201, 202, 203, 204, 205, 206, 206, 207, 207, 208, 208, 209,
+    209, 210, 211, 211, 212, 212, 213, 214, 214, 215, 215, 216, 217, 217, 218, 219, 220, 220, 221,
+    222, 222, 223, 224, 225, 226, 226, 227, 228, 229, 230, 230, 231, 232, 233, 234, 235, 236, 237,
+    238, 238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255,
+];
+
+impl P8E0 {
+    /// Sine of the number in radians.
+    #[inline]
+    pub fn sin(self) -> Self {
+        Self::from_bits(SIN8[self.to_bits() as usize])
+    }
+
+    /// Cosine of the number in radians.
+    #[inline]
+    pub fn cos(self) -> Self {
+        Self::from_bits(COS8[self.to_bits() as usize])
+    }
+
+    /// Tangent of the number in radians.
+    #[inline]
+    pub fn tan(self) -> Self {
+        Self::from_bits(TAN8[self.to_bits() as usize])
+    }
+
+    /// Arcsine in radians, in the range [-pi/2, pi/2].
+    ///
+    /// Returns NaR if the number is outside the range [-1, 1].
+    #[inline]
+    pub fn asin(self) -> Self {
+        Self::from_bits(ASIN8[self.to_bits() as usize])
+    }
+
+    /// Arccosine in radians, in the range [0, pi].
+    ///
+    /// Returns NaR if the number is outside the range [-1, 1].
+    #[inline]
+    pub fn acos(self) -> Self {
+        Self::from_bits(ACOS8[self.to_bits() as usize])
+    }
+
+    /// Arctangent in radians, in the range [-pi/2, pi/2].
+    #[inline]
+    pub fn atan(self) -> Self {
+        Self::from_bits(ATAN8[self.to_bits() as usize])
+    }
+
+    /// Four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians.
+    ///
+    /// Returns NaR if both numbers are zero.
+    pub fn atan2(self, other: Self) -> Self {
+        if self.is_zero() && other.is_zero() {
+            Self::NAR
+        } else {
+            // a single rounding of the double, see the module documentation
+            Self::from(libm::atan2(f64::from(self), f64::from(other)))
+        }
+    }
+}
+
+#[test]
+fn test_sin() {
+    super::test_reference(P8E0::sin, |r, x, _| Some(r.sin(x)));
+}
+
+#[test]
+fn test_cos() {
+    super::test_reference(P8E0::cos, |r, x, _| Some(r.cos(x)));
+}
+
+#[test]
+fn test_tan() {
+    super::test_reference(P8E0::tan, |r, x, _| Some(r.tan(x)));
+}
+
+#[test]
+fn test_asin() {
+    super::test_reference(P8E0::asin, |r, x, p| {
+        if p.abs() > P8E0::ONE {
+            None
+        } else {
+            Some(r.asin(x))
+        }
+    });
+}
+
+#[test]
+fn test_acos() {
+    super::test_reference(P8E0::acos, |r, x, p| {
+        if p.abs() > P8E0::ONE {
+            None
+        } else {
+            Some(r.acos(x))
+        }
+    });
+}
+
+#[test]
+fn test_atan() {
+    super::test_reference(P8E0::atan, |r, x, _| Some(r.atan(x)));
+}
+
+#[test]
+fn test_atan2() {
+    use crate::dd::reference::{self, Reference};
+    let r = Reference::new();
+    let fixed = |p: P8E0| reference::fixed(p.to_bits() as u64, 8, 0);
+    for i in -0x80_i32..0x80 {
+        for j in -0x80_i32..0x80 {
+            let p_a = P8E0::new(i as i8);
+            let p_b = P8E0::new(j as i8);
+            let exact = if p_a.is_nar() || p_b.is_nar() {
+                None
+            } else {
+                r.atan2(&fixed(p_a), &fixed(p_b))
+            };
+            let expected = match exact {
+                Some(value) => P8E0::from_bits(reference::round(&value, 8, 0).unwrap() as u8),
+                None => P8E0::NAR,
+            };
+            assert_eq!(p_a.atan2(p_b), expected, "{:?}.atan2({:?})", p_a, p_b);
+        }
+    }
+}
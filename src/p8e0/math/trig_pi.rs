@@ -0,0 +1,178 @@
+//! The π-scaled trigonometric functions are looked up in tables of the
+//! correctly rounded results for all 256 inputs, checked exhaustively
+//! against a high precision reference below.
+
+use super::P8E0;
+
+crate::impl_trig_pi!(P8E0);
+
+const SIN_PI8: [u8; 256] = [
+    0, 3, 6, 9, 12, 16, 19, 22, 24, 27, 30, 33, 36, 38, 41, 43, 45, 47, 49, 51, 53, 55, 56, 58, 59,
+    60, 61, 62, 63, 63, 64, 64, 64, 64, 64, 63, 63, 62, 61, 60, 59, 58, 56, 55, 53, 51, 49, 47, 45,
+    43, 41, 38, 36, 33, 30, 27, 24, 22, 19, 16, 12, 9, 6, 3, 0, 250, 244, 237, 232, 226, 220, 215,
+    211, 207, 203, 200, 197, 195, 193, 192, 192, 192, 193, 195, 197, 200, 203, 207, 211, 215, 220,
+    226, 232, 237, 244, 250, 0, 24, 45, 59, 64, 59, 45, 24, 0, 232, 211, 197, 192, 197, 211, 232,
+    0, 64, 0, 192, 0, 64, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 192,
+    0, 64, 0, 192, 0, 24, 45, 59, 64, 59, 45, 24, 0, 232, 211, 197, 192, 197, 211, 232, 0, 6, 12,
+    19, 24, 30, 36, 41, 45, 49, 53, 56, 59, 61, 63, 64, 64, 64, 63, 61, 59, 56, 53, 49, 45, 41, 36,
+    30, 24, 19, 12, 6, 0, 253, 250, 247, 244, 240, 237, 234, 232, 229, 226, 223, 220, 218, 215,
+    213, 211, 209, 207, 205, 203, 201, 200, 198, 197, 196, 195, 194, 193, 193, 192, 192, 192, 192,
+    192, 193, 193, 194, 195, 196, 197, 198, 200, 201, 203, 205, 207, 209, 211, 213, 215, 218, 220,
+    223, 226, 229, 232, 234, 237, 240, 244, 247, 250, 253,
+];
+
+const COS_PI8: [u8; 256] = [
+    64, 64, 64, 63, 63, 62, 61, 60, 59, 58, 56, 55, 53, 51, 49, 47, 45, 43, 41, 38, 36, 33, 30, 27,
+    24, 22, 19, 16, 12, 9, 6, 3, 0, 253, 250, 247, 244, 240, 237, 234, 232, 229, 226, 223, 220,
+    218, 215, 213, 211, 209, 207, 205, 203, 201, 200, 198, 197, 196, 195, 194, 193, 193, 192, 192,
+    192, 192, 193, 195, 197, 200, 203, 207, 211, 215, 220, 226, 232, 237, 244, 250, 0, 6, 12, 19,
+    24, 30, 36, 41, 45, 49, 53, 56, 59, 61, 63, 64, 64, 59, 45, 24, 0, 232, 211, 197, 192, 197,
+    211, 232, 0, 24, 45, 59, 64, 0, 192, 0, 64, 0, 192, 0, 64, 64, 64, 64, 64, 64, 64, 64, 128, 64,
+    64, 64, 64, 64, 64, 64, 64, 0, 192, 0, 64, 0, 192, 0, 64, 59, 45, 24, 0, 232, 211, 197, 192,
+    197, 211, 232, 0, 24, 45, 59, 64, 64, 63, 61, 59, 56, 53, 49, 45, 41, 36, 30, 24, 19, 12, 6, 0,
+    250, 244, 237, 232, 226, 220, 215, 211, 207, 203, 200, 197, 195, 193, 192, 192, 192, 192, 193,
+    193, 194, 195, 196, 197, 198, 200, 201, 203, 205, 207, 209, 211, 213, 215, 218, 220, 223, 226,
+    229, 232, 234, 237, 240, 244, 247, 250, 253, 0, 3, 6, 9, 12, 16, 19, 22, 24, 27, 30, 33, 36,
+    38, 41, 43, 45, 47, 49, 51, 53, 55, 56, 58, 59, 60, 61, 62, 63, 63, 64, 64,
+];
+
+const TAN_PI8: [u8; 256] = [
+    0, 3, 6, 9, 13, 16, 19, 23, 27, 30, 34, 38, 43, 47, 53, 58, 64, 67, 71, 75, 80, 85, 92, 97, 99,
+    102, 106, 112, 114, 117, 121, 125, 128, 131, 135, 139, 142, 144, 150, 154, 157, 159, 164, 171,
+    176, 181, 185, 189, 192, 198, 203, 209, 213, 218, 222, 226, 229, 233, 237, 240, 243, 247, 250,
+    253, 0, 6, 13, 19, 27, 34, 43, 53, 64, 71, 80, 92, 99, 106, 114, 121, 128, 135, 142, 150, 157,
+    164, 176, 185, 192, 203, 213, 222, 229, 237, 243, 250, 0, 27, 64, 99, 128, 157, 192, 229, 0,
+    27, 64, 99, 128, 157, 192, 229, 0, 128, 0, 128, 0, 128, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0,
+    0, 0, 0, 0, 0, 0, 0, 128, 0, 128, 0, 128, 0, 128, 0, 27, 64, 99, 128, 157, 192, 229, 0, 27, 64,
+    99, 128, 157, 192, 229, 0, 6, 13, 19, 27, 34, 43, 53, 64, 71, 80, 92, 99, 106, 114, 121, 128,
+    135, 142, 150, 157, 164, 176, 185, 192, 203, 213, 222, 229, 237, 243, 250, 0, 3, 6, 9, 13, 16,
+    19, 23, 27, 30, 34, 38, 43, 47, 53, 58, 64, 67, 71, 75, 80, 85, 92, 97, 99, 102, 106, 112, 114,
+    117, 121, 125, 128, 131, 135, 139, 142, 144, 150, 154, 157, 159, 164, 171, 176, 181, 185, 189,
+    192, 198, 203, 209, 213, 218, 222, 226, 229, 233, 237, 240, 243, 247, 250, 253,
+];
+
+const ASIN_PI8: [u8; 256] = [
+    0, 1, 1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 6, 6, 6, 7, 7, 7, 8, 8, 9, 9, 9, 10, 10,
+    10, 11, 11, 11, 12, 12, 13, 13, 13, 14, 14, 15, 15, 15, 16, 16, 17, 17, 18, 18, 19, 19, 20, 20,
+    21, 22, 22, 23, 24, 25, 26, 27, 28, 32, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 224, 228, 229, 230, 231, 232, 233, 234, 234, 235, 236, 236, 237, 237, 238, 238, 239,
+    239, 240, 240, 241, 241, 241, 242, 242, 243, 243, 243, 244, 244, 245, 245, 245, 246, 246, 246,
+    247, 247, 247, 248, 248, 249, 249, 249, 250, 250, 250, 251, 251, 251, 252, 252, 252, 252, 253,
+    253, 253, 254, 254, 254, 255, 255, 255, 255,
+];
+
+const ACOS_PI8: [u8; 256] = [
+    32, 32, 31, 31, 31, 30, 30, 30, 29, 29, 29, 28, 28, 28, 28, 27, 27, 27, 26, 26, 26, 25, 25, 25,
+    24, 24, 23, 23, 23, 22, 22, 22, 21, 21, 21, 20, 20, 19, 19, 19, 18, 18, 17, 17, 17, 16, 16, 15,
+    15, 14, 14, 13, 13, 12, 12, 11, 10, 10, 9, 8, 7, 6, 5, 4, 0, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+    128, 128, 128, 128, 128, 128, 64, 60, 59, 58, 57, 56, 55, 54, 54, 53, 52, 52, 51, 51, 50, 50,
+    49, 49, 48, 48, 47, 47, 47, 46, 46, 45, 45, 45, 44, 44, 43, 43, 43, 42, 42, 42, 41, 41, 41, 40,
+    40, 39, 39, 39, 38, 38, 38, 37, 37, 37, 36, 36, 36, 36, 35, 35, 35, 34, 34, 34, 33, 33, 33, 32,
+];
+
+const ATAN_PI8: [u8; 256] = [
+    0, 1, 1, 1, 1, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 5, 5, 5, 6, 6, 6, 6, 7, 7, 7, 8, 8, 8, 8, 9, 9, 9,
+    9, 10, 10, 10, 10, 11, 11, 11, 11, 12, 12, 12, 12, 12, 13, 13, 13, 13, 14, 14, 14, 14, 14, 14,
+    15, 15, 15, 15, 15, 16, 16, 16, 16, 16, 17, 17, 17, 17, 18, 18, 18, 18, 19, 19, 19, 19, 20, 20,
+    20, 20, 20, 21, 21, 21, 21, 21, 21, 22, 22, 22, 22, 22, 22, 22, 23, 23, 23, 24, 24, 25, 25, 25,
+    25, 26, 26, 26, 26, 27, 27, 27, 27, 28, 28, 28, 29, 29, 29, 29, 29, 30, 30, 31, 31, 31, 31, 32,
+    128, 224, 225, 225, 225, 225, 226, 226, 227, 227, 227, 227, 227, 228, 228, 228, 229, 229, 229,
+    229, 230, 230, 230, 230, 231, 231, 231, 231, 232, 232, 233, 233, 233, 234, 234, 234, 234, 234,
+    234, 234, 235, 235, 235, 235, 235, 235, 236, 236, 236, 236, 236, 237, 237, 237, 237, 238, 238,
+    238, 238, 239, 239, 239, 239, 240, 240, 240, 240, 240, 241, 241, 241, 241, 241, 242, 242, 242,
+    242, 242, 242, 243, 243, 243, 243, 244, 244, 244, 244, 244, 245, 245, 245, 245, 246, 246, 246,
+    246, 247, 247, 247, 247, 248, 248, 248, 248, 249, 249, 249, 250, 250, 250, 250, 251, 251, 251,
+    252, 252, 252, 253, 253, 253, 253, 254, 254, 254, 255, 255, 255, 255,
+];
+
+impl P8E0 {
+    /// Returns `sin(π x)`.
+    #[inline]
+    pub fn sin_pi(self) -> Self {
+        Self::from_bits(SIN_PI8[self.to_bits() as usize])
+    }
+
+    /// Returns `cos(π x)`.
+    #[inline]
+    pub fn cos_pi(self) -> Self {
+        Self::from_bits(COS_PI8[self.to_bits() as usize])
+    }
+
+    /// Returns `tan(π x)`, NaR at the half-integers.
+    #[inline]
+    pub fn tan_pi(self) -> Self {
+        Self::from_bits(TAN_PI8[self.to_bits() as usize])
+    }
+
+    /// Returns `asin(x) / π`, NaR outside `[-1, 1]`.
+    #[inline]
+    pub fn asin_pi(self) -> Self {
+        Self::from_bits(ASIN_PI8[self.to_bits() as usize])
+    }
+
+    /// Returns `acos(x) / π`, NaR outside `[-1, 1]`.
+    #[inline]
+    pub fn acos_pi(self) -> Self {
+        Self::from_bits(ACOS_PI8[self.to_bits() as usize])
+    }
+
+    /// Returns `atan(x) / π`.
+    #[inline]
+    pub fn atan_pi(self) -> Self {
+        Self::from_bits(ATAN_PI8[self.to_bits() as usize])
+    }
+}
+
+#[test]
+fn test_sin_pi() {
+    super::test_reference(P8E0::sin_pi, |r, x, _| Some(r.sin_pi(x)));
+}
+
+#[test]
+fn test_cos_pi() {
+    super::test_reference(P8E0::cos_pi, |r, x, _| Some(r.cos_pi(x)));
+}
+
+#[test]
+fn test_tan_pi() {
+    super::test_reference(P8E0::tan_pi, |r, x, _| r.tan_pi(x));
+}
+
+#[test]
+fn test_asin_pi() {
+    super::test_reference(P8E0::asin_pi, |r, x, p| {
+        if p.abs() > P8E0::ONE {
+            None
+        } else {
+            Some(r.asin_pi(x))
+        }
+    });
+}
+
+#[test]
+fn test_acos_pi() {
+    super::test_reference(P8E0::acos_pi, |r, x, p| {
+        if p.abs() > P8E0::ONE {
+            None
+        } else {
+            Some(r.acos_pi(x))
+        }
+    });
+}
+
+#[test]
+fn test_atan_pi() {
+    super::test_reference(P8E0::atan_pi, |r, x, _| Some(r.atan_pi(x)));
+}
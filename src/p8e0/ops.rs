@@ -319,12 +319,7 @@ impl P8E0 {
     }
 }
 
-impl ops::Rem for P8E0 {
-    type Output = Self;
-    fn rem(self, other: Self) -> Self {
-        self - (self / other).trunc() * other
-    }
-}
+crate::impl_rem!(P8E0, 8, 0);
 
 #[cfg(test)]
 fn test_ops(fun: fn(P8E0, P8E0, f64, f64) -> (P8E0, f64)) {
@@ -359,3 +354,23 @@ fn mul() {
 fn div() {
     test_ops(|p_a, p_b, f_a, f_b| (p_a / p_b, f_a / f_b));
 }
+
+/// Compares the remainders with the exact ones rounded by the reference.
+#[test]
+fn rem() {
+    use crate::p64e2::reference::div_rem;
+    for i in 0..0x100_u32 {
+        for j in 0..0x100_u32 {
+            let p_a = P8E0::from_bits(i as u8);
+            let p_b = P8E0::from_bits(j as u8);
+            let [trunc, nearest, euclid] =
+                div_rem(p_a.to_bits() as u64, p_b.to_bits() as u64, 8, 0);
+            let p = |bits: u64| P8E0::from_bits(bits as u8);
+            assert_eq!(p_a % p_b, p(trunc.1), "{:?} {:?}", p_a, p_b);
+            assert_eq!(p_a.remainder(p_b), p(nearest.1));
+            assert_eq!(p_a.remquo(p_b), (p(nearest.1), nearest.2));
+            assert_eq!(p_a.div_euclid(p_b), p(euclid.0));
+            assert_eq!(p_a.rem_euclid(p_b), p(euclid.1));
+        }
+    }
+}
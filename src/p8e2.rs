@@ -0,0 +1,221 @@
+use crate::convert::{decode_posit, encode_posit};
+
+mod convert;
+mod math;
+mod ops;
+crate::impl_num_traits!(P8E2, 8, 2);
+#[cfg(feature = "approx")]
+crate::impl_ulps_eq!(P8E2, i8);
+#[cfg(feature = "approx")]
+use approx::AbsDiffEq;
+#[cfg(feature = "approx")]
+crate::impl_signed_abs_diff_eq!(P8E2, P8E2::ZERO);
+#[cfg(feature = "approx")]
+crate::impl_relative_eq!(P8E2, i8);
+
+#[cfg(feature = "alga")]
+crate::impl_lattice!(P8E2);
+#[cfg(feature = "alga")]
+crate::impl_real!(P8E2);
+#[cfg(feature = "alga")]
+crate::impl_complex!(P8E2);
+#[cfg(feature = "alga")]
+crate::impl_alga!(P8E2);
+#[cfg(feature = "alga")]
+use alga::general::{Additive, Multiplicative};
+
+/// Standard 8-bit posit (posit8, es = 2).
+#[cfg_attr(feature = "alga", derive(alga_derive::Alga))]
+#[cfg_attr(feature = "alga", alga_traits(Field(Additive, Multiplicative)))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct P8E2(i8);
+
+impl P8E2 {
+    pub const SIZE: usize = 8;
+    pub const ES: usize = 2;
+    pub const USEED: usize = 16;
+
+    /// Machine epsilon (1.25e-1).
+    pub const EPSILON: Self = Self::new(0x_28);
+
+    /// Smallest finite value (-16777216).
+    pub const MIN: Self = Self::new(-0x_7F);
+
+    /// Smallest positive normal value (5.960464477539063_e-8).
+    pub const MIN_POSITIVE: Self = Self::new(0x_1);
+
+    /// Largest finite value (16777216).
+    pub const MAX: Self = Self::new(0x_7F);
+
+    /// Not a Real (NaR).
+    pub const NAR: Self = Self::new(-0x_80);
+
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self::NAR;
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Self::NAR;
+
+    /// Zero.
+    pub const ZERO: Self = Self::new(0);
+
+    /// Identity.
+    pub const ONE: Self = Self::new(0x_40);
+
+    #[inline]
+    pub const fn new(i: i8) -> Self {
+        Self(i)
+    }
+    #[inline]
+    pub const fn from_bits(v: u8) -> Self {
+        Self(v as i8)
+    }
+    #[inline]
+    pub const fn to_bits(self) -> u8 {
+        self.0 as u8
+    }
+    #[inline]
+    pub fn abs(self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+    #[inline]
+    pub fn is_nar(self) -> bool {
+        self == Self::NAR
+    }
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.is_nar()
+    }
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.is_nar()
+    }
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        !self.is_nar()
+    }
+    #[inline]
+    pub fn is_normal(self) -> bool {
+        !self.is_nar()
+    }
+    #[inline]
+    pub fn classify(self) -> core::num::FpCategory {
+        use core::num::FpCategory::*;
+        match self {
+            Self::ZERO => Zero,
+            Self::NAR => Nan,
+            _ => Normal,
+        }
+    }
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self < Self::ZERO
+    }
+    #[inline]
+    pub fn copysign(self, other: Self) -> Self {
+        if ((self.to_bits() ^ other.to_bits()) & Self::SIGN_MASK) != 0 {
+            -self
+        } else {
+            self
+        }
+    }
+    #[inline]
+    pub fn signum(self) -> Self {
+        match self.0 {
+            n if n == Self::NAR.0 => Self::NAR,
+            n if n > 0 => Self::ONE,
+            0 => Self::ZERO,
+            _ => -Self::ONE,
+        }
+    }
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+    #[inline]
+    pub fn to_degrees(self) -> Self {
+        const PIS_IN_180: P8E2 = P8E2::new(0x_67);
+        self * PIS_IN_180
+    }
+    #[inline]
+    pub fn to_radians(self) -> Self {
+        const PI_180: P8E2 = P8E2::new(0x_18);
+        self * PI_180
+    }
+}
+
+impl P8E2 {
+    pub const SIGN_MASK: u8 = 0x_80;
+
+    /// Unpacks a nonzero, non-NaR posit into its sign, scale and fraction,
+    /// with the hidden bit of the fraction at bit 63.
+    #[inline]
+    pub(crate) fn unpack(self) -> (bool, i32, u64) {
+        decode_posit(self.to_bits() as u64, 8, 2)
+    }
+
+    /// Rounds `(-1)^sign * frac * 2^(scale - 127)`, with the hidden bit of
+    /// `frac` at bit 127, to the nearest posit.
+    #[inline]
+    pub(crate) fn encode(sign: bool, scale: i32, frac: u128, sticky: bool) -> Self {
+        Self::from_bits(encode_posit(sign, scale, frac, sticky, 8, 2) as u8)
+    }
+}
+
+impl core::str::FromStr for P8E2 {
+    type Err = crate::ParsePositError;
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_posit(src, 8, 2).map(|bits| Self::from_bits(bits as u8))
+    }
+}
+
+crate::impl_fmt!(P8E2, 8, 2);
+crate::impl_fields!(P8E2, 8, 2);
+
+use core::fmt;
+impl fmt::Debug for P8E2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            crate::fields::debug(f, "P8E2", self.to_bits() as u64, 8, 2)
+        } else {
+            write!(f, "P8E2({})", self.0)
+        }
+    }
+}
+
+impl crate::AssociatedQuire<Self> for P8E2 {
+    type Q = crate::Q8E2;
+}
+
+impl crate::polynom::poly::Poly<Self> for P8E2 {}
+impl crate::Polynom<Self> for P8E2 {}
+
+impl crate::polynom::poly::Poly<[Self; 1]> for P8E2 {}
+impl crate::Polynom<[Self; 1]> for P8E2 {}
+impl crate::polynom::poly::Poly<[Self; 2]> for P8E2 {}
+impl crate::Polynom<[Self; 2]> for P8E2 {}
+impl crate::polynom::poly::Poly<[Self; 3]> for P8E2 {}
+impl crate::Polynom<[Self; 3]> for P8E2 {}
+impl crate::polynom::poly::Poly<[Self; 4]> for P8E2 {}
+impl crate::Polynom<[Self; 4]> for P8E2 {}
+
+#[cfg(any(feature = "rand", test))]
+impl rand::distributions::Distribution<P8E2> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> P8E2 {
+        let s = rng.gen_range(-0x_7f_i8, 0x_7f);
+        P8E2::new(s)
+    }
+}
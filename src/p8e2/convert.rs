@@ -0,0 +1,96 @@
+use super::P8E2;
+use crate::{P16E1, P32E2, P8E0};
+
+crate::impl_convert!(P8E2);
+crate::impl_int_convert!(P8E2, 8, 2);
+crate::impl_xfloat_convert!(P8E2, 8, 2);
+crate::impl_xfloat_from_posit!(P8E2, 8, 2; P8E0, 8, 0, u8; P16E1, 16, 1, u16; P32E2, 32, 2, u32);
+
+#[test]
+fn convert_p8e2_f64() {
+    use crate::p64e2::reference::{round, value};
+    for i in 0..0x100_u32 {
+        let p_a = P8E2::from_bits(i as u8);
+        let f_a = f64::from(p_a);
+        if p_a.is_nar() {
+            assert!(f_a.is_nan());
+            continue;
+        }
+        let (m, e) = value(i as u128, 8, 2);
+        assert_eq!(
+            f_a,
+            num_traits::ToPrimitive::to_f64(&m).unwrap() * 2_f64.powi(e)
+        );
+        assert_eq!(f32::from(p_a), f_a as f32);
+        assert_eq!(P8E2::from(f_a), p_a);
+        // the midpoints to the next posit and the numbers around them
+        if i < 0x7F {
+            let (m, e) = value(((i as u128) << 1) | 1, 9, 2);
+            let mid = num_traits::ToPrimitive::to_f64(&m).unwrap() * 2_f64.powi(e);
+            for f in &[mid, mid * (1. - 1e-9), mid * (1. + 1e-9)] {
+                let m = num_bigint::BigInt::from((f * 2_f64.powi(50 - e)) as i64);
+                assert_eq!(
+                    P8E2::from(*f).to_bits(),
+                    round(&m, &1.into(), e - 50, 8, 2) as u8
+                );
+            }
+        }
+    }
+    assert_eq!(P8E2::from(1e10), P8E2::MAX);
+    assert_eq!(P8E2::from(-1e-10), -P8E2::MIN_POSITIVE);
+    assert_eq!(P8E2::from(f64::INFINITY), P8E2::NAR);
+}
+
+#[test]
+fn convert_p8e2_int() {
+    use crate::PositConversionError;
+    use core::convert::TryFrom;
+    for i in 0..0x100_u32 {
+        let p_a = P8E2::from_bits(i as u8);
+        let f_a = f64::from(p_a);
+        if p_a.is_nar() {
+            assert_eq!(i32::try_from(p_a), Err(PositConversionError::NaR));
+            assert_eq!(p_a.to_int_floor::<u64>(), Err(PositConversionError::NaR));
+            continue;
+        }
+        let f = f_a.round();
+        // ties to even
+        let f = if (f - f_a).abs() == 0.5 {
+            2. * (f_a / 2.).round()
+        } else {
+            f
+        };
+        assert_eq!(i64::try_from(p_a), Ok(f as i64));
+        if f < 0. {
+            assert_eq!(u32::try_from(p_a), Err(PositConversionError::Negative));
+        } else {
+            assert_eq!(u32::try_from(p_a), Ok(f as u32));
+        }
+        assert_eq!(p_a.to_int_floor(), Ok(f_a.floor() as i64));
+        assert_eq!(p_a.to_int_ceil(), Ok(f_a.ceil() as i64));
+        assert_eq!(p_a.to_int_trunc(), Ok(f_a.trunc() as i64));
+        assert_eq!(P8E2::from(f_a as i64), P8E2::from(f_a.trunc()));
+    }
+    assert_eq!(i32::try_from(P8E2::MAX), Ok(1 << 24));
+    assert_eq!(
+        i16::try_from(P8E2::MAX),
+        Err(PositConversionError::Overflow)
+    );
+    assert_eq!(P8E2::from(i128::MAX), P8E2::MAX);
+}
+
+#[test]
+fn convert_p8e2_posits() {
+    for i in 0..0x100_u32 {
+        let p_a = P8E2::from_bits(i as u8);
+        let f_a = f64::from(p_a);
+        // the wider standard posits hold every P8E2
+        assert_eq!(f64::from(crate::P16E2::from(p_a)).to_bits(), f_a.to_bits());
+        assert_eq!(f64::from(P32E2::from(p_a)).to_bits(), f_a.to_bits());
+        assert_eq!(P8E2::from(crate::P64E2::from(p_a)), p_a);
+        assert_eq!(P16E1::from(p_a), P16E1::from(f_a));
+        assert_eq!(P8E0::from(p_a), P8E0::from(f_a));
+        let p_b = P8E0::from_bits(i as u8);
+        assert_eq!(P8E2::from(p_b), P8E2::from(f64::from(p_b)));
+    }
+}
@@ -0,0 +1,86 @@
+use super::P8E2;
+
+impl crate::MathConsts for P8E2 {
+    const E: Self = Self::new(0x_4b);
+    const FRAC_1_PI: Self = Self::new(0x_32);
+    const FRAC_1_SQRT_2: Self = Self::new(0x_3b);
+    const FRAC_2_PI: Self = Self::new(0x_3a);
+    const FRAC_2_SQRT_PI: Self = Self::new(0x_41);
+    const FRAC_PI_2: Self = Self::new(0x_45);
+    const FRAC_PI_3: Self = Self::new(0x_40);
+    const FRAC_PI_4: Self = Self::new(0x_3d);
+    const FRAC_PI_6: Self = Self::new(0x_38);
+    const FRAC_PI_8: Self = Self::new(0x_35);
+    const LN_10: Self = Self::new(0x_49);
+    const LN_2: Self = Self::new(0x_3b);
+    const LOG10_E: Self = Self::new(0x_36);
+    const LOG2_E: Self = Self::new(0x_44);
+    const PI: Self = Self::new(0x_4d);
+    const SQRT_2: Self = Self::new(0x_43);
+    const LOG2_10: Self = Self::new(0x_4d);
+    const LOG10_2: Self = Self::new(0x_32);
+}
+
+crate::impl_xfloat_math!(P8E2, 8, 2);
+
+#[test]
+fn test_consts() {
+    use crate::MathConsts;
+    use core::f64::consts;
+    let c = |f: f64| P8E2::from(f);
+    assert_eq!(c(consts::E), MathConsts::E);
+    assert_eq!(c(consts::PI), MathConsts::PI);
+    assert_eq!(c(consts::LN_2), MathConsts::LN_2);
+    assert_eq!(c(consts::SQRT_2), MathConsts::SQRT_2);
+    assert_eq!(c(consts::FRAC_1_PI), MathConsts::FRAC_1_PI);
+    assert_eq!(c(consts::LOG2_10), MathConsts::LOG2_10);
+}
+
+#[test]
+fn test_math_exhaustive() {
+    for i in 0..0x100_u32 {
+        let p_a = P8E2::from_bits(i as u8);
+        if p_a.is_nar() {
+            continue;
+        }
+        let f_a = f64::from(p_a);
+        assert_eq!(p_a.sqrt(), P8E2::from(f_a.sqrt()));
+        // exponentials saturate to maxpos and minpos
+        let exp = f_a.exp().clamp(f64::MIN_POSITIVE, f64::MAX);
+        assert_eq!(p_a.exp(), P8E2::from(exp));
+        assert_eq!(p_a.ln(), P8E2::from(f_a.ln()));
+        assert_eq!(p_a.sin(), P8E2::from(f_a.sin()));
+        assert_eq!(p_a.atan(), P8E2::from(f_a.atan()));
+        assert_eq!(p_a.trunc(), P8E2::from(f_a.trunc()));
+        assert_eq!(p_a.floor(), P8E2::from(f_a.floor()));
+        assert_eq!(p_a.ceil(), P8E2::from(f_a.ceil()));
+        if (f_a - f_a.trunc()).abs() != 0.5 {
+            assert_eq!(p_a.round(), P8E2::from(f_a.round()));
+        }
+    }
+    let nar = P8E2::NAR;
+    assert_eq!(
+        (nar.sqrt(), nar.exp(), nar.ln(), nar.round()),
+        (nar, nar, nar, nar)
+    );
+}
+
+#[test]
+fn test_mul_add() {
+    use crate::p64e2::reference::{round, value};
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let (p_a, p_b, p_c): (P8E2, P8E2, P8E2) = (rng.gen(), rng.gen(), rng.gen());
+        let (a, ea) = value(p_a.to_bits() as u128, 8, 2);
+        let (b, eb) = value(p_b.to_bits() as u128, 8, 2);
+        let (c, ec) = value(p_c.to_bits() as u128, 8, 2);
+        let ep = ea + eb;
+        let e = ep.min(ec);
+        let sum = ((a * b) << (ep - e) as usize) + (c << (ec - e) as usize);
+        assert_eq!(
+            p_a.mul_add(p_b, p_c).to_bits(),
+            round(&sum, &1.into(), e, 8, 2) as u8
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use super::P8E2;
+
+crate::impl_xfloat_ops!(P8E2, 8, 2);
+
+/// Exact sums and products of two `P8E2` fit in an `f64`, and the quotients
+/// are never close enough to a rounding boundary to be rounded twice.
+#[cfg(test)]
+fn test_exhaustive(fun: fn(P8E2, P8E2) -> P8E2, fun_f64: fn(f64, f64) -> f64) {
+    for i in 0..0x100_u32 {
+        for j in 0..0x100_u32 {
+            let p_a = P8E2::from_bits(i as u8);
+            let p_b = P8E2::from_bits(j as u8);
+            let f = fun_f64(f64::from(p_a), f64::from(p_b));
+            assert_eq!(fun(p_a, p_b), P8E2::from(f), "{:?} {:?}", p_a, p_b);
+        }
+    }
+}
+
+#[test]
+fn add() {
+    test_exhaustive(|p_a, p_b| p_a + p_b, |f_a, f_b| f_a + f_b);
+}
+
+#[test]
+fn sub() {
+    test_exhaustive(|p_a, p_b| p_a - p_b, |f_a, f_b| f_a - f_b);
+}
+
+#[test]
+fn mul() {
+    test_exhaustive(|p_a, p_b| p_a * p_b, |f_a, f_b| f_a * f_b);
+}
+
+#[test]
+fn div() {
+    test_exhaustive(|p_a, p_b| p_a / p_b, |f_a, f_b| f_a / f_b);
+}
+
+/// Compares the remainders with the exact ones rounded by the reference.
+#[test]
+fn rem() {
+    use crate::p64e2::reference::div_rem;
+    for i in 0..0x100_u32 {
+        for j in 0..0x100_u32 {
+            let p_a = P8E2::from_bits(i as u8);
+            let p_b = P8E2::from_bits(j as u8);
+            let [trunc, nearest, euclid] =
+                div_rem(p_a.to_bits() as u64, p_b.to_bits() as u64, 8, 2);
+            let p = |bits: u64| P8E2::from_bits(bits as u8);
+            assert_eq!(p_a % p_b, p(trunc.1), "{:?} {:?}", p_a, p_b);
+            assert_eq!(p_a.remainder(p_b), p(nearest.1));
+            assert_eq!(p_a.remquo(p_b), (p(nearest.1), nearest.2));
+            assert_eq!(p_a.div_euclid(p_b), p(euclid.0));
+            assert_eq!(p_a.rem_euclid(p_b), p(euclid.1));
+        }
+    }
+}
@@ -0,0 +1,410 @@
+//! Correctly rounded conversion of decimal and hexadecimal strings to posits.
+
+use crate::convert::encode_posit;
+use crate::decimal::Decimal;
+use crate::limbs;
+use core::fmt;
+
+/// An error which can be returned when parsing a posit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParsePositError {
+    /// The string is empty.
+    Empty,
+    /// The string is neither a number nor one of `NaR`, `nan`, `inf` and
+    /// `infinity`.
+    Invalid,
+}
+
+impl fmt::Display for ParsePositError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ParsePositError::Empty => "cannot parse posit from empty string",
+            ParsePositError::Invalid => "invalid posit literal",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePositError {}
+
+/// Rounds the number written in `src` to the nearest `n`-bit posit with `es`
+/// exponent bits, without any intermediate rounding.
+///
+/// The number has an optional sign, followed either by decimal digits with an
+/// optional fraction and decimal exponent (`-1.25e-3`), or by `0x` and
+/// hexadecimal digits with an optional fraction and binary exponent
+/// (`0x1.4p-3`). `NaR`, `nan`, `inf` and `infinity`, in any case, are NaR.
+pub(crate) fn parse_posit(src: &str, n: u32, es: u32) -> Result<u64, ParsePositError> {
+    let (sign, rest) = match split_sign(src)? {
+        (sign, Some(rest)) => (sign, rest),
+        (_, None) => return Ok(1 << (n - 1)),
+    };
+    let number = match rest {
+        [b'0', b'x', hex @ ..] | [b'0', b'X', hex @ ..] => parse_hex(hex),
+        _ => parse_decimal(rest),
+    };
+    match number {
+        Some((_, 0, _)) => Ok(0),
+        Some((scale, frac, sticky)) => Ok(encode_posit(sign, scale, frac, sticky, n, es)),
+        None => Err(ParsePositError::Invalid),
+    }
+}
+
+/// Rounds the number written in `src`, as for `parse_posit`, to the
+/// `width`-bit quire `limbs`, sign-extended to 64-bit limbs, whose least
+/// significant bit has the weight `2^-one_bit`. Rounds to nearest, ties to
+/// even, so the exact values written by `fmt_quire` load back unchanged.
+///
+/// Returns `false` for NaR and for numbers beyond the range of the quire.
+pub(crate) fn parse_quire(
+    src: &str,
+    limbs: &mut [u64],
+    width: u32,
+    one_bit: i32,
+) -> Result<bool, ParsePositError> {
+    let (sign, rest) = match split_sign(src)? {
+        (sign, Some(rest)) => (sign, rest),
+        (_, None) => return Ok(false),
+    };
+    limbs.iter_mut().for_each(|l| *l = 0);
+    let fits = match rest {
+        [b'0', b'x', hex @ ..] | [b'0', b'X', hex @ ..] => load_hex(hex, limbs, width, one_bit),
+        _ => load_decimal(rest, limbs, width, one_bit),
+    };
+    match fits {
+        Some(true) if sign => {
+            limbs::neg(limbs);
+            Ok(true)
+        }
+        Some(fits) => Ok(fits),
+        None => Err(ParsePositError::Invalid),
+    }
+}
+
+/// Splits off the sign, and returns no number for the words of NaR.
+fn split_sign(src: &str) -> Result<(bool, Option<&[u8]>), ParsePositError> {
+    let (sign, rest) = match src.as_bytes() {
+        [] => return Err(ParsePositError::Empty),
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        rest => (false, rest),
+    };
+    for word in [&b"nar"[..], b"nan", b"inf", b"infinity"].iter() {
+        if rest.eq_ignore_ascii_case(word) {
+            return Ok((sign, None));
+        }
+    }
+    Ok((sign, Some(rest)))
+}
+
+/// Loads the magnitude of a hexadecimal number into the limbs of a quire,
+/// and returns whether it fits.
+fn load_hex(s: &[u8], limbs: &mut [u64], width: u32, one_bit: i32) -> Option<bool> {
+    let (int, fract, exp) = split(s, 16, b'p')?;
+    let len = limbs.len();
+    // position of the lowest bit of the first digit in the quire
+    let first = exp
+        .saturating_add(one_bit as i64)
+        .saturating_add(4 * int.len() as i64 - 4);
+    let (mut half, mut sticky) = (false, false);
+    for (i, c) in int.iter().chain(fract).enumerate() {
+        let digit = (*c as char).to_digit(16).unwrap();
+        for b in (0..4).filter(|b| ((digit >> b) & 1) != 0) {
+            match first.saturating_sub(4 * i as i64).saturating_add(b) {
+                e if e >= width as i64 - 1 => return Some(false),
+                e if e >= 0 => limbs[len - 1 - (e / 64) as usize] |= 1 << (e % 64),
+                -1 => half = true,
+                _ => sticky = true,
+            }
+        }
+    }
+    if half && (sticky || (limbs[len - 1] & 1) != 0) {
+        limbs::mul_add_small(limbs, 1, 1);
+    }
+    Some(fits(limbs, width))
+}
+
+/// Loads the magnitude of a decimal number into the limbs of a quire, and
+/// returns whether it fits.
+fn load_decimal(s: &[u8], limbs: &mut [u64], width: u32, one_bit: i32) -> Option<bool> {
+    let (int, fract, exp) = split(s, 10, b'e')?;
+    let mut d = Decimal::new();
+    for &c in int {
+        d.push(c - b'0', true);
+    }
+    for &c in fract {
+        d.push(c - b'0', false);
+    }
+    d.trim();
+    if d.nd == 0 {
+        return Some(true);
+    }
+    d.dp = d.dp.saturating_add(exp);
+    // 10^width lies beyond every quire, and 10^-one_bit rounds to zero
+    if d.dp > width as i64 {
+        return Some(false);
+    } else if d.dp < -(one_bit as i64) {
+        return Some(true);
+    }
+    d.shift(one_bit);
+    if d.dp < 0 {
+        return Some(true);
+    }
+    d.round(d.dp as usize);
+    for i in 0..d.dp {
+        if limbs::mul_add_small(limbs, 10, d.digit(i) as u64) != 0 {
+            return Some(false);
+        }
+    }
+    Some(fits(limbs, width))
+}
+
+/// Whether the magnitude in the limbs is below `2^(width - 1)`.
+fn fits(limbs: &[u64], width: u32) -> bool {
+    let len = limbs.len();
+    let top = width as usize - 1;
+    limbs[..len - 1 - top / 64].iter().all(|&l| l == 0)
+        && (limbs[len - 1 - top / 64] >> (top % 64)) == 0
+}
+
+/// Scale given to numbers far beyond the range of any posit, which saturate.
+const SCALE_LIMIT: i64 = 1 << 20;
+
+/// Splits `digits[.digits][marker[sign]digits]` into its integer digits, its
+/// fraction digits and its exponent, which saturates.
+fn split(s: &[u8], radix: u32, marker: u8) -> Option<(&[u8], &[u8], i64)> {
+    let is_digit = |c: &&u8| (**c as char).is_digit(radix);
+    let (int, rest) = s.split_at(s.iter().take_while(is_digit).count());
+    let (fract, rest) = match rest {
+        [b'.', tail @ ..] => tail.split_at(tail.iter().take_while(is_digit).count()),
+        _ => (&rest[..0], rest),
+    };
+    if int.is_empty() && fract.is_empty() {
+        return None;
+    }
+    let exp = match rest {
+        [] => 0,
+        [c, tail @ ..] if (c | 0x20) == marker => {
+            let (negative, digits) = match tail {
+                [b'-', digits @ ..] => (true, digits),
+                [b'+', digits @ ..] => (false, digits),
+                _ => (false, tail),
+            };
+            if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+                return None;
+            }
+            let exp = digits.iter().fold(0_i64, |exp, &c| {
+                exp.saturating_mul(10).saturating_add((c - b'0') as i64)
+            });
+            if negative {
+                -exp
+            } else {
+                exp
+            }
+        }
+        _ => return None,
+    };
+    Some((int, fract, exp))
+}
+
+/// Unpacks a hexadecimal number into the arguments of `encode_posit`.
+fn parse_hex(s: &[u8]) -> Option<(i32, u128, bool)> {
+    let (int, fract, mut exp) = split(s, 16, b'p')?;
+    let mut mant = 0_u128;
+    let mut sticky = false;
+    for (i, c) in int.iter().chain(fract).enumerate() {
+        let digit = (*c as char).to_digit(16).unwrap() as u128;
+        let is_int = i < int.len();
+        if (mant >> 124) == 0 {
+            mant = (mant << 4) | digit;
+            if !is_int {
+                exp = exp.saturating_sub(4);
+            }
+        } else {
+            sticky |= digit != 0;
+            if is_int {
+                exp = exp.saturating_add(4);
+            }
+        }
+    }
+    if mant == 0 {
+        return Some((0, 0, false));
+    }
+    let lz = mant.leading_zeros();
+    let scale = exp.saturating_add(127 - lz as i64);
+    let scale = scale.clamp(-SCALE_LIMIT, SCALE_LIMIT) as i32;
+    Some((scale, mant << lz, sticky))
+}
+
+/// Unpacks a decimal number into the arguments of `encode_posit`.
+fn parse_decimal(s: &[u8]) -> Option<(i32, u128, bool)> {
+    let (int, fract, exp) = split(s, 10, b'e')?;
+    let mut d = Decimal::new();
+    for &c in int {
+        d.push(c - b'0', true);
+    }
+    for &c in fract {
+        d.push(c - b'0', false);
+    }
+    d.trim();
+    if d.nd == 0 {
+        return Some((0, 0, false));
+    }
+    d.dp = d.dp.saturating_add(exp);
+    // 10^(SCALE_LIMIT / 4) lies far beyond 2^SCALE_LIMIT / 2^(SCALE_LIMIT / 2)
+    if d.dp > SCALE_LIMIT / 4 {
+        return Some((SCALE_LIMIT as i32, 1 << 127, false));
+    } else if d.dp < -SCALE_LIMIT / 4 {
+        return Some((-SCALE_LIMIT as i32, 1 << 127, false));
+    }
+    Some(d.unpack())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_posit, ParsePositError};
+    use crate::format::tests::Buf;
+    use crate::p64e2::reference::value;
+    use core::fmt::{self, Write};
+    use num_bigint::BigInt;
+    use num_traits::{Pow, Signed};
+
+    fn check(src: fmt::Arguments, n: u32, es: u32, expected: u64) {
+        let mut buf = Buf::new();
+        buf.write_fmt(src).unwrap();
+        let mask = u64::MAX >> (64 - n);
+        assert_eq!(
+            parse_posit(buf.as_str(), n, es),
+            Ok(expected & mask),
+            "{}",
+            buf.as_str()
+        );
+        let mut neg = Buf::new();
+        write!(neg, "-{}", buf.as_str()).unwrap();
+        assert_eq!(
+            parse_posit(neg.as_str(), n, es),
+            Ok(expected.wrapping_neg() & mask),
+            "{}",
+            neg.as_str()
+        );
+    }
+
+    /// Checks the positive posit `bits`, the boundary above it and the
+    /// numbers just around that boundary, all written exactly in decimal.
+    fn check_decimal(bits: u64, n: u32, es: u32) {
+        let (m, e) = value(bits as u128, n, es);
+        let (m, d) = if e >= 0 {
+            (m << e as usize, 0)
+        } else {
+            (m * BigInt::from(5).pow(-e as u32), e)
+        };
+        check(format_args!("{}e{}", m, d), n, es, bits);
+        if bits == u64::MAX >> (65 - n) {
+            // maxpos
+            check(format_args!("{}1e{}", m, d - 1), n, es, bits);
+            return;
+        }
+        let (m, e) = value(2 * bits as u128 + 1, n + 1, es);
+        let (m, d) = if e >= 0 {
+            (m << e as usize, 0)
+        } else {
+            (m * BigInt::from(5).pow(-e as u32), e)
+        };
+        let even = bits + (bits & 1);
+        check(format_args!("{}e{}", m, d), n, es, even);
+        check(format_args!("{}1e{}", m, d - 1), n, es, bits + 1);
+        check(format_args!("{}9e{}", m - 1, d - 1), n, es, bits);
+    }
+
+    /// Checks the positive posit `bits` written exactly in hexadecimal.
+    fn check_hex(bits: u64, n: u32, es: u32) {
+        let (m, e) = value(bits as u128, n, es);
+        check(format_args!("{:#x}p{}", m.abs(), e), n, es, bits);
+        check(format_args!("0x{:X}.{:x}P{:+}", m, 0, e), n, es, bits);
+    }
+
+    #[test]
+    fn test_parse_exhaustive() {
+        for &(n, es) in [(8, 0), (8, 2), (16, 1), (16, 2)].iter() {
+            for bits in 1..(1 << (n - 1)) {
+                check_decimal(bits, n, es);
+                check_hex(bits, n, es);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_random() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        for &(n, es) in [(32, 2), (64, 2)].iter() {
+            for _ in 0..crate::NTESTS16 {
+                let bits = rng.gen_range(1, 1 << (n - 1));
+                check_decimal(bits, n, es);
+                check_hex(bits, n, es);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        use crate::{P16E1, P32E2, P64E2, P8E0};
+        let p = |s: &str| s.parse::<P32E2>();
+        assert_eq!(p("1"), Ok(P32E2::ONE));
+        assert_eq!(p("+1.0"), Ok(P32E2::ONE));
+        assert_eq!(p("1."), Ok(P32E2::ONE));
+        assert_eq!(p("0.1e1"), Ok(P32E2::ONE));
+        assert_eq!(p("10000e-4"), Ok(P32E2::ONE));
+        assert_eq!(p("0x1p0"), Ok(P32E2::ONE));
+        assert_eq!(p("0x.8P1"), Ok(P32E2::ONE));
+        assert_eq!(p("-.5"), Ok(P32E2::from(-0.5)));
+        assert_eq!(p("0x1.8p1"), Ok(P32E2::from(3.)));
+        assert_eq!(p("-0x18"), Ok(P32E2::from(-24.)));
+        assert_eq!(p("000.000"), Ok(P32E2::ZERO));
+        assert_eq!(p("-0e99999999999999999999"), Ok(P32E2::ZERO));
+        assert_eq!(p("1e99999999999999999999"), Ok(P32E2::MAX));
+        assert_eq!(p("-1e-99999999999999999999"), Ok(-P32E2::MIN_POSITIVE));
+        assert_eq!(p("0x1p-99999999999999999999"), Ok(P32E2::MIN_POSITIVE));
+        for s in ["NaR", "nar", "-NAN", "+inf", "Infinity"].iter() {
+            assert_eq!(p(s), Ok(P32E2::NAR));
+        }
+        assert_eq!(p(""), Err(ParsePositError::Empty));
+        for s in [
+            "+", "-", ".", "e5", "1e", "1e+", "1.2.3", "0x", "0x.p1", "1p1", "0x1g", "1e5.0", " 1",
+            "1 ", "nanx", "1_000", "--1",
+        ]
+        .iter()
+        {
+            assert_eq!(p(s), Err(ParsePositError::Invalid), "{}", s);
+        }
+
+        // digits beyond the kept ones still break a tie, 1 + 2^-28 lies halfway
+        let tie = "1.0000000037252902984619140625";
+        assert_eq!(p(tie).unwrap().to_bits(), 0x_4000_0000);
+        let mut buf = Buf::new();
+        buf.write_str(tie).unwrap();
+        for _ in 0..900 {
+            buf.write_char('0').unwrap();
+        }
+        buf.write_char('1').unwrap();
+        assert_eq!(p(buf.as_str()).unwrap().to_bits(), 0x_4000_0001);
+
+        // 1 + 2^-59 needs more bits than f64 has
+        let s = "1.00000000000000000173472347597680709441192448139190673828125";
+        assert_eq!(P64E2::from(s.parse::<f64>().unwrap()), P64E2::ONE);
+        assert_eq!(
+            s.parse::<P64E2>().unwrap().to_bits(),
+            0x_4000_0000_0000_0001
+        );
+        assert_eq!("12.3".parse::<P16E1>(), Ok(P16E1::from(12.3)));
+        assert_eq!("-0.4".parse::<P8E0>(), Ok(P8E0::from(-0.4)));
+        #[cfg(feature = "nightly")]
+        {
+            use crate::{Posit, PxE1, PxE2};
+            assert_eq!("-12.3".parse::<PxE1<{ 12 }>>(), Ok(PxE1::from(-12.3)));
+            assert_eq!("0.3".parse::<PxE2<{ 20 }>>(), Ok(PxE2::from(0.3)));
+            assert_eq!("nar".parse::<PxE2<{ 20 }>>(), Ok(PxE2::NAR));
+            assert_eq!("1e-3".parse::<Posit<24, 3>>(), Ok(Posit::from(1e-3)));
+        }
+    }
+}
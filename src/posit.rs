@@ -0,0 +1,238 @@
+//! Posits of any width `N` (`3 <= N <= 64`) and exponent size `ES`.
+//!
+//! Every operation unpacks the operands, computes the exact result (or
+//! enough of it to round correctly) and rounds once, so a single code path
+//! serves all configurations. The fixed-width types are faster for the
+//! formats they cover.
+
+mod convert;
+mod math;
+mod ops;
+mod quire;
+pub use self::quire::PositQuire;
+
+/// Posit with `N` bits and `ES` exponent bits.
+///
+/// The bits are kept sign-extended in an `i64`, so the ordering of the
+/// representation is the ordering of the reals, with NaR the smallest value.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Posit<const N: u32, const ES: u32>(i64);
+
+impl<const N: u32, const ES: u32> Posit<{ N }, { ES }> {
+    pub const SIZE: usize = N as usize;
+    pub const ES: usize = ES as usize;
+    pub const USEED: u128 = 1 << (1 << ES);
+
+    /// Machine epsilon, the distance from one to the next larger number.
+    ///
+    /// Only defined if one has at least one fraction bit, `N >= ES + 4`.
+    pub const EPSILON: Self = Self::new(Self::epsilon_bits());
+
+    /// Smallest finite value.
+    pub const MIN: Self = Self::new(-Self::MAX.0);
+
+    /// Smallest positive value.
+    pub const MIN_POSITIVE: Self = Self::new(1);
+
+    /// Largest finite value.
+    pub const MAX: Self = Self::new((u64::MAX >> (65 - N)) as i64);
+
+    /// Not a Real (NaR).
+    pub const NAR: Self = Self::new(i64::MIN >> (64 - N));
+
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self::NAR;
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Self::NAR;
+
+    /// Zero.
+    pub const ZERO: Self = Self::new(0);
+
+    /// Identity.
+    pub const ONE: Self = Self::new(1 << (N - 2));
+
+    /// Creates a posit from its bits, sign-extended to 64 bits.
+    #[inline]
+    pub const fn new(i: i64) -> Self {
+        Self(i)
+    }
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+    #[inline]
+    pub fn is_nar(self) -> bool {
+        self == Self::NAR
+    }
+    /// Creates a posit from the lowest `N` bits of `v`.
+    #[inline]
+    pub fn from_bits(v: u64) -> Self {
+        Self(((v << (64 - N)) as i64) >> (64 - N))
+    }
+    /// Returns the `N` bits of the posit in the lowest bits.
+    #[inline]
+    pub fn to_bits(self) -> u64 {
+        (self.0 as u64) & Self::mask()
+    }
+    #[inline]
+    pub fn abs(self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.is_nar()
+    }
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.is_nar()
+    }
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        !self.is_nar()
+    }
+    #[inline]
+    pub fn is_normal(self) -> bool {
+        !self.is_nar()
+    }
+    #[inline]
+    pub fn classify(self) -> core::num::FpCategory {
+        use core::num::FpCategory::*;
+        if self.is_zero() {
+            Zero
+        } else if self.is_nar() {
+            Nan
+        } else {
+            Normal
+        }
+    }
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self < Self::ZERO
+    }
+    #[inline]
+    pub fn copysign(self, other: Self) -> Self {
+        if self.is_sign_negative() != other.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+    #[inline]
+    pub fn signum(self) -> Self {
+        match self.0 {
+            n if n == Self::NAR.0 => Self::NAR,
+            n if n > 0 => Self::ONE,
+            0 => Self::ZERO,
+            _ => -Self::ONE,
+        }
+    }
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+}
+
+impl<const N: u32, const ES: u32> Posit<{ N }, { ES }> {
+    #[inline]
+    pub(crate) const fn mask() -> u64 {
+        u64::MAX >> (64 - N)
+    }
+
+    /// Bits of `2^(ES + 3 - N)`, the spacing of the numbers in `[1, useed)`.
+    const fn epsilon_bits() -> i64 {
+        let scale = (ES as i64) + 3 - (N as i64);
+        let k = scale >> ES;
+        let exp = scale - (k << ES);
+        (1 << (N as i64 - 2 + k)) | (exp << (N as i64 - 2 + k - ES as i64))
+    }
+
+    /// Unpacks a nonzero, non-NaR posit into its sign, scale and fraction,
+    /// with the hidden bit of the fraction at bit 63.
+    #[inline]
+    pub(crate) fn unpack(self) -> (bool, i32, u64) {
+        crate::convert::decode_posit(self.to_bits(), N, ES)
+    }
+
+    /// Rounds `(-1)^sign * frac * 2^(scale - 127)`, with the hidden bit of
+    /// `frac` at bit 127, to the nearest posit.
+    #[inline]
+    pub(crate) fn encode(sign: bool, scale: i32, frac: u128, sticky: bool) -> Self {
+        Self::from_bits(crate::convert::encode_posit(
+            sign, scale, frac, sticky, N, ES,
+        ))
+    }
+}
+
+impl<const N: u32, const ES: u32> core::str::FromStr for Posit<{ N }, { ES }> {
+    type Err = crate::ParsePositError;
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_posit(src, N, ES).map(Self::from_bits)
+    }
+}
+
+crate::impl_fmt!([const N: u32, const ES: u32] Posit<{ N }, { ES }>, N, ES);
+crate::impl_fields!([const N: u32, const ES: u32] Posit<{ N }, { ES }>, N, ES);
+
+use core::fmt;
+impl<const N: u32, const ES: u32> fmt::Debug for Posit<{ N }, { ES }> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            crate::fields::debug(f, "Posit", self.to_bits(), N, ES)
+        } else {
+            write!(f, "Posit<{}, {}>({})", N, ES, self.0)
+        }
+    }
+}
+
+impl<const N: u32, const ES: u32> crate::AssociatedQuire<Self> for Posit<{ N }, { ES }> {
+    type Q = crate::PositQuire<{ N }, { ES }>;
+}
+
+#[cfg(any(feature = "rand", test))]
+impl<const N: u32, const ES: u32> rand::distributions::Distribution<Posit<{ N }, { ES }>>
+    for rand::distributions::Standard
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Posit<{ N }, { ES }> {
+        // every posit but NaR, maxpos included
+        let max = Posit::<{ N }, { ES }>::MAX.0;
+        Posit::new(rng.sample(rand::distributions::Uniform::new_inclusive(-max, max)))
+    }
+}
+
+#[test]
+fn test_consts() {
+    fn check<const N: u32, const ES: u32>() {
+        type P<const N: u32, const ES: u32> = Posit<{ N }, { ES }>;
+        let max_scale = ((N as i32) - 2) << ES;
+        assert_eq!(f64::from(P::<{ N }, { ES }>::ONE), 1.);
+        assert_eq!(f64::from(P::<{ N }, { ES }>::MAX), 2_f64.powi(max_scale));
+        assert_eq!(f64::from(P::<{ N }, { ES }>::MIN), -(2_f64.powi(max_scale)));
+        assert_eq!(
+            f64::from(P::<{ N }, { ES }>::MIN_POSITIVE),
+            2_f64.powi(-max_scale)
+        );
+        assert!(f64::from(P::<{ N }, { ES }>::NAR).is_nan());
+        assert_eq!(P::<{ N }, { ES }>::NAR.to_bits(), 1 << (N - 1));
+        assert!(P::<{ N }, { ES }>::NAR < P::<{ N }, { ES }>::MIN);
+        let scale = (ES as i32) + 3 - (N as i32);
+        assert_eq!(f64::from(P::<{ N }, { ES }>::EPSILON), 2_f64.powi(scale));
+    }
+    check::<8, 0>();
+    check::<8, 3>();
+    check::<10, 1>();
+    check::<16, 2>();
+    check::<24, 3>();
+    check::<32, 2>();
+    check::<64, 2>();
+    check::<64, 3>();
+}
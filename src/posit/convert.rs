@@ -0,0 +1,190 @@
+use super::Posit;
+use crate::convert::{
+    convert_posit_bits, f64_to_posit_bits, posit_to_ieee_bits, u128_to_posit_bits,
+};
+use crate::{P16E1, P16E2, P32E2, P64E2, P8E0, P8E2};
+use core::convert::From;
+use core::f64;
+
+impl<const N: u32, const ES: u32> Posit<{ N }, { ES }> {
+    /// Rounds the posit to another width and exponent size.
+    #[inline]
+    pub fn convert<const M: u32, const E: u32>(self) -> Posit<{ M }, { E }> {
+        Posit::from_bits(convert_posit_bits(self.to_bits(), N, ES, M, E))
+    }
+}
+
+impl<const N: u32, const ES: u32> From<f64> for Posit<{ N }, { ES }> {
+    #[inline]
+    fn from(float: f64) -> Self {
+        Self::from_bits(f64_to_posit_bits(float, N, ES))
+    }
+}
+
+impl<const N: u32, const ES: u32> From<f32> for Posit<{ N }, { ES }> {
+    #[inline]
+    fn from(float: f32) -> Self {
+        Self::from(float as f64)
+    }
+}
+
+impl<const N: u32, const ES: u32> From<Posit<{ N }, { ES }>> for f64 {
+    #[inline]
+    fn from(p_a: Posit<{ N }, { ES }>) -> Self {
+        f64::from_bits(posit_to_ieee_bits(p_a.to_bits(), N, ES, 52, 1023))
+    }
+}
+
+impl<const N: u32, const ES: u32> From<Posit<{ N }, { ES }>> for f32 {
+    #[inline]
+    fn from(p_a: Posit<{ N }, { ES }>) -> Self {
+        f32::from_bits(posit_to_ieee_bits(p_a.to_bits(), N, ES, 23, 127) as u32)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {$(
+        impl<const N: u32, const ES: u32> From<$int> for Posit<{ N }, { ES }> {
+            #[inline]
+            fn from(a: $int) -> Self {
+                Self::from_bits(u128_to_posit_bits(a < 0, (a as i128).unsigned_abs(), N, ES))
+            }
+        }
+    )*}
+}
+
+macro_rules! impl_from_uint {
+    ($($uint:ty),*) => {$(
+        impl<const N: u32, const ES: u32> From<$uint> for Posit<{ N }, { ES }> {
+            #[inline]
+            fn from(a: $uint) -> Self {
+                Self::from_bits(u128_to_posit_bits(false, a as u128, N, ES))
+            }
+        }
+    )*}
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize);
+impl_from_uint!(u8, u16, u32, u64, u128, usize);
+crate::impl_int_convert!([const N: u32, const ES: u32] Posit<{ N }, { ES }>, N, ES);
+
+macro_rules! impl_from_fixed {
+    ($($posit:ty, $n:literal, $es:literal, $bits:ty);*) => {$(
+        impl<const N: u32, const ES: u32> From<$posit> for Posit<{ N }, { ES }> {
+            #[inline]
+            fn from(p_a: $posit) -> Self {
+                Self::from_bits(convert_posit_bits(p_a.to_bits() as u64, $n, $es, N, ES))
+            }
+        }
+
+        impl<const N: u32, const ES: u32> From<Posit<{ N }, { ES }>> for $posit {
+            #[inline]
+            fn from(p_a: Posit<{ N }, { ES }>) -> Self {
+                Self::from_bits(convert_posit_bits(p_a.to_bits(), N, ES, $n, $es) as $bits)
+            }
+        }
+    )*}
+}
+
+impl_from_fixed!(
+    P8E0, 8, 0, u8;
+    P16E1, 16, 1, u16;
+    P32E2, 32, 2, u32;
+    P64E2, 64, 2, u64;
+    P8E2, 8, 2, u8;
+    P16E2, 16, 2, u16
+);
+
+#[test]
+fn test_f64_exhaustive() {
+    for i in 0..0x100_u32 {
+        let p_a = P8E0::from_bits(i as u8);
+        let p_b = Posit::<8, 0>::from_bits(i as u64);
+        assert_eq!(f64::from(p_b).to_bits(), f64::from(p_a).to_bits());
+        assert_eq!(Posit::<8, 0>::from(f64::from(p_a)), p_b);
+        assert_eq!(P8E0::from(p_b), p_a);
+    }
+    for i in 0..0x1_0000_u32 {
+        let p_a = P16E1::from_bits(i as u16);
+        let p_b = Posit::<16, 1>::from_bits(i as u64);
+        assert_eq!(f64::from(p_b).to_bits(), f64::from(p_a).to_bits());
+        assert_eq!(Posit::<16, 1>::from(f64::from(p_a)), p_b);
+        assert_eq!(P16E1::from(p_b), p_a);
+        assert_eq!(Posit::<16, 1>::from(p_a), p_b);
+    }
+}
+
+#[test]
+fn test_f64_p32e2() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS32 {
+        let p_a: P32E2 = rng.gen();
+        let p_b = Posit::<32, 2>::from(p_a);
+        assert_eq!(p_b.to_bits(), p_a.to_bits() as u64);
+        assert_eq!(f64::from(p_b), f64::from(p_a));
+        assert_eq!(f32::from(p_b), f32::from(p_a));
+        // compare the rounding with the midpoints and their neighbours
+        let f = f64::from(p_a) * (1. + rng.gen_range(-1e-8, 1e-8));
+        assert_eq!(
+            Posit::<32, 2>::from(f),
+            Posit::from(P32E2::from(f)),
+            "{}",
+            f
+        );
+    }
+}
+
+#[test]
+fn test_convert_widths() {
+    // widening is exact, and narrowing back gives the same posit
+    for i in 0..0x1_0000_u64 {
+        let p_a = Posit::<16, 1>::from_bits(i);
+        let p_b = p_a.convert::<64, 3>();
+        assert_eq!(f64::from(p_b).to_bits(), f64::from(p_a).to_bits());
+        assert_eq!(p_b.convert::<16, 1>(), p_a);
+        assert_eq!(
+            p_a.convert::<8, 0>(),
+            Posit::from(P8E0::from(P16E1::from(p_a)))
+        );
+    }
+    // f64 keeps the 53 leading bits of 64-bit posits
+    let p = Posit::<64, 2>::ONE + Posit::EPSILON;
+    assert_eq!(f64::from(p), 1.);
+    assert_eq!(f64::from(Posit::<64, 2>::MAX), 2_f64.powi(248));
+    assert_eq!(f64::from(Posit::<64, 5>::MAX), f64::INFINITY);
+    assert_eq!(f64::from(Posit::<64, 5>::MIN_POSITIVE), 0.);
+}
+
+#[test]
+fn test_int() {
+    use crate::PositConversionError;
+    use core::convert::TryFrom;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    type P = Posit<64, 2>;
+    for _ in 0..crate::NTESTS16 {
+        let i: i32 = rng.gen();
+        // 64-bit posits hold every i32 near one exactly
+        assert_eq!(i32::try_from(P::from(i)), Ok(i));
+        assert_eq!(i64::try_from(P::from(i)), Ok(i as i64));
+        let u: u32 = rng.gen();
+        assert_eq!(u32::try_from(P::from(u)), Ok(u));
+        // the conversion of integers and of f64 round the same way
+        let j = rng.gen_range(-(1_i64 << 53), 1 << 53);
+        assert_eq!(Posit::<32, 2>::from(j), Posit::from(j as f64));
+        assert_eq!(Posit::<16, 1>::from(j), Posit::from(j as f64));
+    }
+    assert_eq!(i64::try_from(P::NAR), Err(PositConversionError::NaR));
+    assert_eq!(
+        u32::try_from(P::from(-3)),
+        Err(PositConversionError::Negative)
+    );
+    assert_eq!(i32::try_from(P::MAX), Err(PositConversionError::Overflow));
+    assert_eq!(i32::try_from(P::from(2.5)), Ok(2));
+    assert_eq!(i32::try_from(P::from(-3.5)), Ok(-4));
+    assert_eq!(P::from(-3.5).to_int_floor(), Ok(-4));
+    assert_eq!(P::from(-3.5).to_int_trunc(), Ok(-3));
+    assert_eq!(Posit::<8, 0>::from(0.75).to_int_ceil(), Ok(1_u8));
+    assert_eq!(u128::try_from(P::from(u64::MAX)), Ok(1 << 64));
+}
@@ -0,0 +1,141 @@
+use super::Posit;
+
+impl<const N: u32, const ES: u32> Posit<{ N }, { ES }> {
+    /// Fused multiply-add, `self * b + c` rounded once.
+    #[inline]
+    pub fn mul_add(self, b: Self, c: Self) -> Self {
+        Self::from_bits(crate::xfloat::mul_add(
+            self.to_bits(),
+            b.to_bits(),
+            c.to_bits(),
+            N,
+            ES,
+        ))
+    }
+
+    /// Square root, correctly rounded.
+    ///
+    /// Returns NaR for negative numbers and NaR.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        Self::from_bits(crate::xfloat::sqrt(self.to_bits(), N, ES))
+    }
+
+    /// Raises the number to an integer power.
+    ///
+    /// The result is correctly rounded unless the exact power lies within about
+    /// `n^2 2^-124` (relative) of a rounding boundary, where it may be one ulp
+    /// smaller in magnitude. `x.powi(0)` is 1 for every real `x`, and NaR for
+    /// zero raised to a negative power.
+    #[inline]
+    pub fn powi(self, n: i32) -> Self {
+        Self::from_bits(crate::xfloat::powi(self.to_bits(), n, N, ES))
+    }
+
+    /// Rounds to the nearest integer, ties to even.
+    pub fn round(self) -> Self {
+        if self.is_nar() || self.is_zero() {
+            return self;
+        }
+        let (sign, scale, frac) = self.unpack();
+        if scale < -1 {
+            return Self::ZERO;
+        } else if scale >= 63 {
+            return self;
+        }
+        let shift = (63 - scale) as u32;
+        let frac = frac as u128;
+        let half = 1 << (shift - 1);
+        let rem = frac & ((half << 1) - 1);
+        let mut int = (frac >> shift) as u64;
+        if rem > half || (rem == half && (int & 1) != 0) {
+            int += 1;
+        }
+        if int == 0 {
+            return Self::ZERO;
+        }
+        let lz = int.leading_zeros();
+        Self::encode(sign, 63 - lz as i32, ((int << lz) as u128) << 64, false)
+    }
+
+    /// Rounds toward zero.
+    pub fn trunc(self) -> Self {
+        if self.is_nar() || self.is_zero() {
+            return self;
+        }
+        let (sign, scale, frac) = self.unpack();
+        if scale < 0 {
+            Self::ZERO
+        } else if scale >= 63 {
+            self
+        } else {
+            let int = frac & !((1 << (63 - scale)) - 1);
+            Self::encode(sign, scale, (int as u128) << 64, false)
+        }
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self {
+        let t = self.trunc();
+        if self < t {
+            t - Self::ONE
+        } else {
+            t
+        }
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Self {
+        let t = self.trunc();
+        if self > t {
+            t + Self::ONE
+        } else {
+            t
+        }
+    }
+
+    #[inline]
+    pub fn fract(self) -> Self {
+        self - self.trunc()
+    }
+}
+
+#[test]
+fn test_rounding() {
+    fn check<const N: u32, const ES: u32>() {
+        for i in 0..(1_u64 << N) {
+            let p_a = Posit::<{ N }, { ES }>::from_bits(i);
+            let f_a = f64::from(p_a);
+            let p = |f: f64| Posit::<{ N }, { ES }>::from(f);
+            assert_eq!(p_a.trunc(), p(f_a.trunc()));
+            assert_eq!(p_a.floor(), p(f_a.floor()));
+            assert_eq!(p_a.ceil(), p(f_a.ceil()));
+            assert_eq!(p_a.fract(), p(f_a.fract()));
+            let r = f_a.round();
+            let even = if (f_a - f_a.trunc()).abs() == 0.5 {
+                2. * (f_a / 2.).round()
+            } else {
+                r
+            };
+            assert_eq!(p_a.round(), p(even), "{}", f_a);
+        }
+    }
+    check::<8, 0>();
+    check::<8, 2>();
+    check::<12, 1>();
+    check::<16, 3>();
+}
+
+#[test]
+fn test_sqrt_mul_add() {
+    use crate::P16E1;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let p = |p: P16E1| Posit::<16, 1>::from_bits(p.to_bits() as u64);
+    for i in 0..0x1_0000_u32 {
+        let p_a = P16E1::from_bits(i as u16);
+        assert_eq!(p(p_a).sqrt(), p(p_a.sqrt()));
+        let (p_b, p_c): (P16E1, P16E1) = (rng.gen(), rng.gen());
+        assert_eq!(p(p_a).mul_add(p(p_b), p(p_c)), p(p_a.mul_add(p_b, p_c)));
+    }
+}
@@ -0,0 +1,167 @@
+use super::Posit;
+use crate::xfloat;
+use core::ops;
+
+impl<const N: u32, const ES: u32> ops::Neg for Posit<{ N }, { ES }> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_bits(self.0.wrapping_neg() as u64)
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::AddAssign for Posit<{ N }, { ES }> {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::SubAssign for Posit<{ N }, { ES }> {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::MulAssign for Posit<{ N }, { ES }> {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::DivAssign for Posit<{ N }, { ES }> {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::RemAssign for Posit<{ N }, { ES }> {
+    #[inline]
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::Add for Posit<{ N }, { ES }> {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self::from_bits(xfloat::add(self.to_bits(), other.to_bits(), N, ES))
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::Sub for Posit<{ N }, { ES }> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::Mul for Posit<{ N }, { ES }> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self::from_bits(xfloat::mul(self.to_bits(), other.to_bits(), N, ES))
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::Div for Posit<{ N }, { ES }> {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self::from_bits(xfloat::div(self.to_bits(), other.to_bits(), N, ES))
+    }
+}
+
+crate::impl_rem!([const N: u32, const ES: u32] Posit<{ N }, { ES }>, N, ES);
+
+#[cfg(test)]
+fn test_exhaustive<const N: u32, const ES: u32>() {
+    // The operands have few enough bits that sums (of operands with close
+    // scales), products and quotients (never at a rounding midpoint) are
+    // correctly rounded through f64.
+    let n = 1_u64 << N;
+    let p = |f: f64| Posit::<{ N }, { ES }>::from(f);
+    for i in 0..n {
+        let p_a = Posit::<{ N }, { ES }>::from_bits(i);
+        let f_a = f64::from(p_a);
+        assert_eq!(-p_a, p(-f_a));
+        for j in 0..n {
+            let p_b = Posit::<{ N }, { ES }>::from_bits(j);
+            let f_b = f64::from(p_b);
+            assert_eq!(p_a + p_b, p(f_a + f_b), "{} + {}", f_a, f_b);
+            assert_eq!(p_a - p_b, p(f_a - f_b), "{} - {}", f_a, f_b);
+            assert_eq!(p_a * p_b, p(f_a * f_b), "{} * {}", f_a, f_b);
+            if f_b != 0. {
+                assert_eq!(p_a / p_b, p(f_a / f_b), "{} / {}", f_a, f_b);
+            } else {
+                assert_eq!(p_a / p_b, Posit::NAR);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ops_exhaustive() {
+    test_exhaustive::<5, 1>();
+    test_exhaustive::<8, 0>();
+    test_exhaustive::<8, 1>();
+    test_exhaustive::<8, 2>();
+    test_exhaustive::<8, 3>();
+    test_exhaustive::<10, 1>();
+}
+
+#[test]
+fn test_ops_p32e2() {
+    use crate::P32E2;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let p = |p: P32E2| Posit::<32, 2>::from_bits(p.to_bits() as u64);
+    for _ in 0..crate::NTESTS32 {
+        let (p_a, p_b, p_c): (P32E2, P32E2, P32E2) = (rng.gen(), rng.gen(), rng.gen());
+        assert_eq!(p(p_a) + p(p_b), p(p_a + p_b));
+        assert_eq!(p(p_a) - p(p_b), p(p_a - p_b));
+        assert_eq!(p(p_a) * p(p_b), p(p_a * p_b));
+        assert_eq!(p(p_a) / p(p_b), p(p_a / p_b));
+        // P32E2::mul_add is not always correctly rounded, the quire is exact
+        let mut q = crate::Q32E2::from_posit(p_c);
+        q += (p_a, p_b);
+        assert_eq!(p(p_a).mul_add(p(p_b), p(p_c)), p(q.to_posit()));
+    }
+}
+
+#[test]
+fn test_ops_p16e1() {
+    use crate::P16E1;
+    let p = |p: P16E1| Posit::<16, 1>::from_bits(p.to_bits() as u64);
+    for i in (0..0x1_0000_u32).step_by(3) {
+        let p_a = P16E1::from_bits(i as u16);
+        for j in (0..0x1_0000_u32).step_by(257) {
+            let p_b = P16E1::from_bits(j as u16);
+            assert_eq!(p(p_a) + p(p_b), p(p_a + p_b));
+            assert_eq!(p(p_a) - p(p_b), p(p_a - p_b));
+            assert_eq!(p(p_a) * p(p_b), p(p_a * p_b));
+            assert_eq!(p(p_a) / p(p_b), p(p_a / p_b));
+        }
+    }
+}
+
+#[test]
+fn test_ops_p64e2() {
+    type P = Posit<64, 2>;
+    let one = P::ONE;
+    let eps = P::EPSILON;
+    // 1 + eps/2 is a tie and rounds to the even number one
+    let half_eps = eps / (one + one);
+    assert_eq!(one + half_eps, one);
+    assert_eq!(one + eps + half_eps, one + eps + eps);
+    assert_eq!((one + eps) * (one - eps), one);
+    assert_eq!((one + eps) - one, eps);
+    assert_eq!(P::MAX + P::MAX, P::MAX);
+    assert_eq!(P::MIN_POSITIVE * P::MIN_POSITIVE, P::MIN_POSITIVE);
+    assert_eq!(P::from(7.) % P::from(2.), one);
+}
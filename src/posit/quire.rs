@@ -0,0 +1,334 @@
+use super::Posit;
+use core::{fmt, ops};
+
+const LIMBS: usize = 32;
+
+/// Quire of `Posit<N, ES>`: a 2048-bit two's complement fixed-point number
+/// that accumulates sums of products without rounding.
+///
+/// The least significant bit is `minpos^2`, and at least 31 carry bits lie
+/// above `maxpos^2`; a sum that overflows them makes the quire NaR. The
+/// limbs are stored most significant first.
+///
+/// This only fits the configurations with `(N - 2) << ES` below 504, and
+/// using the quire of any other one fails to compile:
+///
+/// ```compile_fail
+/// let q = softposit::PositQuire::<64, 4>::init();
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PositQuire<const N: u32, const ES: u32>([u64; LIMBS]);
+
+impl<const N: u32, const ES: u32> PositQuire<{ N }, { ES }> {
+    /// Fails to evaluate for the configurations that do not fit.
+    const FITS: () = assert!(
+        ES < 16 && ((N as u64 - 2) << ES) < 504,
+        "the quire of this posit configuration does not fit in 2048 bits"
+    );
+
+    pub const ZERO: Self = {
+        let () = Self::FITS;
+        Self([0; LIMBS])
+    };
+    pub const NAR: Self = {
+        let () = Self::FITS;
+        Self(Self::nar_bits())
+    };
+
+    /// Bit of the quire whose weight is one.
+    const ONE_BIT: i32 = {
+        let () = Self::FITS;
+        2 * (((N as i32) - 2) << ES)
+    };
+
+    const fn nar_bits() -> [u64; LIMBS] {
+        let mut bits = [0; LIMBS];
+        bits[0] = 0x_8000_0000_0000_0000;
+        bits
+    }
+
+    #[inline]
+    pub const fn init() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    pub fn from_posit(p: Posit<{ N }, { ES }>) -> Self {
+        Self::from(p)
+    }
+
+    #[inline]
+    pub fn from_bits(v: [u64; LIMBS]) -> Self {
+        let () = Self::FITS;
+        Self(v)
+    }
+
+    #[inline]
+    pub fn to_bits(&self) -> [u64; LIMBS] {
+        self.0
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+
+    #[inline]
+    pub fn is_nar(&self) -> bool {
+        *self == Self::NAR
+    }
+
+    #[inline]
+    pub fn add_product(&mut self, p_a: Posit<{ N }, { ES }>, p_b: Posit<{ N }, { ES }>) {
+        self.fdp(p_a, p_b, true);
+    }
+
+    #[inline]
+    pub fn sub_product(&mut self, p_a: Posit<{ N }, { ES }>, p_b: Posit<{ N }, { ES }>) {
+        self.fdp(p_a, p_b, false);
+    }
+
+    #[inline]
+    pub fn to_posit(&self) -> Posit<{ N }, { ES }> {
+        Posit::from(self)
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::ZERO;
+    }
+
+    /// Negates the quire, NaR stays NaR.
+    #[inline]
+    pub fn neg(&mut self) {
+        crate::limbs::neg(&mut self.0);
+    }
+
+    /// Exact fused dot product step, `self ± a * b`.
+    fn fdp(&mut self, p_a: Posit<{ N }, { ES }>, p_b: Posit<{ N }, { ES }>, plus: bool) {
+        if self.is_nar() || p_a.is_nar() || p_b.is_nar() {
+            *self = Self::NAR;
+            return;
+        } else if p_a.is_zero() || p_b.is_zero() {
+            return;
+        }
+        let (a, b) = (p_a.unpack(), p_b.unpack());
+        if crate::limbs::add_product(&mut self.0, Self::ONE_BIT, a, b, plus) {
+            *self = Self::NAR;
+        }
+    }
+}
+
+impl<const N: u32, const ES: u32> From<Posit<{ N }, { ES }>> for PositQuire<{ N }, { ES }> {
+    #[inline]
+    fn from(p_a: Posit<{ N }, { ES }>) -> Self {
+        let mut q = Self::ZERO;
+        q += p_a;
+        q
+    }
+}
+
+impl<const N: u32, const ES: u32> From<&PositQuire<{ N }, { ES }>> for Posit<{ N }, { ES }> {
+    fn from(q_a: &PositQuire<{ N }, { ES }>) -> Self {
+        if q_a.is_zero() {
+            return Self::ZERO;
+        } else if q_a.is_nar() {
+            return Self::NAR;
+        }
+        let (sign, scale, frac, sticky) =
+            crate::limbs::unpack(&q_a.0, PositQuire::<{ N }, { ES }>::ONE_BIT);
+        Self::encode(sign, scale, frac, sticky)
+    }
+}
+
+impl<const N: u32, const ES: u32> From<PositQuire<{ N }, { ES }>> for Posit<{ N }, { ES }> {
+    #[inline]
+    fn from(q_a: PositQuire<{ N }, { ES }>) -> Self {
+        Self::from(&q_a)
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::AddAssign<(Posit<{ N }, { ES }>, Posit<{ N }, { ES }>)>
+    for PositQuire<{ N }, { ES }>
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: (Posit<{ N }, { ES }>, Posit<{ N }, { ES }>)) {
+        self.fdp(rhs.0, rhs.1, true);
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::SubAssign<(Posit<{ N }, { ES }>, Posit<{ N }, { ES }>)>
+    for PositQuire<{ N }, { ES }>
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: (Posit<{ N }, { ES }>, Posit<{ N }, { ES }>)) {
+        self.fdp(rhs.0, rhs.1, false);
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::AddAssign<Posit<{ N }, { ES }>>
+    for PositQuire<{ N }, { ES }>
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Posit<{ N }, { ES }>) {
+        self.fdp(rhs, Posit::ONE, true);
+    }
+}
+
+impl<const N: u32, const ES: u32> ops::SubAssign<Posit<{ N }, { ES }>>
+    for PositQuire<{ N }, { ES }>
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Posit<{ N }, { ES }>) {
+        self.fdp(rhs, Posit::ONE, false);
+    }
+}
+
+impl<const N: u32, const ES: u32> crate::Quire<Posit<{ N }, { ES }>> for PositQuire<{ N }, { ES }> {
+    type Bits = [u64; LIMBS];
+    fn init() -> Self {
+        Self::init()
+    }
+    fn from_posit(p: Posit<{ N }, { ES }>) -> Self {
+        Self::from_posit(p)
+    }
+    fn to_posit(&self) -> Posit<{ N }, { ES }> {
+        Self::to_posit(self)
+    }
+    fn from_bits(v: Self::Bits) -> Self {
+        Self::from_bits(v)
+    }
+    fn to_bits(&self) -> Self::Bits {
+        Self::to_bits(self)
+    }
+    fn is_zero(&self) -> bool {
+        Self::is_zero(self)
+    }
+    fn is_nar(&self) -> bool {
+        Self::is_nar(self)
+    }
+    fn add_product(&mut self, p_a: Posit<{ N }, { ES }>, p_b: Posit<{ N }, { ES }>) {
+        Self::add_product(self, p_a, p_b)
+    }
+    fn sub_product(&mut self, p_a: Posit<{ N }, { ES }>, p_b: Posit<{ N }, { ES }>) {
+        Self::sub_product(self, p_a, p_b)
+    }
+    fn clear(&mut self) {
+        Self::clear(self)
+    }
+    fn neg(&mut self) {
+        Self::neg(self)
+    }
+}
+
+impl<const N: u32, const ES: u32> fmt::Display for PositQuire<{ N }, { ES }> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::format::fmt_quire(&self.0, 64 * LIMBS as u32, Self::ONE_BIT, f, None)
+    }
+}
+
+impl<const N: u32, const ES: u32> fmt::Debug for PositQuire<{ N }, { ES }> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PositQuire<{}, {}>({:x?})", N, ES, &self.0[..])
+    }
+}
+
+#[test]
+fn test_quire_q32e2() {
+    use crate::format::tests::Buf;
+    use crate::{P32E2, Q32E2};
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let p = |p: P32E2| Posit::<32, 2>::from(p);
+    for _ in 0..crate::NTESTS16 / 10 {
+        let mut q_a = Q32E2::init();
+        let mut q_b = PositQuire::<32, 2>::init();
+        for _ in 0..10 {
+            let (p_a, p_b): (P32E2, P32E2) = (rng.gen(), rng.gen());
+            if rng.gen() {
+                q_a += (p_a, p_b);
+                q_b += (p(p_a), p(p_b));
+            } else {
+                q_a -= (p_a, p_b);
+                q_b -= (p(p_a), p(p_b));
+            }
+        }
+        assert_eq!(q_b.to_posit(), p(q_a.to_posit()));
+        // exact like the quire of the standard posit
+        let s = Buf::from_fmt(format_args!("{}", q_b));
+        assert_eq!(s.as_str(), Buf::from_fmt(format_args!("{}", q_a)).as_str());
+    }
+}
+
+#[test]
+fn test_quire_fmt() {
+    use crate::format::tests::{exact_quire, Buf};
+    use num_bigint::BigInt;
+    // the widest configuration, where maxpos^2 + minpos^2 has every digit
+    type P = Posit<64, 3>;
+    let mut q = PositQuire::<64, 3>::init();
+    q += (P::MAX, P::MAX);
+    q += (P::MIN_POSITIVE, P::MIN_POSITIVE);
+    let exact = exact_quire(&((BigInt::from(1) << 1984) + 1), 992, false);
+    let s = Buf::from_fmt(format_args!("{}", q));
+    assert_eq!(s.as_str(), exact.as_str());
+    q.neg();
+    let s = Buf::from_fmt(format_args!("{}", q));
+    assert_eq!(&s.as_str()[1..], exact.as_str());
+    assert!(s.as_str().starts_with('-'));
+    let s = Buf::from_fmt(format_args!("{}", PositQuire::<64, 3>::NAR));
+    assert_eq!(s.as_str(), "NaR");
+}
+
+#[test]
+fn test_quire_products() {
+    fn check<const N: u32, const ES: u32>() {
+        for i in 0..(1_u64 << N) {
+            let p_a = Posit::<{ N }, { ES }>::from_bits(i);
+            for j in 0..(1_u64 << N) {
+                let p_b = Posit::<{ N }, { ES }>::from_bits(j);
+                let mut q = PositQuire::init();
+                q += (p_a, p_b);
+                assert_eq!(q.to_posit(), p_a * p_b);
+                q += p_a;
+                assert_eq!(q.to_posit(), p_a.mul_add(p_b, p_a));
+                q.neg();
+                assert_eq!(q.to_posit(), -p_a.mul_add(p_b, p_a));
+            }
+        }
+    }
+    check::<6, 0>();
+    check::<8, 0>();
+    check::<8, 3>();
+    check::<9, 1>();
+    check::<9, 2>();
+}
+
+#[test]
+fn test_quire_cancellation() {
+    fn check<const N: u32, const ES: u32>() {
+        type P<const N: u32, const ES: u32> = Posit<{ N }, { ES }>;
+        let (max, min) = (P::<{ N }, { ES }>::MAX, P::<{ N }, { ES }>::MIN_POSITIVE);
+        let mut q = PositQuire::<{ N }, { ES }>::init();
+        q += (max, max);
+        q += (min, min);
+        q += (max, max);
+        q -= (max, max);
+        q -= (max, max);
+        assert_eq!(q.to_posit(), min);
+        q -= (min, min);
+        assert!(q.is_zero());
+        q -= (max, P::ONE);
+        assert_eq!(q.to_posit(), -max);
+        q.neg();
+        assert_eq!(q.to_posit(), max);
+        q += (P::NAR, P::ONE);
+        assert!(q.is_nar());
+        assert!(q.to_posit().is_nar());
+    }
+    check::<8, 0>();
+    check::<16, 1>();
+    check::<32, 2>();
+    check::<64, 2>();
+    check::<64, 3>();
+}
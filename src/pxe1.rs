@@ -3,17 +3,37 @@ use core::mem;
 mod convert;
 mod math;
 mod ops;
+crate::impl_num_traits!([const N: u32] PxE1<{ N }>, 32, 1);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct PxE1<const N: u32>(i32);
 
 impl<const N: u32> PxE1<{ N }> {
+    pub const SIZE: usize = N as usize;
     pub const ES: usize = 1;
     pub const USEED: usize = 4;
 
+    /// Machine epsilon, the distance from one to the next larger number.
+    pub const EPSILON: Self = Self::new(Self::epsilon_bits());
+
+    /// Smallest finite value.
+    pub const MIN: Self = Self::new(-((0x_7FFF_FFFF & Self::mask()) as i32));
+
+    /// Smallest positive value.
+    pub const MIN_POSITIVE: Self = Self::new(1 << (32 - N));
+
+    /// Largest finite value.
+    pub const MAX: Self = Self::new((0x_7FFF_FFFF & Self::mask()) as i32);
+
     /// Not a Real (NaR).
     pub const NAR: Self = Self::new(-0x_8000_0000);
 
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self::NAR;
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Self::NAR;
+
     /// Zero.
     pub const ZERO: Self = Self::new(0);
 
@@ -40,12 +60,119 @@ impl<const N: u32> PxE1<{ N }> {
     pub fn to_bits(self) -> u32 {
         unsafe { mem::transmute(self) }
     }
+    /// Splits the posit into the fields of its encoding, `None` for zero and
+    /// NaR.
+    #[inline]
+    pub fn decode(self) -> Option<crate::PositFields> {
+        crate::fields::decode((self.to_bits() >> (32 - N)) as u64, N, 1)
+    }
+    /// Builds the posit with the given fields, `None` if they are out of
+    /// range or need rounding.
+    #[inline]
+    pub fn from_fields(fields: crate::PositFields) -> Option<Self> {
+        crate::fields::encode(&fields, N, 1).map(|bits| Self::from_bits((bits as u32) << (32 - N)))
+    }
+    #[inline]
+    pub fn abs(self) -> Self {
+        if self.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.is_nar()
+    }
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.is_nar()
+    }
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        !self.is_nar()
+    }
+    #[inline]
+    pub fn is_normal(self) -> bool {
+        !self.is_nar()
+    }
+    #[inline]
+    pub fn classify(self) -> core::num::FpCategory {
+        use core::num::FpCategory::*;
+        if self.is_zero() {
+            Zero
+        } else if self.is_nar() {
+            Nan
+        } else {
+            Normal
+        }
+    }
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self < Self::ZERO
+    }
+    #[inline]
+    pub fn copysign(self, other: Self) -> Self {
+        if ((self.to_bits() ^ other.to_bits()) & Self::SIGN_MASK) != 0 {
+            -self
+        } else {
+            self
+        }
+    }
+    #[inline]
+    pub fn signum(self) -> Self {
+        match self.0 {
+            n if n == Self::NAR.0 => Self::NAR,
+            n if n > 0 => Self::ONE,
+            0 => Self::ZERO,
+            _ => -Self::ONE,
+        }
+    }
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+    #[inline]
+    pub fn to_degrees(self) -> Self {
+        const PIS_IN_180: u64 = 0x_7729_7706_98f0_7dee;
+        self * Self::new(Self::round_bits(PIS_IN_180))
+    }
+    #[inline]
+    pub fn to_radians(self) -> Self {
+        const PI_BY_180: u64 = 0x_0877_d1a8_94a7_4e45;
+        self * Self::new(Self::round_bits(PI_BY_180))
+    }
 }
 
 impl<const N: u32> PxE1<{ N }> {
     pub(crate) const fn mask() -> u32 {
         (((-0x_8000_0000_i32) >> (N - 1)) as u32)
     }
+
+    /// Rounds the bits of a positive 64-bit posit with the same exponent size
+    /// to `N` bits.
+    const fn round_bits(bits: u64) -> i32 {
+        let shift = 64 - N;
+        let half = 1_u64 << (shift - 1);
+        let rem = bits & ((half << 1) - 1);
+        let mut ui = bits >> shift;
+        if rem > half || (rem == half && (ui & 1) != 0) {
+            ui += 1;
+        }
+        (ui << (32 - N)) as i32
+    }
+
+    /// Bits of `2^(ES + 3 - N)`, the spacing of the numbers in `[1, useed)`.
+    const fn epsilon_bits() -> i32 {
+        let scale = (Self::ES as i32) + 3 - (N as i32);
+        let k = scale >> Self::ES;
+        let exp = scale - (k << Self::ES);
+        (1 << (30 + k)) | (exp << (30 + k - (Self::ES as i32)))
+    }
     pub const SIGN_MASK: u32 = 0x_8000_0000;
     pub const REGIME_SIGN_MASK: u32 = 0x_4000_0000;
 
@@ -69,7 +196,7 @@ impl<const N: u32> PxE1<{ N }> {
         let (k, tmp) = Self::separate_bits_tmp(bits);
         (
             k,
-            (tmp >> ((N as usize) - 1 - Self::ES)) as i32,
+            (tmp >> (32 - 1 - Self::ES)) as i32,
             (tmp | 0x4000_0000) & 0x7FFF_FFFF,
         )
     }
@@ -110,3 +237,166 @@ impl<const N: u32> PxE1<{ N }> {
         }
     }
 }
+
+impl<const N: u32> crate::MathConsts for PxE1<{ N }> {
+    const E: Self = Self::new(Self::round_bits(0x_55bf_0a8b_1457_6953));
+    const FRAC_1_PI: Self = Self::new(Self::round_bits(0x_245f_306d_c9c8_82a5));
+    const FRAC_1_SQRT_2: Self = Self::new(Self::round_bits(0x_36a0_9e66_7f3b_cc91));
+    const FRAC_2_PI: Self = Self::new(Self::round_bits(0x_345f_306d_c9c8_82a5));
+    const FRAC_2_SQRT_PI: Self = Self::new(Self::round_bits(0x_420d_d750_429b_6d12));
+    const FRAC_PI_2: Self = Self::new(Self::round_bits(0x_4921_fb54_442d_1847));
+    const FRAC_PI_3: Self = Self::new(Self::round_bits(0x_40c1_5238_2d73_6584));
+    const FRAC_PI_4: Self = Self::new(Self::round_bits(0x_3921_fb54_442d_1847));
+    const FRAC_PI_6: Self = Self::new(Self::round_bits(0x_30c1_5238_2d73_6584));
+    const FRAC_PI_8: Self = Self::new(Self::round_bits(0x_2921_fb54_442d_1847));
+    const LN_10: Self = Self::new(Self::round_bits(0x_526b_b1bb_b555_1583));
+    const LN_2: Self = Self::new(Self::round_bits(0x_362e_42fe_fa39_ef35));
+    const LOG10_E: Self = Self::new(Self::round_bits(0x_2bcb_7b15_26e5_0e33));
+    const LOG2_E: Self = Self::new(Self::round_bits(0x_4715_4765_2b82_fe17));
+    const PI: Self = Self::new(Self::round_bits(0x_5921_fb54_442d_1847));
+    const SQRT_2: Self = Self::new(Self::round_bits(0x_46a0_9e66_7f3b_cc91));
+    const LOG2_10: Self = Self::new(Self::round_bits(0x_5a93_4f09_79a3_7160));
+    const LOG10_2: Self = Self::new(Self::round_bits(0x_2344_1350_9f79_fef3));
+}
+
+impl<const N: u32> core::str::FromStr for PxE1<{ N }> {
+    type Err = crate::ParsePositError;
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_posit(src, N, 1).map(|bits| Self::from_bits((bits as u32) << (32 - N)))
+    }
+}
+
+use core::fmt;
+impl<const N: u32> fmt::Display for PxE1<{ N }> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::format::fmt_posit((self.to_bits() >> (32 - N)) as u64, N, 1, f, None)
+    }
+}
+
+impl<const N: u32> fmt::LowerExp for PxE1<{ N }> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::format::fmt_posit((self.to_bits() >> (32 - N)) as u64, N, 1, f, Some("e"))
+    }
+}
+
+impl<const N: u32> fmt::UpperExp for PxE1<{ N }> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::format::fmt_posit((self.to_bits() >> (32 - N)) as u64, N, 1, f, Some("E"))
+    }
+}
+
+impl<const N: u32> fmt::Debug for PxE1<{ N }> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            crate::fields::debug(f, "PxE1", (self.to_bits() >> (32 - N)) as u64, N, 1)
+        } else {
+            write!(f, "PxE1<{}>({})", N, self.0)
+        }
+    }
+}
+
+#[cfg(any(feature = "rand", test))]
+impl<const N: u32> rand::distributions::Distribution<PxE1<{ N }>>
+    for rand::distributions::Standard
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> PxE1<{ N }> {
+        let max = (1_i64 << (N - 1)) - 1;
+        let s = rng.gen_range(-max, max + 1);
+        PxE1::new((s << (32 - N)) as i32)
+    }
+}
+
+#[cfg(test)]
+fn test_api<const N: u32>() {
+    use crate::MathConsts;
+    use num_traits::{Float, FromPrimitive};
+    use rand::Rng;
+    type P<const N: u32> = PxE1<{ N }>;
+    let bits = |f: f64| P::<{ N }>::from(f).to_bits();
+
+    // constants
+    assert_eq!(P::<{ N }>::SIZE, N as usize);
+    assert_eq!(P::<{ N }>::E.to_bits(), bits(core::f64::consts::E));
+    assert_eq!(P::<{ N }>::PI.to_bits(), bits(core::f64::consts::PI));
+    assert_eq!(
+        P::<{ N }>::FRAC_PI_3.to_bits(),
+        bits(core::f64::consts::FRAC_PI_3)
+    );
+    assert_eq!(P::<{ N }>::LN_2.to_bits(), bits(core::f64::consts::LN_2));
+    assert_eq!(
+        P::<{ N }>::LOG2_E.to_bits(),
+        bits(core::f64::consts::LOG2_E)
+    );
+    assert_eq!(
+        P::<{ N }>::LOG10_2.to_bits(),
+        bits(core::f64::consts::LOG10_2)
+    );
+    assert_eq!(
+        P::<{ N }>::SQRT_2.to_bits(),
+        bits(core::f64::consts::SQRT_2)
+    );
+    let scale = (P::<{ N }>::ES as i32) + 3 - (N as i32);
+    assert_eq!(f64::from(P::<{ N }>::EPSILON), 2_f64.powi(scale));
+    assert_eq!(
+        (P::<{ N }>::ONE + P::<{ N }>::EPSILON).to_bits(),
+        P::<{ N }>::ONE.to_bits() + (1 << (32 - N))
+    );
+    let max_scale = ((N as i32) - 2) << P::<{ N }>::ES;
+    assert_eq!(f64::from(P::<{ N }>::MAX), 2_f64.powi(max_scale));
+    assert_eq!(f64::from(P::<{ N }>::MIN_POSITIVE), 2_f64.powi(-max_scale));
+    assert_eq!(P::<{ N }>::MIN, -P::<{ N }>::MAX);
+    assert_eq!(P::<{ N }>::max_value(), P::<{ N }>::MAX);
+    assert!(P::<{ N }>::NAN.is_nan());
+    assert_eq!(
+        <P<{ N }> as FromPrimitive>::from_i32(-3),
+        Some(P::<{ N }>::from(-3.))
+    );
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS8 {
+        let p_a: P<{ N }> = rng.gen();
+        let p_b: P<{ N }> = rng.gen();
+        assert!(!p_a.is_nar());
+        let f_a = f64::from(p_a);
+        let f_b = f64::from(p_b);
+
+        // formatting round trip
+        #[cfg(feature = "std")]
+        assert_eq!(p_a.to_string().parse::<P<{ N }>>(), Ok(p_a));
+        // num_traits
+        assert_eq!(Float::abs(p_a).to_bits(), bits(f_a.abs()));
+        assert_eq!(Float::floor(p_a).to_bits(), bits(f_a.floor()));
+        assert_eq!(Float::ceil(p_a).to_bits(), bits(f_a.ceil()));
+        assert_eq!(Float::trunc(p_a).to_bits(), bits(f_a.trunc()));
+        assert_eq!(Float::recip(p_a).to_bits(), bits(f_a.recip()));
+        assert_eq!(Float::powi(p_a, 2), p_a * p_a);
+        if f_a != 0. {
+            assert_eq!(Float::signum(p_a).to_bits(), bits(f_a.signum()));
+        }
+        assert_eq!(Float::copysign(p_a, p_b).to_bits(), bits(f_a.copysign(f_b)));
+        assert_eq!(Float::atan2(p_a, p_b).to_bits(), bits(f_a.atan2(f_b)));
+        if f_b != 0. {
+            assert_eq!((p_a % p_b).to_bits(), bits(f_a % f_b));
+        }
+        // integers
+        let i: i32 = rng.gen();
+        assert_eq!(P::<{ N }>::from(i).to_bits(), bits(i as f64));
+        let u: u32 = rng.gen();
+        assert_eq!(P::<{ N }>::from(u).to_bits(), bits(u as f64));
+        let f: f32 = rng.gen();
+        assert_eq!(P::<{ N }>::from(f).to_bits(), bits(f as f64));
+    }
+}
+
+#[test]
+fn test_api_widths() {
+    test_api::<9>();
+    test_api::<12>();
+    test_api::<16>();
+    test_api::<20>();
+    test_api::<24>();
+    test_api::<28>();
+    test_api::<31>();
+    test_api::<32>();
+}
@@ -1,257 +1,14 @@
 use super::PxE1;
-use crate::{MulAddType, WithSign};
+use crate::dd::{self, DD};
+use crate::WithSign;
 
 impl<const N: u32> PxE1<{ N }> {
+    /// Fused multiply-add, `self * b + c` rounded once.
     #[inline]
     pub fn mul_add(self, b: Self, c: Self) -> Self {
-        let ui_a = self.to_bits();
-        let ui_b = b.to_bits();
-        let ui_c = c.to_bits();
-        Self::mul_add_ui(ui_a, ui_b, ui_c, crate::MulAddType::Add)
-    }
-
-    #[allow(clippy::cognitive_complexity)]
-    fn mul_add_ui(mut ui_a: u32, mut ui_b: u32, mut ui_c: u32, op: MulAddType) -> Self {
-        let mut bits_more = false;
-        //NaR
-        if (ui_a == 0x_8000_0000) || (ui_b == 0x_8000_0000) || (ui_c == 0x_8000_0000) {
-            return Self::NAR;
-        } else if (ui_a == 0) || (ui_b == 0) {
-            return match op {
-                MulAddType::SubC => Self::from_bits(ui_c.wrapping_neg()),
-                _ => Self::from_bits(ui_c),
-            };
-        }
-
-        let sign_a = Self::sign_ui(ui_a);
-        let sign_b = Self::sign_ui(ui_b);
-        let sign_c = Self::sign_ui(ui_c); //^ (op == softposit_mulAdd_subC);
-        let mut sign_z = sign_a ^ sign_b; // ^ (op == softposit_mulAdd_subProd);
-
-        if sign_a {
-            ui_a = ui_a.wrapping_neg();
-        }
-        if sign_b {
-            ui_b = ui_b.wrapping_neg();
-        }
-        if sign_c {
-            ui_c = ui_c.wrapping_neg();
-        }
-
-        if N == 2 {
-            let reg_sa = Self::sign_reg_ui(ui_a);
-            let reg_sb = Self::sign_reg_ui(ui_b);
-            let mut u_z = if reg_sa & reg_sb {
-                0x_4000_0000_u32
-            } else {
-                0x0
-            };
-            if sign_z {
-                // i.e. negative prod
-                if sign_c {
-                    u_z |= ui_c;
-                    u_z = u_z.wrapping_neg();
-                } else {
-                    //prod is negative
-                    u_z = if ui_c == u_z {
-                        0
-                    } else if u_z > 0 {
-                        0x_4000_0000
-                    } else {
-                        0x_C000_0000
-                    };
-                }
-            } else {
-                //prod : same sign signZ=0
-                if sign_c {
-                    u_z = if ui_c == u_z {
-                        0
-                    } else if u_z > 0 {
-                        0x_4000_0000
-                    } else {
-                        0x_C000_0000
-                    };
-                } else {
-                    //C is positive
-                    u_z |= ui_c;
-                }
-            }
-            Self::from_bits(u_z)
-        } else {
-            let (mut k_a, tmp) = Self::separate_bits_tmp(ui_a);
-            let mut exp_a = (tmp >> 29) as i32; //to get 2 bits
-            let frac_a = (tmp << 2) | 0x_8000_0000;
-
-            let (k_b, tmp) = Self::separate_bits_tmp(ui_b);
-            k_a += k_b;
-            exp_a += (tmp >> 29) as i32;
-            let mut frac64_z = (frac_a as u64) * (((tmp << 2) | 0x_8000_0000) as u64);
-
-            if exp_a > 1 {
-                k_a += 1;
-                exp_a ^= 0x2;
-            }
-
-            let rcarry = (frac64_z & 0x_8000_0000_0000_0000) != 0; //1st bit of frac64_z
-            if rcarry {
-                if exp_a != 0 {
-                    k_a += 1;
-                }
-                exp_a ^= 1;
-                frac64_z >>= 1;
-            }
-
-            let mut k_z;
-            let mut exp_z: i32;
-            if ui_c != 0 {
-                let (k_c, exp_c, frac_c) = Self::separate_bits(ui_c);
-                let mut frac64_c = (frac_c as u64) << 32;
-                let mut shift_right = (((k_a - k_c) as i16) << 1) + (exp_a - exp_c) as i16;
-
-                exp_z = if shift_right < 0 {
-                    // |ui_c| > |Prod|
-                    if shift_right <= -63 {
-                        bits_more = true;
-                        frac64_z = 0;
-                        shift_right = 0;
-                    //set bits_more to one?
-                    } else if (frac64_z << (64 + shift_right)) != 0 {
-                        bits_more = true;
-                    }
-                    if sign_z == sign_c {
-                        frac64_z = frac64_c + (frac64_z >> -shift_right);
-                    } else {
-                        //different signs
-                        frac64_z = frac64_c - (frac64_z >> -shift_right);
-                        sign_z = sign_c;
-                        if bits_more {
-                            frac64_z -= 1;
-                        }
-                    }
-                    k_z = k_c;
-                    exp_c
-                } else if shift_right > 0 {
-                    // |ui_c| < |Prod|
-                    //if frac32C&((1<<shift_right)-1) {bits_more = true;}
-                    if shift_right >= 63 {
-                        bits_more = true;
-                        frac64_c = 0;
-                        shift_right = 0;
-                    } else if (frac64_c << (64 - shift_right)) != 0 {
-                        bits_more = true;
-                    }
-                    if sign_z == sign_c {
-                        frac64_z += frac64_c >> shift_right;
-                    } else {
-                        frac64_z -= frac64_c >> shift_right;
-                        if bits_more {
-                            frac64_z -= 1;
-                        }
-                    }
-                    k_z = k_a;
-                    exp_a
-                } else {
-                    if (frac64_c == frac64_z) && (sign_z != sign_c) {
-                        //check if same number
-                        return Self::ZERO;
-                    } else if sign_z == sign_c {
-                        frac64_z += frac64_c;
-                    } else if frac64_z < frac64_c {
-                        frac64_z = frac64_c - frac64_z;
-                        sign_z = sign_c;
-                    } else {
-                        frac64_z -= frac64_c;
-                    }
-                    k_z = k_a; // actually can be k_c too, no diff
-                    exp_a //same here
-                };
-                let rcarry = (frac64_z & 0x_8000_0000_0000_0000) != 0; //first left bit
-
-                if rcarry {
-                    if exp_z != 0 {
-                        k_z += 1;
-                    }
-                    exp_z ^= 1;
-                    if (frac64_z & 0x1) != 0 {
-                        bits_more = false;
-                    }
-                    frac64_z = (frac64_z >> 1) & 0x_7FFF_FFFF_FFFF_FFFF;
-                } else {
-                    //for subtract cases
-                    if frac64_z != 0 {
-                        while (frac64_z >> 61) == 0 {
-                            k_z -= 1;
-                            frac64_z <<= 2;
-                        }
-                    }
-                    let ecarry = ((0x_4000_0000_0000_0000 & frac64_z) >> 62) != 0;
-
-                    if !ecarry {
-                        if exp_z == 0 {
-                            k_z -= 1;
-                        }
-                        exp_z ^= 1;
-                        frac64_z <<= 1;
-                    }
-                }
-            } else {
-                k_z = k_a;
-                exp_z = exp_a;
-            }
-
-            let (mut regime, reg_sz, reg_z) = Self::calculate_regime(k_z);
-            let reg_z = reg_z as u32;
-
-            let u_z = if reg_z > (N - 2) {
-                //max or min pos. exp and frac does not matter.
-                if reg_sz {
-                    0x_7FFF_FFFF & Self::mask()
-                } else {
-                    0x1 << (32 - N)
-                }
-            } else {
-                let mut bit_n_plus_one = false;
-                let mut frac_z: u32;
-                if reg_z < N {
-                    //remove hidden bits
-                    frac64_z &= 0x_3FFF_FFFF_FFFF_FFFF;
-                    frac_z = (frac64_z >> (reg_z + 33)) as u32; //frac32Z>>16;
-
-                    if reg_z != (N - 2) {
-                        bit_n_plus_one =
-                            ((0x_8000_0000_0000_0000_u64 >> (N - reg_z - 1)) & frac64_z) != 0;
-                        bits_more =
-                            ((0x_7FFF_FFFF_FFFF_FFFF_u64 >> (N - reg_z - 1)) & frac64_z) != 0;
-                        frac_z &= Self::mask();
-                    } else if frac64_z > 0 {
-                        frac_z = 0;
-                        bits_more = true;
-                    }
-                    if (reg_z == (N - 2)) && (exp_z != 0) {
-                        bit_n_plus_one = true;
-                        exp_z = 0;
-                    }
-                } else {
-                    regime = if reg_sz {
-                        regime & Self::mask()
-                    } else {
-                        regime << (32 - N)
-                    };
-                    exp_z = 0;
-                    frac_z = 0;
-                }
-
-                exp_z <<= 29 - reg_z;
-
-                let mut u_z = Self::pack_to_ui(regime, exp_z as u32, frac_z);
-
-                if bit_n_plus_one {
-                    u_z += (((u_z >> (32 - N)) & 1) | (bits_more as u32)) << (32 - N);
-                }
-                u_z
-            };
-            Self::from_bits(u_z.with_sign(sign_z))
-        }
+        let bits = |p: Self| (p.to_bits() >> (32 - N)) as u64;
+        let ui_z = crate::xfloat::mul_add(bits(self), bits(b), bits(c), N, Self::ES as u32);
+        Self::from_bits((ui_z as u32) << (32 - N))
     }
 
     #[inline]
@@ -322,3 +79,481 @@ impl<const N: u32> PxE1<{ N }> {
         Self::from_bits(u_a.with_sign(sign))
     }
 }
+
+impl<const N: u32> PxE1<{ N }> {
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self::from(libm::floor(f64::from(self)))
+    }
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self::from(libm::ceil(f64::from(self)))
+    }
+    #[inline]
+    pub fn trunc(self) -> Self {
+        if self > Self::ZERO {
+            self.floor()
+        } else {
+            self.ceil()
+        }
+    }
+    #[inline]
+    pub fn fract(self) -> Self {
+        self - self.trunc()
+    }
+    #[inline]
+    pub(crate) fn div_rem(
+        self,
+        rhs: Self,
+        rounding: crate::xfloat::QuotRounding,
+    ) -> (Self, Self, i32) {
+        let bits = |p: Self| (p.to_bits() >> (32 - N)) as u64;
+        let (q, r, quo) =
+            crate::xfloat::div_rem(bits(self), bits(rhs), N, Self::ES as u32, rounding);
+        (
+            Self::from_bits((q as u32) << (32 - N)),
+            Self::from_bits((r as u32) << (32 - N)),
+            quo,
+        )
+    }
+    /// IEEE 754 remainder, `self - q * rhs` where `q` is `self / rhs` rounded
+    /// to the nearest integer, ties to even.
+    #[inline]
+    pub fn remainder(self, rhs: Self) -> Self {
+        self.div_rem(rhs, crate::xfloat::QuotRounding::Nearest).1
+    }
+    /// The `remainder` and the lowest 31 bits of the integer quotient with
+    /// the sign of `self / rhs`, like C's `remquo`.
+    #[inline]
+    pub fn remquo(self, rhs: Self) -> (Self, i32) {
+        let (_, r, quo) = self.div_rem(rhs, crate::xfloat::QuotRounding::Nearest);
+        (r, quo)
+    }
+    /// Euclidean division, the integer `q` such that
+    /// `self = q * rhs + self.rem_euclid(rhs)`, rounded to a posit.
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        self.div_rem(rhs, crate::xfloat::QuotRounding::Euclid).0
+    }
+    /// Least nonnegative remainder of `self (mod rhs)`.
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        self.div_rem(rhs, crate::xfloat::QuotRounding::Euclid).1
+    }
+}
+
+// The elementary functions below round a `libm` result in double precision
+// to `N` bits, which is within one ulp as `N <= 32`. Where `crate::dd` has a
+// double-double evaluation of the function, the results within 2^-50
+// (relative) of a rounding boundary are rounded from that instead, which
+// makes them correctly rounded as for `P32E2`. The others, `atan2`, the
+// hyperbolic functions, `cbrt`, `hypot` and `powf`, are only within one ulp.
+impl<const N: u32> PxE1<{ N }> {
+    /// Square root, correctly rounded.
+    ///
+    /// Returns NaR for negative numbers and NaR.
+    pub fn sqrt(self) -> Self {
+        let bits = crate::xfloat::sqrt((self.to_bits() >> (32 - N)) as u64, N, Self::ES as u32);
+        Self::from_bits((bits as u32) << (32 - N))
+    }
+
+    /// Exponential function, `e^(self)`, correctly rounded.
+    pub fn exp(self) -> Self {
+        let x = f64::from(self);
+        let f = libm::exp(x);
+        if self.is_nar() {
+            Self::NAR
+        } else if f == 0. || f.is_infinite() {
+            Self::saturate_exp(f)
+        } else {
+            Self::round_correctly(f, || dd::exp(DD::from(x)))
+        }
+    }
+
+    /// Returns `2^(self)`, correctly rounded.
+    pub fn exp2(self) -> Self {
+        let x = f64::from(self);
+        let f = libm::exp2(x);
+        if self.is_nar() {
+            Self::NAR
+        } else if f == 0. || f.is_infinite() || libm::trunc(x) == x {
+            // exact, but may be a rounding boundary in the longest regimes
+            Self::saturate_exp(f)
+        } else {
+            Self::round_correctly(f, || dd::exp(dd::LN2.mul_f64(x)))
+        }
+    }
+
+    /// Natural logarithm, correctly rounded.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    pub fn ln(self) -> Self {
+        if self <= Self::ZERO {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::round_correctly(libm::log(x), || dd::ln(x))
+    }
+
+    /// Base-2 logarithm, correctly rounded.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    pub fn log2(self) -> Self {
+        if self <= Self::ZERO {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::round_correctly(libm::log2(x), || {
+            let (e, ln_m) = dd::ln_parts(x);
+            DD::from(e as f64) + ln_m * dd::LOG2_E
+        })
+    }
+
+    /// Sine of the number in radians, correctly rounded.
+    pub fn sin(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::sin(x), || dd::sin_cos(x).0)
+    }
+
+    /// Cosine of the number in radians, correctly rounded.
+    pub fn cos(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::cos(x), || dd::sin_cos(x).1)
+    }
+
+    /// Tangent of the number in radians, correctly rounded.
+    pub fn tan(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::tan(x), || {
+            let (s, c) = dd::sin_cos(x);
+            s / c
+        })
+    }
+
+    /// Arcsine in radians, in the range [-pi/2, pi/2], correctly rounded.
+    ///
+    /// Returns NaR if the number is outside the range [-1, 1].
+    pub fn asin(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::asin(x), || dd::asin(x))
+    }
+
+    /// Arccosine in radians, in the range [0, pi], correctly rounded.
+    ///
+    /// Returns NaR if the number is outside the range [-1, 1].
+    pub fn acos(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::acos(x), || dd::acos(x))
+    }
+
+    /// Arctangent in radians, in the range [-pi/2, pi/2], correctly rounded.
+    pub fn atan(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::atan(x), || dd::atan(DD::from(x)))
+    }
+
+    /// Four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians,
+    /// within one ulp.
+    ///
+    /// Returns NaR if both numbers are zero.
+    pub fn atan2(self, other: Self) -> Self {
+        if self.is_zero() && other.is_zero() {
+            Self::NAR
+        } else {
+            Self::from(libm::atan2(f64::from(self), f64::from(other)))
+        }
+    }
+
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    /// Hyperbolic sine, within one ulp.
+    pub fn sinh(self) -> Self {
+        Self::saturate(libm::sinh(f64::from(self)))
+    }
+
+    /// Hyperbolic cosine, within one ulp.
+    pub fn cosh(self) -> Self {
+        Self::saturate(libm::cosh(f64::from(self)))
+    }
+
+    /// Hyperbolic tangent, within one ulp.
+    pub fn tanh(self) -> Self {
+        Self::from(libm::tanh(f64::from(self)))
+    }
+
+    /// Inverse hyperbolic sine, within one ulp.
+    pub fn asinh(self) -> Self {
+        Self::from(libm::asinh(f64::from(self)))
+    }
+
+    /// Inverse hyperbolic cosine, within one ulp.
+    ///
+    /// Returns NaR for numbers less than one.
+    pub fn acosh(self) -> Self {
+        if self < Self::ONE {
+            Self::NAR
+        } else {
+            Self::from(libm::acosh(f64::from(self)))
+        }
+    }
+
+    /// Inverse hyperbolic tangent, within one ulp.
+    ///
+    /// Returns NaR for numbers outside the open interval (-1, 1).
+    pub fn atanh(self) -> Self {
+        Self::from(libm::atanh(f64::from(self)))
+    }
+
+    /// Returns `e^(self) - 1`, correctly rounded, accurate even if the number
+    /// is close to zero.
+    pub fn exp_m1(self) -> Self {
+        let x = f64::from(self);
+        let f = libm::expm1(x);
+        if f.is_infinite() {
+            Self::MAX
+        } else {
+            Self::round_correctly(f, || dd::exp_m1(DD::from(x)))
+        }
+    }
+
+    /// Returns `ln(1 + self)`, correctly rounded, accurate even if the number
+    /// is close to zero.
+    ///
+    /// Returns NaR for numbers less than or equal to -1.
+    pub fn ln_1p(self) -> Self {
+        if self <= -Self::ONE {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::round_correctly(libm::log1p(x), || dd::ln_1p(x))
+    }
+
+    /// Base-10 logarithm, correctly rounded.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    pub fn log10(self) -> Self {
+        let bits = dd::log10(f64::from(self), N, Self::ES as u32);
+        Self::from_bits((bits as u32) << (32 - N))
+    }
+
+    /// Logarithm with respect to an arbitrary base, correctly rounded.
+    ///
+    /// Returns NaR if either number is zero, negative or NaR, or if the base
+    /// is one.
+    pub fn log(self, base: Self) -> Self {
+        let bits = dd::log(f64::from(self), f64::from(base), N, Self::ES as u32);
+        Self::from_bits((bits as u32) << (32 - N))
+    }
+
+    /// Cube root, within one ulp.
+    pub fn cbrt(self) -> Self {
+        Self::from(libm::cbrt(f64::from(self)))
+    }
+
+    /// Length of the hypotenuse of a right-angle triangle with legs `self`
+    /// and `other`, within one ulp.
+    pub fn hypot(self, other: Self) -> Self {
+        Self::from(libm::hypot(f64::from(self), f64::from(other)))
+    }
+
+    /// Raises the number to a posit power, within one ulp.
+    ///
+    /// NaR is returned if either argument is NaR, for zero raised to a negative
+    /// power, and for a negative number raised to a non-integer power. Otherwise
+    /// `x.powf(0)` and `1.powf(y)` are 1 for every `x` and `y`.
+    pub fn powf(self, other: Self) -> Self {
+        if self.is_nar() || other.is_nar() || (self.is_zero() && other < Self::ZERO) {
+            return Self::NAR;
+        }
+        let f = libm::pow(f64::from(self), f64::from(other));
+        if f == 0. && !self.is_zero() {
+            // underflow of the f64 range, posits never round to zero
+            if f.is_sign_negative() {
+                -Self::MIN_POSITIVE
+            } else {
+                Self::MIN_POSITIVE
+            }
+        } else {
+            Self::saturate(f)
+        }
+    }
+
+    /// Raises the number to an integer power.
+    ///
+    /// The result is correctly rounded unless the exact power lies within about
+    /// `n^2 2^-124` (relative) of a rounding boundary, where it may be one ulp
+    /// smaller in magnitude. `x.powi(0)` is 1 for every real `x`, and NaR for
+    /// zero raised to a negative power.
+    pub fn powi(self, n: i32) -> Self {
+        let bits = crate::xfloat::powi((self.to_bits() >> (32 - N)) as u64, n, N, Self::ES as u32);
+        Self::from_bits((bits as u32) << (32 - N))
+    }
+
+    /// Rounds `f(self)` from a `libm` approximation, or if that is too close
+    /// to a rounding boundary, from an accurate double-double evaluation.
+    fn round_correctly<F>(approx: f64, accurate: F) -> Self
+    where
+        F: FnOnce() -> DD,
+    {
+        let bits = dd::round_correctly(approx, accurate, N, Self::ES as u32);
+        Self::from_bits((bits as u32) << (32 - N))
+    }
+
+    /// Rounds a result evaluated in double precision, saturating to maxpos if
+    /// the evaluation overflowed the `f64` range.
+    fn saturate(f: f64) -> Self {
+        if f == f64::INFINITY {
+            Self::MAX
+        } else if f == f64::NEG_INFINITY {
+            Self::MIN
+        } else {
+            Self::from(f)
+        }
+    }
+
+    /// Rounds a positive exponential, which never overflows to NaR nor
+    /// underflows to zero.
+    fn saturate_exp(f: f64) -> Self {
+        if f == 0. {
+            Self::from_bits(0x1 << (32 - N))
+        } else if f.is_infinite() {
+            Self::from_bits(0x_7FFF_FFFF & Self::mask())
+        } else {
+            Self::from(f)
+        }
+    }
+}
+
+#[cfg(test)]
+fn test_widths<const N: u32>() {
+    use crate::dd::reference::{self, Reference};
+    use num_bigint::BigInt;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let r = Reference::new();
+    // far beyond the saturation of the exponentials
+    let limit = BigInt::from(200) << reference::PREC;
+    let bits = |p: PxE1<{ N }>| (p.to_bits() >> (32 - N)) as u64;
+    assert!(PxE1::<{ N }>::NAR.powf(PxE1::<{ N }>::ZERO).is_nar());
+    assert!(PxE1::<{ N }>::ONE.powf(PxE1::<{ N }>::NAR).is_nar());
+    for _ in 0..crate::NTESTS8 {
+        let p_a = PxE1::<{ N }>::from_bits(rng.gen::<u32>() & PxE1::<{ N }>::mask());
+        let p_b = PxE1::<{ N }>::from_bits(rng.gen::<u32>() & PxE1::<{ N }>::mask());
+        let f_a = f64::from(p_a);
+        assert_eq!(
+            p_a.sqrt().to_bits(),
+            PxE1::<{ N }>::from(f_a.sqrt()).to_bits()
+        );
+        if p_a.is_nar() || p_b.is_nar() {
+            continue;
+        }
+        let x = reference::fixed(bits(p_a), N, 1);
+        let b = reference::fixed(bits(p_b), N, 1);
+        let clamped = x.clone().clamp(-&limit, limit.clone());
+        // checks that `p` is within `ulps` of the correctly rounded value
+        let check = |name: &str, ulps: u64, p: PxE1<{ N }>, exact: Option<reference::Exact>| {
+            let nar = bits(PxE1::<{ N }>::NAR);
+            let correct = match exact {
+                // skips results too close to a rounding boundary to tell
+                Some(value) => match reference::round(&value, N, 1) {
+                    Some(correct) => correct,
+                    None => return,
+                },
+                None => nar,
+            };
+            let d = (bits(p).wrapping_sub(correct) << (64 - N)) as i64 >> (64 - N);
+            assert!(
+                d.unsigned_abs() <= ulps && (bits(p) == nar) == (correct == nar),
+                "{}({:?}, {:?})",
+                name,
+                p_a,
+                p_b
+            );
+        };
+        let positive = p_a > PxE1::<{ N }>::ZERO;
+        let unit = p_a.abs() <= PxE1::<{ N }>::ONE;
+        check("exp", 0, p_a.exp(), Some(r.exp(&clamped)));
+        check("exp2", 0, p_a.exp2(), Some(r.exp2(&clamped)));
+        check("exp_m1", 0, p_a.exp_m1(), Some(r.exp_m1(&clamped)));
+        check("ln", 0, p_a.ln(), positive.then(|| r.ln(&x)));
+        check("log2", 0, p_a.log2(), positive.then(|| r.log2(&x)));
+        check("log10", 0, p_a.log10(), positive.then(|| r.log10(&x)));
+        let above = p_a > -PxE1::<{ N }>::ONE;
+        check("ln_1p", 0, p_a.ln_1p(), above.then(|| r.ln_1p(&x)));
+        if positive && p_b > PxE1::<{ N }>::ZERO {
+            check("log", 0, p_a.log(p_b), r.log(&x, &b));
+        }
+        check("sin", 0, p_a.sin(), Some(r.sin(&x)));
+        check("cos", 0, p_a.cos(), Some(r.cos(&x)));
+        check("tan", 0, p_a.tan(), Some(r.tan(&x)));
+        check("asin", 0, p_a.asin(), unit.then(|| r.asin(&x)));
+        check("acos", 0, p_a.acos(), unit.then(|| r.acos(&x)));
+        check("atan", 0, p_a.atan(), Some(r.atan(&x)));
+        check("atan2", 1, p_a.atan2(p_b), r.atan2(&x, &b));
+        check("sinh", 1, p_a.sinh(), Some(r.sinh(&clamped)));
+        check("cosh", 1, p_a.cosh(), Some(r.cosh(&clamped)));
+        check("tanh", 1, p_a.tanh(), Some(r.tanh(&clamped)));
+        check("asinh", 1, p_a.asinh(), Some(r.asinh(&x)));
+        check("acosh", 1, p_a.acosh(), r.acosh(&x));
+        check("atanh", 1, p_a.atanh(), r.atanh(&x));
+        check("cbrt", 1, p_a.cbrt(), Some(r.cbrt(&x)));
+        check("hypot", 1, p_a.hypot(p_b), Some(r.hypot(&x, &b)));
+        let y = f64::from(p_b);
+        let integer = libm::trunc(y) == y;
+        let power = if p_b.is_zero() || p_a == PxE1::<{ N }>::ONE {
+            Some((BigInt::from(1), BigInt::from(1), 0))
+        } else if p_a.is_zero() {
+            (y > 0.).then(|| (BigInt::from(0), BigInt::from(1), 0))
+        } else if positive {
+            Some(r.pow(&x, &b))
+        } else {
+            // negative numbers to integer powers, odd below 2^53 only
+            integer.then(|| {
+                let (num, den, e) = r.pow(&-&x, &b);
+                let odd = y.abs() < 9e15 && y as i64 % 2 != 0;
+                (if odd { -num } else { num }, den, e)
+            })
+        };
+        check("powf", 1, p_a.powf(p_b), power);
+    }
+}
+
+#[test]
+fn test_math_widths() {
+    test_widths::<9>();
+    test_widths::<12>();
+    test_widths::<16>();
+    test_widths::<20>();
+    test_widths::<24>();
+    test_widths::<28>();
+    test_widths::<31>();
+    test_widths::<32>();
+}
+
+#[test]
+fn test_math_p16e1() {
+    use crate::P16E1;
+    for i in 0..0x_0001_0000_u32 {
+        let p_a = PxE1::<16>::from_bits(i << 16);
+        let p_b = P16E1::from_bits(i as u16);
+        let bits = |p: PxE1<16>| (p.to_bits() >> 16) as u16;
+        assert_eq!(bits(p_a.sqrt()), p_b.sqrt().to_bits());
+        assert_eq!(bits(p_a.exp()), p_b.exp().to_bits());
+        assert_eq!(bits(p_a.exp2()), p_b.exp2().to_bits());
+        assert_eq!(bits(p_a.ln()), p_b.ln().to_bits());
+        assert_eq!(bits(p_a.log2()), p_b.log2().to_bits());
+        // the radian functions of `P16E1` are only within one ulp
+        let ulp = |p: PxE1<16>, q: P16E1| (bits(p) as i16).wrapping_sub(q.to_bits() as i16).abs();
+        assert!(ulp(p_a.sin(), p_b.sin()) <= 1);
+        assert!(ulp(p_a.cos(), p_b.cos()) <= 1);
+        assert!(ulp(p_a.tan(), p_b.tan()) <= 1);
+        // the roots are within one ulp of the correctly rounded `P16E1` ones
+        let j = i ^ 0x_5555;
+        let (q_a, q_b) = (PxE1::<16>::from_bits(j << 16), P16E1::from_bits(j as u16));
+        assert!(ulp(p_a.cbrt(), p_b.cbrt()) <= 1);
+        assert!(ulp(p_a.hypot(q_a), p_b.hypot(q_b)) <= 1);
+    }
+}
@@ -38,6 +38,13 @@ impl<const N: u32> ops::DivAssign for PxE1<{ N }> {
     }
 }
 
+impl<const N: u32> ops::RemAssign for PxE1<{ N }> {
+    #[inline]
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other
+    }
+}
+
 impl<const N: u32> ops::Add for PxE1<{ N }> {
     type Output = Self;
     #[inline]
@@ -117,8 +124,8 @@ impl<const N: u32> PxE1<{ N }> {
             let mut shift_right = (k_a as i16) - (k_b as i16);
             let mut frac64_b = (frac_b as u64) << 32;
 
-            //This is 4kZ + expZ; (where kZ=k_a-kB and expZ=exp-expB)
-            shift_right = (shift_right << 2) + (exp as i16) - (exp_b as i16);
+            //This is 2kZ + expZ; (where kZ=k_a-kB and expZ=exp-expB)
+            shift_right = (shift_right << 1) + (exp as i16) - (exp_b as i16);
 
             if shift_right == 0 {
                 frac64 += frac64_b;
@@ -182,12 +189,14 @@ impl<const N: u32> PxE1<{ N }> {
                     0
                 };
 
-                exp <<= 29 - reg_len;
+                if reg_len <= 29 {
+                    exp <<= 29 - reg_len;
+                }
                 let mut u_z = Self::pack_to_ui(regime, exp as u32, frac);
 
                 //n+1 frac bit is 1. Need to check if another bit is 1 too if not round to even
                 if bit_n_plus_one {
-                    let bits_more = ((0x_FFFF_FFFF_FFFF_FFFF_u64 >> N) & frac64) != 0;
+                    let bits_more = ((0x_7FFF_FFFF_FFFF_FFFF_u64 >> N) & frac64) != 0;
                     u_z += (((u_z >> (32 - N)) & 1) | (bits_more as u32)) << (32 - N);
                 }
                 u_z
@@ -233,8 +242,8 @@ impl<const N: u32> PxE1<{ N }> {
             let mut shift_right = (k_a as i16) - (k_b as i16);
             let mut frac64_b = (frac_b as u64) << 32;
 
-            //This is 4kZ + expZ; (where kZ=kA-kB and expZ=exp-expB)
-            shift_right = (shift_right << 2) + (exp as i16) - (exp_b as i16);
+            //This is 2kZ + expZ; (where kZ=kA-kB and expZ=exp-expB)
+            shift_right = (shift_right << 1) + (exp as i16) - (exp_b as i16);
 
             if shift_right > 60 {
                 return Self::from_bits(if sign { ui_a.wrapping_neg() } else { ui_a });
@@ -291,12 +300,14 @@ impl<const N: u32> PxE1<{ N }> {
                     0
                 };
 
-                exp <<= 29 - reg_len;
+                if reg_len <= 29 {
+                    exp <<= 29 - reg_len;
+                }
                 let mut u_z = Self::pack_to_ui(regime, exp as u32, frac);
 
                 //n+1 frac bit is 1. Need to check if another bit is 1 too if not round to even
                 if bit_n_plus_one {
-                    let bits_more = ((0x_FFFF_FFFF_FFFF_FFFF_u64 >> N) & frac64) != 0;
+                    let bits_more = ((0x_7FFF_FFFF_FFFF_FFFF_u64 >> N) & frac64) != 0;
                     u_z += (((u_z >> (32 - N)) & 1) | (bits_more as u32)) << (32 - N);
                 }
                 u_z
@@ -399,7 +410,9 @@ impl<const N: u32> ops::Mul for PxE1<{ N }> {
                     0
                 };
 
-                exp <<= 29 - reg_len;
+                if reg_len <= 29 {
+                    exp <<= 29 - reg_len;
+                }
                 let mut u_z = Self::pack_to_ui(regime, exp as u32, frac);
 
                 if bit_n_plus_one {
@@ -509,7 +522,9 @@ impl<const N: u32> ops::Div for PxE1<{ N }> {
                     frac = 0;
                 }
 
-                exp <<= 29 - reg_len;
+                if reg_len <= 29 {
+                    exp <<= 29 - reg_len;
+                }
                 let mut u_z = Self::pack_to_ui(regime, exp as u32, frac);
 
                 if bit_n_plus_one {
@@ -522,3 +537,38 @@ impl<const N: u32> ops::Div for PxE1<{ N }> {
         Self::from_bits(u_z.with_sign(sign_z))
     }
 }
+
+impl<const N: u32> ops::Rem for PxE1<{ N }> {
+    type Output = Self;
+    /// Remainder of the division truncated toward zero, with the sign of
+    /// `self`, like `fmod`.
+    #[inline]
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(other, crate::xfloat::QuotRounding::Trunc).1
+    }
+}
+
+#[test]
+fn test_ops_p16e1() {
+    use crate::P16E1;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let p = |i: u16| PxE1::<16>::from_bits((i as u32) << 16);
+    let bits = |p: PxE1<16>| (p.to_bits() >> 16) as u16;
+    for _ in 0..crate::NTESTS32 {
+        let (i, j, k): (u16, u16, u16) = (rng.gen(), rng.gen(), rng.gen());
+        let (p_a, p_b, p_c) = (
+            P16E1::from_bits(i),
+            P16E1::from_bits(j),
+            P16E1::from_bits(k),
+        );
+        assert_eq!(bits(p(i) + p(j)), (p_a + p_b).to_bits());
+        assert_eq!(bits(p(i) - p(j)), (p_a - p_b).to_bits());
+        assert_eq!(bits(p(i) * p(j)), (p_a * p_b).to_bits());
+        assert_eq!(bits(p(i) / p(j)), (p_a / p_b).to_bits());
+        assert_eq!(
+            bits(p(i).mul_add(p(j), p(k))),
+            p_a.mul_add(p_b, p_c).to_bits()
+        );
+    }
+}
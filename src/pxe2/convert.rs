@@ -34,190 +34,18 @@ impl<const N: u32> From<PxE2<{ N }>> for f64 {
     }
 }
 
-impl<const N: u32> From<f64> for PxE2<{ N }> {
-    #[allow(clippy::cognitive_complexity)]
-    fn from(mut float: f64) -> Self {
-        let mut reg: u32;
-        let mut frac = 0_u32;
-        let mut exp = 0_i32;
-        let mut bit_n_plus_one = false;
-        let mut bits_more = false;
-
-        if float == 0. {
-            return Self::ZERO;
-        } else if !float.is_finite() {
-            return Self::NAR;
-        }
-
-        let sign = float < 0.;
-
-        let u_z: u32 = if float == 1. {
-            0x4000_0000
-        } else if float == -1. {
-            0xC000_0000
-        } else if (float > 1.) || (float < -1.) {
-            if sign {
-                //Make negative numbers positive for easier computation
-                float = -float;
-            }
-
-            let reg_s = true;
-            reg = 1; //because k = m-1; so need to add back 1
-                     // minpos
-            if (N == 2) && (float <= 7.523_163_845_262_64_e-37) {
-                1
-            } else {
-                //regime
-                while float >= 16. {
-                    float *= 0.0625; // float/=16;
-                    reg += 1;
-                }
-                while float >= 2. {
-                    float *= 0.5;
-                    exp += 1;
-                }
-
-                let frac_length = (N - 4) as isize - (reg as isize);
-
-                if frac_length < 0 {
-                    //in both cases, reg=29 and 30, e is n+1 bit and frac are sticky bits
-                    if reg == N - 3 {
-                        bit_n_plus_one = (exp & 0x1) != 0;
-                        //exp>>=1; //taken care of by the pack algo
-                        exp &= 0x2;
-                    } else {
-                        //reg=30
-                        bit_n_plus_one = (exp >> 1) != 0;
-                        bits_more = (exp & 0x1) != 0;
-                        exp = 0;
-                    }
-                    if float != 1. {
-                        //because of hidden bit
-                        bits_more = true;
-                        frac = 0;
-                    }
-                } else {
-                    frac = crate::convert_fraction_p32(
-                        float,
-                        frac_length as u16,
-                        &mut bit_n_plus_one,
-                        &mut bits_more,
-                    );
-                }
-
-                if reg > (N - 2) {
-                    if reg_s {
-                        0x_7FFF_FFFF & Self::mask()
-                    } else {
-                        0x1 << (32 - N)
-                    }
-                } else {
-                    //rounding off fraction bits
-
-                    let regime = if reg_s { ((1 << reg) - 1) << 1 } else { 1_u32 };
-
-                    if (N == 32) && (reg == 29) {
-                        exp >>= 1;
-                    } else if reg <= 28 {
-                        exp <<= 28 - reg;
-                    }
-
-                    let mut u_z = ((regime as u32) << (30 - reg))
-                        + (exp as u32)
-                        + ((frac << (32 - N)) as u32);
-                    //minpos
-                    if (u_z == 0) && (frac > 0) {
-                        u_z = 0x1 << (32 - N);
-                    }
-                    if bit_n_plus_one {
-                        u_z += (((u_z >> (32 - N)) & 0x1) | (bits_more as u32)) << (32 - N);
-                    }
-                    u_z
-                }
-                .with_sign(sign)
-            }
-        } else if (float < 1.) || (float > -1.) {
-            if sign {
-                //Make negative numbers positive for easier computation
-                float = -float;
-            }
-
-            let reg_s = false;
-            reg = 0;
-
-            //regime
-            while float < 1. {
-                float *= 16.;
-                reg += 1;
-            }
-
-            while float >= 2. {
-                float *= 0.5;
-                exp += 1;
-            }
-
-            let frac_length = (N - 4) as isize - (reg as isize);
-            if frac_length < 0 {
-                //in both cases, reg=29 and 30, e is n+1 bit and frac are sticky bits
-                if reg == N - 3 {
-                    bit_n_plus_one = (exp & 0x1) != 0;
-                    //exp>>=1; //taken care of by the pack algo
-                    exp &= 0x2;
-                } else {
-                    //reg=30
-                    bit_n_plus_one = (exp >> 1) != 0;
-                    bits_more = (exp & 0x1) != 0;
-                    exp = 0;
-                }
-                if float != 1. {
-                    //because of hidden bit
-                    bits_more = true;
-                    frac = 0;
-                }
-            } else {
-                frac = crate::convert_fraction_p32(
-                    float,
-                    frac_length as u16,
-                    &mut bit_n_plus_one,
-                    &mut bits_more,
-                );
-            }
-
-            if reg > (N - 2) {
-                if reg_s {
-                    0x_7FFF_FFFF & Self::mask()
-                } else {
-                    0x1 << (32 - N)
-                }
-            } else {
-                //rounding off fraction bits
-
-                let regime = if reg_s { ((1 << reg) - 1) << 1 } else { 1_u32 };
-
-                if (N == 32) && (reg == 29) {
-                    exp >>= 1;
-                } else if reg <= 28 {
-                    exp <<= 28 - reg;
-                }
-
-                let mut u_z =
-                    ((regime as u32) << (30 - reg)) + (exp as u32) + ((frac << (32 - N)) as u32);
-                //minpos
-                if (u_z == 0) && (frac > 0) {
-                    u_z = 0x1 << (32 - N);
-                }
+impl<const N: u32> From<f32> for PxE2<{ N }> {
+    #[inline]
+    fn from(float: f32) -> Self {
+        Self::from(float as f64)
+    }
+}
 
-                if bit_n_plus_one {
-                    u_z += (((u_z >> (32 - N)) & 0x1) | (bits_more as u32)) << (32 - N);
-                }
-                u_z
-            }
-            .with_sign(sign)
-        } else {
-            //NaR - for NaN, INF and all other combinations
-            0x8000_0000
-        };
-        Self::from_bits(u_z)
+impl<const N: u32> From<f64> for PxE2<{ N }> {
+    #[inline]
+    fn from(float: f64) -> Self {
+        let bits = crate::convert::f64_to_posit_bits(float, N, Self::ES as u32);
+        Self::from_bits((bits as u32) << (32 - N))
     }
 }
 
@@ -304,7 +132,7 @@ fn convert_u32_to_px2bits<const N: u32>(a: u32) -> u32 {
             }
         } else if k == (N - 5) {
             ui_a = (0x_7FFF_FFFF ^ (0x_3FFF_FFFF >> k)) | (exp_a << (27 - k));
-            mask = 0x8 << (k - N);
+            mask = 0x8 << (k + 32 - N);
             if ((mask & frac_a) != 0) && ((((mask - 1) & frac_a) | (exp_a & 0x1)) != 0) {
                 //bitNPlusOne
                 ui_a += 0x_8000_0000_u32 >> (N - 1);
@@ -312,7 +140,7 @@ fn convert_u32_to_px2bits<const N: u32>(a: u32) -> u32 {
         } else {
             ui_a = ((0x_7FFF_FFFF ^ (0x_3FFF_FFFF >> k)) | (exp_a << (27 - k)) | frac_a >> (k + 4))
                 & PxE2::<{ N }>::mask();
-            mask = 0x8 << (k - N); //bitNPlusOne
+            mask = 0x8 << (k + 32 - N); //bitNPlusOne
             if ((mask & frac_a) != 0) && ((((mask - 1) & frac_a) | ((mask << 1) & frac_a)) != 0) {
                 ui_a += 0x_8000_0000_u32 >> (N - 1);
             }
@@ -423,31 +251,21 @@ fn convert_u64_to_px2bits<const N: u32>(a: u64) -> u32 {
     }
 }
 
-use crate::P32E2;
-impl<const N: u32> From<PxE2<{ N }>> for i32 {
+impl<const N: u32> From<i128> for PxE2<{ N }> {
     #[inline]
-    fn from(p_a: PxE2<{ N }>) -> Self {
-        Self::from(P32E2::from_bits(p_a.to_bits()))
+    fn from(a: i128) -> Self {
+        let bits = crate::convert::u128_to_posit_bits(a < 0, a.unsigned_abs(), N, 2);
+        Self::from_bits((bits as u32) << (32 - N))
     }
 }
 
-impl<const N: u32> From<PxE2<{ N }>> for u32 {
+impl<const N: u32> From<u128> for PxE2<{ N }> {
     #[inline]
-    fn from(p_a: PxE2<{ N }>) -> Self {
-        Self::from(P32E2::from_bits(p_a.to_bits()))
+    fn from(a: u128) -> Self {
+        let bits = crate::convert::u128_to_posit_bits(false, a, N, 2);
+        Self::from_bits((bits as u32) << (32 - N))
     }
 }
 
-impl<const N: u32> From<PxE2<{ N }>> for u64 {
-    #[inline]
-    fn from(p_a: PxE2<{ N }>) -> Self {
-        Self::from(P32E2::from_bits(p_a.to_bits()))
-    }
-}
-
-impl<const N: u32> From<PxE2<{ N }>> for i64 {
-    #[inline]
-    fn from(p_a: PxE2<{ N }>) -> Self {
-        Self::from(P32E2::from_bits(p_a.to_bits()))
-    }
-}
+// The bits of `PxE2<N>` are those of a 32-bit posit with the same value.
+crate::impl_int_convert!([const N: u32] PxE2<{ N }>, 32, 2);
@@ -1,4 +1,5 @@
 use super::PxE2;
+use crate::dd::{self, DD};
 use crate::{MulAddType, WithSign};
 
 impl<const N: u32> PxE2<{ N }> {
@@ -245,7 +246,11 @@ impl<const N: u32> PxE2<{ N }> {
                     frac_z = 0;
                 }
 
-                exp_z <<= 28 - reg_z;
+                if (N == 32) && (reg_z == 29) {
+                    exp_z >>= 1;
+                } else if reg_z <= 28 {
+                    exp_z <<= 28 - reg_z;
+                }
 
                 let mut u_z = Self::pack_to_ui(regime, exp_z as u32, frac_z);
 
@@ -352,9 +357,10 @@ impl<const N: u32> PxE2<{ N }> {
             // Assemble the result and return it.
             let mut u_a = ui_z | (exp_z << (27 - shift)) | ((frac64_z >> (5 + shift)) as u32);
             //Check if rounding bits in regime or exp and clean off unwanted bits
-            if (((0x_8000_0000_u32 >> N) & u_a) != 0)
+            // For N == 32 there are no bits past the last one to round on
+            if ((0x_8000_0000_u32.checked_shr(N).unwrap_or(0) & u_a) != 0)
                 && ((((0x_8000_0000_u32 >> (N - 1)) & u_a) != 0)
-                    || (((0x_7FFF_FFFF_u32 >> N) & u_a) != 0))
+                    || ((0x_7FFF_FFFF_u32.checked_shr(N).unwrap_or(0) & u_a) != 0))
             {
                 u_a = (u_a & Self::mask()) + (0x_8000_0000_u32 >> (N - 1));
             }
@@ -435,3 +441,464 @@ impl<const N: u32> PxE2<{ N }> {
         Self::from_bits(u_a.with_sign(sign))
     }
 }
+
+impl<const N: u32> PxE2<{ N }> {
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self::from(libm::floor(f64::from(self)))
+    }
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self::from(libm::ceil(f64::from(self)))
+    }
+    #[inline]
+    pub fn trunc(self) -> Self {
+        if self > Self::ZERO {
+            self.floor()
+        } else {
+            self.ceil()
+        }
+    }
+    #[inline]
+    pub fn fract(self) -> Self {
+        self - self.trunc()
+    }
+    #[inline]
+    pub(crate) fn div_rem(
+        self,
+        rhs: Self,
+        rounding: crate::xfloat::QuotRounding,
+    ) -> (Self, Self, i32) {
+        let bits = |p: Self| (p.to_bits() >> (32 - N)) as u64;
+        let (q, r, quo) =
+            crate::xfloat::div_rem(bits(self), bits(rhs), N, Self::ES as u32, rounding);
+        (
+            Self::from_bits((q as u32) << (32 - N)),
+            Self::from_bits((r as u32) << (32 - N)),
+            quo,
+        )
+    }
+    /// IEEE 754 remainder, `self - q * rhs` where `q` is `self / rhs` rounded
+    /// to the nearest integer, ties to even.
+    #[inline]
+    pub fn remainder(self, rhs: Self) -> Self {
+        self.div_rem(rhs, crate::xfloat::QuotRounding::Nearest).1
+    }
+    /// The `remainder` and the lowest 31 bits of the integer quotient with
+    /// the sign of `self / rhs`, like C's `remquo`.
+    #[inline]
+    pub fn remquo(self, rhs: Self) -> (Self, i32) {
+        let (_, r, quo) = self.div_rem(rhs, crate::xfloat::QuotRounding::Nearest);
+        (r, quo)
+    }
+    /// Euclidean division, the integer `q` such that
+    /// `self = q * rhs + self.rem_euclid(rhs)`, rounded to a posit.
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        self.div_rem(rhs, crate::xfloat::QuotRounding::Euclid).0
+    }
+    /// Least nonnegative remainder of `self (mod rhs)`.
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        self.div_rem(rhs, crate::xfloat::QuotRounding::Euclid).1
+    }
+}
+
+// As for `PxE1`, the elementary functions round a `libm` result to `N` bits,
+// correctly if `crate::dd` can evaluate the function near a rounding boundary
+// and within one ulp otherwise.
+impl<const N: u32> PxE2<{ N }> {
+    /// Exponential function, `e^(self)`, correctly rounded.
+    pub fn exp(self) -> Self {
+        let x = f64::from(self);
+        let f = libm::exp(x);
+        if self.is_nar() {
+            Self::NAR
+        } else if f == 0. || f.is_infinite() {
+            Self::saturate_exp(f)
+        } else {
+            Self::round_correctly(f, || dd::exp(DD::from(x)))
+        }
+    }
+
+    /// Returns `2^(self)`, correctly rounded.
+    pub fn exp2(self) -> Self {
+        let x = f64::from(self);
+        let f = libm::exp2(x);
+        if self.is_nar() {
+            Self::NAR
+        } else if f == 0. || f.is_infinite() || libm::trunc(x) == x {
+            // exact, but may be a rounding boundary in the longest regimes
+            Self::saturate_exp(f)
+        } else {
+            Self::round_correctly(f, || dd::exp(dd::LN2.mul_f64(x)))
+        }
+    }
+
+    /// Natural logarithm, correctly rounded.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    pub fn ln(self) -> Self {
+        if self <= Self::ZERO {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::round_correctly(libm::log(x), || dd::ln(x))
+    }
+
+    /// Base-2 logarithm, correctly rounded.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    pub fn log2(self) -> Self {
+        if self <= Self::ZERO {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::round_correctly(libm::log2(x), || {
+            let (e, ln_m) = dd::ln_parts(x);
+            DD::from(e as f64) + ln_m * dd::LOG2_E
+        })
+    }
+
+    /// Sine of the number in radians, correctly rounded.
+    pub fn sin(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::sin(x), || dd::sin_cos(x).0)
+    }
+
+    /// Cosine of the number in radians, correctly rounded.
+    pub fn cos(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::cos(x), || dd::sin_cos(x).1)
+    }
+
+    /// Tangent of the number in radians, correctly rounded.
+    pub fn tan(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::tan(x), || {
+            let (s, c) = dd::sin_cos(x);
+            s / c
+        })
+    }
+
+    /// Arcsine in radians, in the range [-pi/2, pi/2], correctly rounded.
+    ///
+    /// Returns NaR if the number is outside the range [-1, 1].
+    pub fn asin(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::asin(x), || dd::asin(x))
+    }
+
+    /// Arccosine in radians, in the range [0, pi], correctly rounded.
+    ///
+    /// Returns NaR if the number is outside the range [-1, 1].
+    pub fn acos(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::acos(x), || dd::acos(x))
+    }
+
+    /// Arctangent in radians, in the range [-pi/2, pi/2], correctly rounded.
+    pub fn atan(self) -> Self {
+        let x = f64::from(self);
+        Self::round_correctly(libm::atan(x), || dd::atan(DD::from(x)))
+    }
+
+    /// Four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians,
+    /// within one ulp.
+    ///
+    /// Returns NaR if both numbers are zero.
+    pub fn atan2(self, other: Self) -> Self {
+        if self.is_zero() && other.is_zero() {
+            Self::NAR
+        } else {
+            Self::from(libm::atan2(f64::from(self), f64::from(other)))
+        }
+    }
+
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    /// Hyperbolic sine, within one ulp.
+    pub fn sinh(self) -> Self {
+        Self::saturate(libm::sinh(f64::from(self)))
+    }
+
+    /// Hyperbolic cosine, within one ulp.
+    pub fn cosh(self) -> Self {
+        Self::saturate(libm::cosh(f64::from(self)))
+    }
+
+    /// Hyperbolic tangent, within one ulp.
+    pub fn tanh(self) -> Self {
+        Self::from(libm::tanh(f64::from(self)))
+    }
+
+    /// Inverse hyperbolic sine, within one ulp.
+    pub fn asinh(self) -> Self {
+        Self::from(libm::asinh(f64::from(self)))
+    }
+
+    /// Inverse hyperbolic cosine, within one ulp.
+    ///
+    /// Returns NaR for numbers less than one.
+    pub fn acosh(self) -> Self {
+        if self < Self::ONE {
+            Self::NAR
+        } else {
+            Self::from(libm::acosh(f64::from(self)))
+        }
+    }
+
+    /// Inverse hyperbolic tangent, within one ulp.
+    ///
+    /// Returns NaR for numbers outside the open interval (-1, 1).
+    pub fn atanh(self) -> Self {
+        Self::from(libm::atanh(f64::from(self)))
+    }
+
+    /// Returns `e^(self) - 1`, correctly rounded, accurate even if the number
+    /// is close to zero.
+    pub fn exp_m1(self) -> Self {
+        let x = f64::from(self);
+        let f = libm::expm1(x);
+        if f.is_infinite() {
+            Self::MAX
+        } else {
+            Self::round_correctly(f, || dd::exp_m1(DD::from(x)))
+        }
+    }
+
+    /// Returns `ln(1 + self)`, correctly rounded, accurate even if the number
+    /// is close to zero.
+    ///
+    /// Returns NaR for numbers less than or equal to -1.
+    pub fn ln_1p(self) -> Self {
+        if self <= -Self::ONE {
+            return Self::NAR;
+        }
+        let x = f64::from(self);
+        Self::round_correctly(libm::log1p(x), || dd::ln_1p(x))
+    }
+
+    /// Base-10 logarithm, correctly rounded.
+    ///
+    /// Returns NaR for zero, negative numbers and NaR.
+    pub fn log10(self) -> Self {
+        let bits = dd::log10(f64::from(self), N, Self::ES as u32);
+        Self::from_bits((bits as u32) << (32 - N))
+    }
+
+    /// Logarithm with respect to an arbitrary base, correctly rounded.
+    ///
+    /// Returns NaR if either number is zero, negative or NaR, or if the base
+    /// is one.
+    pub fn log(self, base: Self) -> Self {
+        let bits = dd::log(f64::from(self), f64::from(base), N, Self::ES as u32);
+        Self::from_bits((bits as u32) << (32 - N))
+    }
+
+    /// Cube root, within one ulp.
+    pub fn cbrt(self) -> Self {
+        Self::from(libm::cbrt(f64::from(self)))
+    }
+
+    /// Length of the hypotenuse of a right-angle triangle with legs `self`
+    /// and `other`, within one ulp.
+    pub fn hypot(self, other: Self) -> Self {
+        Self::from(libm::hypot(f64::from(self), f64::from(other)))
+    }
+
+    /// Raises the number to a posit power, within one ulp.
+    ///
+    /// NaR is returned if either argument is NaR, for zero raised to a negative
+    /// power, and for a negative number raised to a non-integer power. Otherwise
+    /// `x.powf(0)` and `1.powf(y)` are 1 for every `x` and `y`.
+    pub fn powf(self, other: Self) -> Self {
+        if self.is_nar() || other.is_nar() || (self.is_zero() && other < Self::ZERO) {
+            return Self::NAR;
+        }
+        let f = libm::pow(f64::from(self), f64::from(other));
+        if f == 0. && !self.is_zero() {
+            // underflow of the f64 range, posits never round to zero
+            if f.is_sign_negative() {
+                -Self::MIN_POSITIVE
+            } else {
+                Self::MIN_POSITIVE
+            }
+        } else {
+            Self::saturate(f)
+        }
+    }
+
+    /// Raises the number to an integer power.
+    ///
+    /// The result is correctly rounded unless the exact power lies within about
+    /// `n^2 2^-124` (relative) of a rounding boundary, where it may be one ulp
+    /// smaller in magnitude. `x.powi(0)` is 1 for every real `x`, and NaR for
+    /// zero raised to a negative power.
+    pub fn powi(self, n: i32) -> Self {
+        let bits = crate::xfloat::powi((self.to_bits() >> (32 - N)) as u64, n, N, Self::ES as u32);
+        Self::from_bits((bits as u32) << (32 - N))
+    }
+
+    /// Rounds `f(self)` from a `libm` approximation, or if that is too close
+    /// to a rounding boundary, from an accurate double-double evaluation.
+    fn round_correctly<F>(approx: f64, accurate: F) -> Self
+    where
+        F: FnOnce() -> DD,
+    {
+        let bits = dd::round_correctly(approx, accurate, N, Self::ES as u32);
+        Self::from_bits((bits as u32) << (32 - N))
+    }
+
+    /// Rounds a result evaluated in double precision, saturating to maxpos if
+    /// the evaluation overflowed the `f64` range.
+    fn saturate(f: f64) -> Self {
+        if f == f64::INFINITY {
+            Self::MAX
+        } else if f == f64::NEG_INFINITY {
+            Self::MIN
+        } else {
+            Self::from(f)
+        }
+    }
+
+    /// Rounds a positive exponential, which never overflows to NaR nor
+    /// underflows to zero.
+    fn saturate_exp(f: f64) -> Self {
+        if f == 0. {
+            Self::from_bits(0x1 << (32 - N))
+        } else if f.is_infinite() {
+            Self::from_bits(0x_7FFF_FFFF & Self::mask())
+        } else {
+            Self::from(f)
+        }
+    }
+}
+
+#[cfg(test)]
+fn test_widths<const N: u32>() {
+    use crate::dd::reference::{self, Reference};
+    use num_bigint::BigInt;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let r = Reference::new();
+    // far beyond the saturation of the exponentials
+    let limit = BigInt::from(200) << reference::PREC;
+    let bits = |p: PxE2<{ N }>| (p.to_bits() >> (32 - N)) as u64;
+    assert!(PxE2::<{ N }>::NAR.powf(PxE2::<{ N }>::ZERO).is_nar());
+    assert!(PxE2::<{ N }>::ONE.powf(PxE2::<{ N }>::NAR).is_nar());
+    for _ in 0..crate::NTESTS8 {
+        let p_a = PxE2::<{ N }>::from_bits(rng.gen::<u32>() & PxE2::<{ N }>::mask());
+        let p_b = PxE2::<{ N }>::from_bits(rng.gen::<u32>() & PxE2::<{ N }>::mask());
+        let f_a = f64::from(p_a);
+        assert_eq!(
+            p_a.sqrt().to_bits(),
+            PxE2::<{ N }>::from(f_a.sqrt()).to_bits()
+        );
+        if p_a.is_nar() || p_b.is_nar() {
+            continue;
+        }
+        let x = reference::fixed(bits(p_a), N, 2);
+        let b = reference::fixed(bits(p_b), N, 2);
+        let clamped = x.clone().clamp(-&limit, limit.clone());
+        // checks that `p` is within `ulps` of the correctly rounded value
+        let check = |name: &str, ulps: u64, p: PxE2<{ N }>, exact: Option<reference::Exact>| {
+            let nar = bits(PxE2::<{ N }>::NAR);
+            let correct = match exact {
+                // skips results too close to a rounding boundary to tell
+                Some(value) => match reference::round(&value, N, 2) {
+                    Some(correct) => correct,
+                    None => return,
+                },
+                None => nar,
+            };
+            let d = (bits(p).wrapping_sub(correct) << (64 - N)) as i64 >> (64 - N);
+            assert!(
+                d.unsigned_abs() <= ulps && (bits(p) == nar) == (correct == nar),
+                "{}({:?}, {:?})",
+                name,
+                p_a,
+                p_b
+            );
+        };
+        let positive = p_a > PxE2::<{ N }>::ZERO;
+        let unit = p_a.abs() <= PxE2::<{ N }>::ONE;
+        check("exp", 0, p_a.exp(), Some(r.exp(&clamped)));
+        check("exp2", 0, p_a.exp2(), Some(r.exp2(&clamped)));
+        check("exp_m1", 0, p_a.exp_m1(), Some(r.exp_m1(&clamped)));
+        check("ln", 0, p_a.ln(), positive.then(|| r.ln(&x)));
+        check("log2", 0, p_a.log2(), positive.then(|| r.log2(&x)));
+        check("log10", 0, p_a.log10(), positive.then(|| r.log10(&x)));
+        let above = p_a > -PxE2::<{ N }>::ONE;
+        check("ln_1p", 0, p_a.ln_1p(), above.then(|| r.ln_1p(&x)));
+        if positive && p_b > PxE2::<{ N }>::ZERO {
+            check("log", 0, p_a.log(p_b), r.log(&x, &b));
+        }
+        check("sin", 0, p_a.sin(), Some(r.sin(&x)));
+        check("cos", 0, p_a.cos(), Some(r.cos(&x)));
+        check("tan", 0, p_a.tan(), Some(r.tan(&x)));
+        check("asin", 0, p_a.asin(), unit.then(|| r.asin(&x)));
+        check("acos", 0, p_a.acos(), unit.then(|| r.acos(&x)));
+        check("atan", 0, p_a.atan(), Some(r.atan(&x)));
+        check("atan2", 1, p_a.atan2(p_b), r.atan2(&x, &b));
+        check("sinh", 1, p_a.sinh(), Some(r.sinh(&clamped)));
+        check("cosh", 1, p_a.cosh(), Some(r.cosh(&clamped)));
+        check("tanh", 1, p_a.tanh(), Some(r.tanh(&clamped)));
+        check("asinh", 1, p_a.asinh(), Some(r.asinh(&x)));
+        check("acosh", 1, p_a.acosh(), r.acosh(&x));
+        check("atanh", 1, p_a.atanh(), r.atanh(&x));
+        check("cbrt", 1, p_a.cbrt(), Some(r.cbrt(&x)));
+        check("hypot", 1, p_a.hypot(p_b), Some(r.hypot(&x, &b)));
+        let y = f64::from(p_b);
+        let integer = libm::trunc(y) == y;
+        let power = if p_b.is_zero() || p_a == PxE2::<{ N }>::ONE {
+            Some((BigInt::from(1), BigInt::from(1), 0))
+        } else if p_a.is_zero() {
+            (y > 0.).then(|| (BigInt::from(0), BigInt::from(1), 0))
+        } else if positive {
+            Some(r.pow(&x, &b))
+        } else {
+            // negative numbers to integer powers, odd below 2^53 only
+            integer.then(|| {
+                let (num, den, e) = r.pow(&-&x, &b);
+                let odd = y.abs() < 9e15 && y as i64 % 2 != 0;
+                (if odd { -num } else { num }, den, e)
+            })
+        };
+        check("powf", 1, p_a.powf(p_b), power);
+    }
+}
+
+#[test]
+fn test_math_widths() {
+    test_widths::<9>();
+    test_widths::<12>();
+    test_widths::<16>();
+    test_widths::<20>();
+    test_widths::<24>();
+    test_widths::<28>();
+    test_widths::<31>();
+}
+
+#[test]
+fn test_math_p32e2() {
+    use crate::P32E2;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS32 {
+        let p_b: P32E2 = rng.gen();
+        let p_a = PxE2::<32>::from_bits(p_b.to_bits());
+        let bits = |p: PxE2<32>| p.to_bits();
+        assert_eq!(bits(p_a.sqrt()), p_b.sqrt().to_bits());
+        assert_eq!(bits(p_a.exp()), p_b.exp().to_bits());
+        assert_eq!(bits(p_a.exp2()), p_b.exp2().to_bits());
+        assert_eq!(bits(p_a.ln()), p_b.ln().to_bits());
+        assert_eq!(bits(p_a.log2()), p_b.log2().to_bits());
+        assert_eq!(bits(p_a.sin()), p_b.sin().to_bits());
+        assert_eq!(bits(p_a.cos()), p_b.cos().to_bits());
+        assert_eq!(bits(p_a.tan()), p_b.tan().to_bits());
+    }
+}
@@ -38,6 +38,13 @@ impl<const N: u32> ops::DivAssign for PxE2<{ N }> {
     }
 }
 
+impl<const N: u32> ops::RemAssign for PxE2<{ N }> {
+    #[inline]
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other
+    }
+}
+
 impl<const N: u32> ops::Add for PxE2<{ N }> {
     type Output = Self;
     #[inline]
@@ -183,7 +190,11 @@ impl<const N: u32> PxE2<{ N }> {
                 }
                 frac_a &= Self::mask();
 
-                exp_a <<= 28 - reg_a;
+                if (N == 32) && (reg_a == 29) {
+                    exp_a >>= 1;
+                } else if reg_a <= 28 {
+                    exp_a <<= 28 - reg_a;
+                }
                 let mut u_z = Self::pack_to_ui(regime, exp_a as u32, frac_a);
 
                 //n+1 frac bit is 1. Need to check if another bit is 1 too if not round to even
@@ -306,7 +317,11 @@ impl<const N: u32> PxE2<{ N }> {
                 }
                 frac_a &= Self::mask();
 
-                exp_a <<= 28 - reg_a;
+                if (N == 32) && (reg_a == 29) {
+                    exp_a >>= 1;
+                } else if reg_a <= 28 {
+                    exp_a <<= 28 - reg_a;
+                }
                 let mut u_z = Self::pack_to_ui(regime, exp_a as u32, frac_a);
 
                 //n+1 frac bit is 1. Need to check if another bit is 1 too if not round to even
@@ -426,7 +441,11 @@ impl<const N: u32> ops::Mul for PxE2<{ N }> {
                     frac_a = 0;
                 }
 
-                exp_a <<= 28 - reg_a;
+                if (N == 32) && (reg_a == 29) {
+                    exp_a >>= 1;
+                } else if reg_a <= 28 {
+                    exp_a <<= 28 - reg_a;
+                }
                 let mut u_z = Self::pack_to_ui(regime, exp_a as u32, frac_a);
 
                 if bit_n_plus_one {
@@ -508,7 +527,7 @@ impl<const N: u32> ops::Div for PxE2<{ N }> {
             } else {
                 //remove carry and rcarry bits and shift to correct position
                 let frac64_z = (frac64_z & 0x_3FFF_FFFF) as u32;
-                frac_a = frac64_z >> (reg_a + 2);
+                frac_a = frac64_z.checked_shr(reg_a + 2).unwrap_or(0);
 
                 //regime length is smaller than length of posit
                 let mut bit_n_plus_one = false;
@@ -547,7 +566,11 @@ impl<const N: u32> ops::Div for PxE2<{ N }> {
                     frac_a = 0;
                 }
 
-                exp_a <<= 28 - reg_a;
+                if (N == 32) && (reg_a == 29) {
+                    exp_a >>= 1;
+                } else if reg_a <= 28 {
+                    exp_a <<= 28 - reg_a;
+                }
                 let mut u_z = Self::pack_to_ui(regime, exp_a as u32, frac_a);
 
                 if bit_n_plus_one {
@@ -560,3 +583,13 @@ impl<const N: u32> ops::Div for PxE2<{ N }> {
         Self::from_bits(u_z.with_sign(sign_z))
     }
 }
+
+impl<const N: u32> ops::Rem for PxE2<{ N }> {
+    type Output = Self;
+    /// Remainder of the division truncated toward zero, with the sign of
+    /// `self`, like `fmod`.
+    #[inline]
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(other, crate::xfloat::QuotRounding::Trunc).1
+    }
+}
@@ -5,12 +5,17 @@ mod convert;
 mod math;
 mod ops;
 
+/// Standard quire of `P16E1`: a 128-bit two's complement fixed-point number
+/// that accumulates sums of products without rounding.
+///
+/// The least significant bit is `minpos^2 = 2^-56`, and 15 carry bits lie
+/// above `maxpos^2`; a sum that overflows them makes the quire NaR.
 #[derive(Clone, Debug)]
 pub struct Q16E1(i128);
 
 impl Q16E1 {
     pub const ZERO: Self = Self(0);
-    pub const ONE: Self = Self(0x_0100_0000_0000_0000_0000_0000_0000_0000);
+    pub const ONE: Self = Self(0x_0000_0000_0000_0000_0100_0000_0000_0000);
     pub const NAR: Self = Self(-0x_8000_0000_0000_0000_0000_0000_0000_0000);
 
     #[inline]
@@ -72,6 +77,41 @@ impl Q16E1 {
         self.0 = self.0.wrapping_neg();
     }
 
+    /// The quire as 64-bit limbs, most significant first, for the
+    /// conversions.
+    fn to_limbs(&self) -> [u64; 2] {
+        [(self.0 >> 64) as u64, self.0 as u64]
+    }
+
+    fn from_limbs(limbs: [u64; 2]) -> Self {
+        Self((((limbs[0] as u128) << 64) | limbs[1] as u128) as i128)
+    }
+
+    /// Multiplies the quire by `2^n` exactly.
+    ///
+    /// Returns `None` if the product is not representable: if set bits would be
+    /// shifted out below the least significant bit, or the result would not fit
+    /// in the quire. NaR stays NaR.
+    pub fn checked_mul_pow2(&self, n: i32) -> Option<Self> {
+        if self.is_nar() {
+            return Some(Self::NAR);
+        }
+        let shift = |x: i128, n: i32| {
+            if n >= 0 {
+                x.checked_shl(n as u32).unwrap_or(0)
+            } else {
+                x.checked_shr(n.unsigned_abs()).unwrap_or(x >> 127)
+            }
+        };
+        let z = shift(self.0, n);
+        // the shift is exact if and only if it can be undone
+        if shift(z, n.saturating_neg()) == self.0 && z != Self::NAR.0 {
+            Some(Self(z))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     pub fn into_two_posits(mut self) -> (P16E1, P16E1) {
         let p1 = self.to_posit();
@@ -87,6 +127,25 @@ impl Q16E1 {
         self -= p2;
         (p1, p2, self.to_posit())
     }
+
+    /// Splits the quire into the posits of its expansion: each one is the
+    /// remainder of the previous ones rounded to a posit.
+    #[inline]
+    pub fn into_posits(self) -> crate::IntoPosits<P16E1, Self> {
+        crate::IntoPosits::new(self)
+    }
+
+    /// The first `K` posits of the expansion, padded with zeros.
+    #[inline]
+    pub fn to_posits<const K: usize>(&self) -> [P16E1; K] {
+        crate::expansion::to_posits(self.clone())
+    }
+
+    /// Sums posits exactly, which reloads an expansion.
+    #[inline]
+    pub fn from_posits<I: IntoIterator<Item = P16E1>>(posits: I) -> Self {
+        crate::expansion::from_posits(posits)
+    }
 }
 
 impl crate::Quire<P16E1> for Q16E1 {
@@ -126,9 +185,52 @@ impl crate::Quire<P16E1> for Q16E1 {
     }
 }
 
-use core::fmt;
-impl fmt::Display for Q16E1 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", f64::from(self.clone().to_posit()))
-    }
+crate::impl_quire_fmt!(Q16E1, 128, 56);
+
+#[test]
+fn test_quire_fmt() {
+    use crate::format::tests::Buf;
+    crate::format::tests::test_quire_fmt(128, 56, Q16E1::to_limbs, Q16E1::from_limbs);
+    let q = Q16E1::from(P16E1::from(-1.5));
+    assert_eq!(Buf::from_fmt(format_args!("{}", q)).as_str(), "-1.5");
+    assert_eq!(Buf::from_fmt(format_args!("{:x}", q)).as_str(), "-1.8");
+    assert_eq!(
+        Buf::from_fmt(format_args!("{:+#x}", Q16E1::ONE)).as_str(),
+        "+0x1"
+    );
+    assert_eq!(Buf::from_fmt(format_args!("{:>6.2}", q)).as_str(), " -1.50");
+    assert_eq!(Buf::from_fmt(format_args!("{:e}", q)).as_str(), "-1.5e0");
+    assert_eq!(
+        Buf::from_fmt(format_args!("{}", Q16E1::NAR)).as_str(),
+        "NaR"
+    );
+    assert_eq!(
+        Buf::from_fmt(format_args!("{:x}", Q16E1::NAR)).as_str(),
+        "NaR"
+    );
+    let mut min = Q16E1::init();
+    min += (P16E1::MIN_POSITIVE, P16E1::MIN_POSITIVE);
+    assert_eq!(
+        Buf::from_fmt(format_args!("{}", min)).as_str(),
+        "0.00000000000000001387778780781445675529539585113525390625"
+    );
+    min.neg();
+    let neg_min = Buf::from_fmt(format_args!("{:#x}", min));
+    assert_eq!(neg_min.as_str(), "-0x0.00000000000001");
+    let p = |s: &str| s.parse::<Q16E1>().unwrap();
+    assert_eq!(p("1").to_bits(), Q16E1::ONE.to_bits());
+    assert_eq!(p("-0x1.8p0").to_bits(), q.to_bits());
+    assert!(p("nar").is_nar());
+    assert!(p("1e40").is_nar());
+    assert!(p("-1e40").is_nar());
+    assert!(p("0x1p-57").is_zero());
+    assert!(p("1e-999999999999999").is_zero());
+    assert_eq!(
+        "".parse::<Q16E1>().err(),
+        Some(crate::ParsePositError::Empty)
+    );
+    assert_eq!(
+        "1x".parse::<Q16E1>().err(),
+        Some(crate::ParsePositError::Invalid)
+    );
 }
@@ -3,6 +3,8 @@ use crate::WithSign;
 use crate::P16E1;
 use core::convert::From;
 
+crate::impl_quire_convert!(Q16E1, 128, 56);
+
 impl From<P16E1> for Q16E1 {
     #[inline]
     fn from(a: P16E1) -> Self {
@@ -113,3 +115,17 @@ impl From<&Q16E1> for P16E1 {
         Self::from_bits(u_a.with_sign(sign))
     }
 }
+
+#[test]
+fn test_quire_convert() {
+    use crate::PositConversionError;
+    use core::convert::TryFrom;
+    use num_traits::FromPrimitive;
+    crate::limbs::test_convert(128, 56, Q16E1::to_limbs, Q16E1::from_limbs);
+    assert_eq!(Q16E1::from(P16E1::ONE).to_bits(), Q16E1::ONE.to_bits());
+    assert_eq!(Q16E1::from_f64(1.).unwrap().to_bits(), Q16E1::ONE.to_bits());
+    assert_eq!(f64::from(&Q16E1::ONE), 1.);
+    assert!(f64::from(Q16E1::NAR).is_nan());
+    assert!(Q16E1::from_f64(f64::NAN).unwrap().is_nar());
+    assert_eq!(i64::try_from(&Q16E1::NAR), Err(PositConversionError::NaR));
+}
@@ -4,6 +4,7 @@ use core::ops;
 
 crate::quire_add_sub!(P16E1, Q16E1);
 crate::quire_add_sub_array!(P16E1, Q16E1, 1, 2, 3, 4);
+crate::quire_add_sub_quire!(Q16E1);
 
 pub(super) fn fdp(q: &mut Q16E1, mut ui_a: u16, mut ui_b: u16, plus: bool) {
     let u_z1 = q.to_bits();
@@ -60,12 +61,13 @@ pub(super) fn fdp(q: &mut Q16E1, mut ui_a: u16, mut ui_b: u16, plus: bool) {
     if !(sign_z2 ^ plus) {
         u_z2 = u_z2.wrapping_neg();
     }
-    //Addition
-    let u_z = u_z2.wrapping_add(u_z1);
-
-    //Exception handling for NaR
-    let q_z = Q16E1::from_bits(u_z);
-    *q = if q_z.is_nar() { Q16E1::ZERO } else { q_z }
+    //Addition, NaR if it overflows the carry bits
+    let (u_z, overflow) = (u_z1 as i128).overflowing_add(u_z2 as i128);
+    *q = if overflow {
+        Q16E1::NAR
+    } else {
+        Q16E1::from_bits(u_z as u128)
+    }
 }
 
 pub(super) fn fdp_one(q: &mut Q16E1, mut ui_a: u16, plus: bool) {
@@ -114,12 +116,31 @@ pub(super) fn fdp_one(q: &mut Q16E1, mut ui_a: u16, plus: bool) {
     if !(sign_a ^ plus) {
         u_z2 = u_z2.wrapping_neg();
     }
-    //Addition
-    let u_z = u_z2.wrapping_add(u_z1);
+    //Addition, NaR if it overflows the carry bits
+    let (u_z, overflow) = (u_z1 as i128).overflowing_add(u_z2 as i128);
+    *q = if overflow {
+        Q16E1::NAR
+    } else {
+        Q16E1::from_bits(u_z as u128)
+    }
+}
 
-    //Exception handling for NaR
-    let q_z = Q16E1::from_bits(u_z);
-    *q = if q_z.is_nar() { Q16E1::ZERO } else { q_z }
+pub(super) fn add_quire(q: &mut Q16E1, other: &Q16E1, plus: bool) {
+    if q.is_nar() || other.is_nar() {
+        *q = Q16E1::NAR;
+        return;
+    }
+    let (u_z1, u_z2) = (q.to_bits() as i128, other.to_bits() as i128);
+    let (u_z, overflow) = if plus {
+        u_z1.overflowing_add(u_z2)
+    } else {
+        u_z1.overflowing_sub(u_z2)
+    };
+    *q = if overflow {
+        Q16E1::NAR
+    } else {
+        Q16E1::from_bits(u_z as u128)
+    }
 }
 
 #[cfg(test)]
@@ -170,3 +191,89 @@ fn test_quire_mul_sub() {
         );
     }
 }
+
+#[test]
+fn test_quire_add_quire() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let mut q_a = Q16E1::init();
+        let mut q_b = Q16E1::init();
+        q_a += (rng.gen::<P16E1>(), rng.gen::<P16E1>());
+        q_b -= (rng.gen::<P16E1>(), rng.gen::<P16E1>());
+        if q_a.is_nar() || q_b.is_nar() {
+            continue;
+        }
+        let (a, b) = (q_a.0, q_b.0);
+        assert_eq!((q_a.clone() + &q_b).0, a + b);
+        assert_eq!((q_a.clone() - q_b.clone()).0, a - b);
+        let mut q = q_a.clone();
+        q += &q_b;
+        q -= &q_b;
+        assert_eq!(q.0, a);
+    }
+    assert!((Q16E1::NAR + Q16E1::ONE).is_nar());
+    assert!((Q16E1::ONE - Q16E1::NAR).is_nar());
+}
+
+#[test]
+fn test_quire_mul_pow2() {
+    use num_bigint::BigInt;
+    use num_traits::{ToPrimitive, Zero};
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let max = BigInt::from(1) << 127;
+    for _ in 0..crate::NTESTS16 {
+        let mut q = Q16E1::init();
+        q += (rng.gen::<P16E1>(), rng.gen::<P16E1>());
+        if q.is_nar() {
+            continue;
+        }
+        let n = rng.gen_range(-140, 140);
+        let v = BigInt::from(q.0);
+        let expected = if n >= 0 {
+            Some(&v << n as usize)
+        } else if (&v % (BigInt::from(1) << -n as usize)).is_zero() {
+            Some(&v >> -n as usize)
+        } else {
+            None
+        }
+        .filter(|z| *z < max && *z > -&max);
+        assert_eq!(
+            q.checked_mul_pow2(n).map(|z| z.0),
+            expected.map(|z| z.to_i128().unwrap()),
+            "{:?} {}",
+            q,
+            n
+        );
+    }
+    assert!(Q16E1::NAR.checked_mul_pow2(-3).unwrap().is_nar());
+    assert!(Q16E1::ONE.checked_mul_pow2(i32::MIN).is_none());
+    assert_eq!(Q16E1::ONE.checked_mul_pow2(-56).unwrap().0, 1);
+}
+
+#[test]
+fn test_quire_overflow() {
+    // the 15 carry bits hold 2^15 - 1 products of maxpos
+    let mut q = Q16E1::init();
+    for _ in 0..0x7fff {
+        q += (P16E1::MAX, P16E1::MAX);
+    }
+    assert_eq!(q.0, 0x7fff << 112);
+    assert_eq!(q.to_posit(), P16E1::MAX);
+    let full = q.clone();
+    q += (P16E1::MAX, P16E1::MAX);
+    assert!(q.is_nar());
+    q -= (P16E1::MAX, P16E1::MAX);
+    assert!(q.is_nar());
+    assert!((full.clone() + &full).is_nar());
+    assert!(!(full.clone() + Q16E1::from(P16E1::ONE)).is_nar());
+    // the most negative value, -2^127, is the bit pattern of NaR
+    let mut q = full;
+    q.neg();
+    assert_eq!(q.to_posit(), P16E1::MIN);
+    q -= (P16E1::MIN_POSITIVE, P16E1::MIN_POSITIVE);
+    assert!(!q.is_nar());
+    q -= (P16E1::MAX, P16E1::MAX);
+    assert!(q.is_nar());
+}
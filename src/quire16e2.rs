@@ -0,0 +1,168 @@
+use crate::P16E2;
+
+mod convert;
+mod math;
+mod ops;
+
+const LIMBS: usize = 4;
+
+/// Bit of the quire whose weight is one.
+const ONE_BIT: i32 = 112;
+
+/// Standard quire of `P16E2`: a 256-bit two's complement fixed-point number
+/// that accumulates sums of products without rounding.
+///
+/// The least significant bit is `minpos^2 = 2^-112`, and 31 carry bits lie
+/// above `maxpos^2`; a sum that overflows them makes the quire NaR. The limbs
+/// are stored most significant first.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Q16E2([u64; LIMBS]);
+
+impl Q16E2 {
+    pub const ZERO: Self = Self([0; LIMBS]);
+    pub const ONE: Self = Self::bit(ONE_BIT as usize);
+    pub const NAR: Self = Self::bit(LIMBS * 64 - 1);
+
+    const fn bit(i: usize) -> Self {
+        let mut bits = [0; LIMBS];
+        bits[LIMBS - 1 - i / 64] = 1 << (i % 64);
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn init() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    pub fn from_posit(p: P16E2) -> Self {
+        Self::from(p)
+    }
+
+    #[inline]
+    pub const fn from_bits(v: [u64; LIMBS]) -> Self {
+        Self(v)
+    }
+
+    #[inline]
+    pub const fn to_bits(&self) -> [u64; LIMBS] {
+        self.0
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+
+    #[inline]
+    pub fn is_nar(&self) -> bool {
+        *self == Self::NAR
+    }
+
+    #[inline]
+    pub fn add_product(&mut self, p_a: P16E2, p_b: P16E2) {
+        ops::fdp(self, p_a.to_bits(), p_b.to_bits(), true);
+    }
+
+    #[inline]
+    pub fn sub_product(&mut self, p_a: P16E2, p_b: P16E2) {
+        ops::fdp(self, p_a.to_bits(), p_b.to_bits(), false);
+    }
+
+    #[inline]
+    pub fn to_posit(&self) -> P16E2 {
+        P16E2::from(self)
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::ZERO;
+    }
+
+    /// Negates the quire, NaR stays NaR.
+    #[inline]
+    pub fn neg(&mut self) {
+        crate::limbs::neg(&mut self.0);
+    }
+
+    #[inline]
+    pub fn into_two_posits(mut self) -> (P16E2, P16E2) {
+        let p1 = self.to_posit();
+        self -= p1;
+        (p1, self.to_posit())
+    }
+
+    #[inline]
+    pub fn into_three_posits(mut self) -> (P16E2, P16E2, P16E2) {
+        let p1 = self.to_posit();
+        self -= p1;
+        let p2 = self.to_posit();
+        self -= p2;
+        (p1, p2, self.to_posit())
+    }
+}
+
+impl crate::Quire<P16E2> for Q16E2 {
+    type Bits = [u64; LIMBS];
+    fn init() -> Self {
+        Self::init()
+    }
+    fn from_posit(p: P16E2) -> Self {
+        Self::from_posit(p)
+    }
+    fn to_posit(&self) -> P16E2 {
+        Self::to_posit(self)
+    }
+    fn from_bits(v: Self::Bits) -> Self {
+        Self::from_bits(v)
+    }
+    fn to_bits(&self) -> Self::Bits {
+        Self::to_bits(self)
+    }
+    fn is_zero(&self) -> bool {
+        Self::is_zero(self)
+    }
+    fn is_nar(&self) -> bool {
+        Self::is_nar(self)
+    }
+    fn add_product(&mut self, p_a: P16E2, p_b: P16E2) {
+        Self::add_product(self, p_a, p_b)
+    }
+    fn sub_product(&mut self, p_a: P16E2, p_b: P16E2) {
+        Self::sub_product(self, p_a, p_b)
+    }
+    fn clear(&mut self) {
+        Self::clear(self)
+    }
+    fn neg(&mut self) {
+        Self::neg(self)
+    }
+}
+
+use core::fmt;
+impl fmt::Display for Q16E2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", f64::from(self.to_posit()))
+    }
+}
+
+#[test]
+fn test_consts() {
+    use crate::MathConsts;
+    assert_eq!(Q16E2::from(P16E2::ONE), Q16E2::ONE);
+    assert_eq!(Q16E2::ONE.to_posit(), P16E2::ONE);
+    assert!(Q16E2::NAR.is_nar());
+    assert_eq!(Q16E2::NAR.to_posit(), P16E2::NAR);
+    // the quire constants round to the posit constants
+    let consts: [(Q16E2, P16E2); 6] = [
+        (MathConsts::E, MathConsts::E),
+        (MathConsts::PI, MathConsts::PI),
+        (MathConsts::LN_2, MathConsts::LN_2),
+        (MathConsts::SQRT_2, MathConsts::SQRT_2),
+        (MathConsts::LOG10_2, MathConsts::LOG10_2),
+        (MathConsts::FRAC_1_PI, MathConsts::FRAC_1_PI),
+    ];
+    for (q, p) in consts.iter() {
+        assert_eq!(q.to_posit(), *p);
+    }
+}
@@ -0,0 +1,47 @@
+use super::{ONE_BIT, Q16E2};
+use crate::P16E2;
+use core::convert::From;
+
+impl From<P16E2> for Q16E2 {
+    #[inline]
+    fn from(a: P16E2) -> Self {
+        let mut q = Self::ZERO;
+        q += a;
+        q
+    }
+}
+
+impl From<Q16E2> for P16E2 {
+    #[inline]
+    fn from(q_a: Q16E2) -> Self {
+        (&q_a).into()
+    }
+}
+
+impl From<&Q16E2> for P16E2 {
+    fn from(q_a: &Q16E2) -> Self {
+        if q_a.is_zero() {
+            return Self::ZERO;
+        } else if q_a.is_nar() {
+            return Self::NAR;
+        }
+        let (sign, scale, frac, sticky) = crate::limbs::unpack(&q_a.0, ONE_BIT);
+        Self::encode(sign, scale, frac, sticky)
+    }
+}
+
+#[test]
+fn test_quire_roundtrip() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let p_a: P16E2 = rng.gen();
+        let q = Q16E2::from(p_a);
+        assert_eq!(q.to_posit(), p_a);
+        let mut r = q.clone();
+        r.neg();
+        assert_eq!(r.to_posit(), -p_a);
+        r += p_a;
+        assert!(r.is_zero());
+    }
+}
@@ -0,0 +1,23 @@
+use super::Q16E2;
+
+// The constants are rounded to the nearest multiple of 2^-112.
+impl crate::MathConsts for Q16E2 {
+    const E: Self = Self([0, 0, 0x_0002_b7e1_5162_8aed, 0x_2a6a_bf71_5880_9cf5]);
+    const FRAC_1_PI: Self = Self([0, 0, 0x_0000_517c_c1b7_2722, 0x_0a94_fe13_abe8_fa9a]);
+    const FRAC_1_SQRT_2: Self = Self([0, 0, 0x_0000_b504_f333_f9de, 0x_6484_597d_89b3_754b]);
+    const FRAC_2_PI: Self = Self([0, 0, 0x_0000_a2f9_836e_4e44, 0x_1529_fc27_57d1_f535]);
+    const FRAC_2_SQRT_PI: Self = Self([0, 0, 0x_0001_20dd_7504_29b6, 0x_d11a_e3a9_14fe_d7fe]);
+    const FRAC_PI_2: Self = Self([0, 0, 0x_0001_921f_b544_42d1, 0x_8469_898c_c517_01b8]);
+    const FRAC_PI_3: Self = Self([0, 0, 0x_0001_0c15_2382_d736, 0x_5846_5bb3_2e0f_567b]);
+    const FRAC_PI_4: Self = Self([0, 0, 0x_0000_c90f_daa2_2168, 0x_c234_c4c6_628b_80dc]);
+    const FRAC_PI_6: Self = Self([0, 0, 0x_0000_860a_91c1_6b9b, 0x_2c23_2dd9_9707_ab3d]);
+    const FRAC_PI_8: Self = Self([0, 0, 0x_0000_6487_ed51_10b4, 0x_611a_6263_3145_c06e]);
+    const LN_10: Self = Self([0, 0, 0x_0002_4d76_3776_aaa2, 0x_b05b_a95b_58ae_0b4c]);
+    const LN_2: Self = Self([0, 0, 0x_0000_b172_17f7_d1cf, 0x_79ab_c9e3_b398_03f3]);
+    const LOG10_E: Self = Self([0, 0, 0x_0000_6f2d_ec54_9b94, 0x_38ca_9aad_d557_d69a]);
+    const LOG2_E: Self = Self([0, 0, 0x_0001_7154_7652_b82f, 0x_e177_7d0f_fda0_d23a]);
+    const PI: Self = Self([0, 0, 0x_0003_243f_6a88_85a3, 0x_08d3_1319_8a2e_0370]);
+    const SQRT_2: Self = Self([0, 0, 0x_0001_6a09_e667_f3bc, 0x_c908_b2fb_1366_ea95]);
+    const LOG2_10: Self = Self([0, 0, 0x_0003_5269_e12f_346e, 0x_2bf9_24af_dbfd_36bf]);
+    const LOG10_2: Self = Self([0, 0, 0x_0000_4d10_4d42_7de7, 0x_fbcc_47c4_acd6_05be]);
+}
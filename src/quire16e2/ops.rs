@@ -0,0 +1,119 @@
+use super::{ONE_BIT, Q16E2};
+use crate::P16E2;
+use core::ops;
+
+crate::quire_add_sub!(P16E2, Q16E2);
+crate::quire_add_sub_array!(P16E2, Q16E2, 1, 2, 3, 4);
+
+pub(super) fn fdp(q: &mut Q16E2, ui_a: u16, ui_b: u16, plus: bool) {
+    let (p_a, p_b) = (P16E2::from_bits(ui_a), P16E2::from_bits(ui_b));
+    if q.is_nar() || p_a.is_nar() || p_b.is_nar() {
+        *q = Q16E2::NAR;
+        return;
+    } else if p_a.is_zero() || p_b.is_zero() {
+        return;
+    }
+    if crate::limbs::add_product(&mut q.0, ONE_BIT, p_a.unpack(), p_b.unpack(), plus) {
+        *q = Q16E2::NAR;
+    }
+}
+
+pub(super) fn fdp_one(q: &mut Q16E2, ui_a: u16, plus: bool) {
+    fdp(q, ui_a, P16E2::ONE.to_bits(), plus);
+}
+
+/// Exact value `m * 2^e` of the quire.
+#[cfg(test)]
+fn value(q: &Q16E2) -> (num_bigint::BigInt, i32) {
+    (crate::limbs::value(&q.0), -ONE_BIT)
+}
+
+#[test]
+fn test_quire_dot() {
+    use crate::p64e2::reference::{self, round};
+    use num_bigint::BigInt;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let mut q = Q16E2::init();
+        let mut sum = BigInt::from(0);
+        let n = rng.gen_range(1, 8);
+        for i in 0..n {
+            let p_a: P16E2 = rng.gen();
+            // products close to the previous ones exercise cancellation
+            let p_b: P16E2 = if i % 2 == 1 && rng.gen() {
+                let (m, e) = value(&q);
+                -P16E2::from_bits(round(&m, &1.into(), e, 16, 2) as u16) / p_a
+            } else {
+                rng.gen()
+            };
+            if p_b.is_nar() {
+                continue;
+            }
+            let plus = rng.gen();
+            if plus {
+                q += (p_a, p_b);
+            } else {
+                q -= (p_a, p_b);
+            }
+            let (a, ea) = reference::value(p_a.to_bits() as u128, 16, 2);
+            let (b, eb) = reference::value(p_b.to_bits() as u128, 16, 2);
+            let prod = (a * b) << (ea + eb + ONE_BIT) as usize;
+            if plus {
+                sum += prod;
+            } else {
+                sum -= prod;
+            }
+            assert_eq!(value(&q), (sum.clone(), -ONE_BIT));
+        }
+        assert_eq!(
+            q.to_posit().to_bits(),
+            round(&sum, &1.into(), -ONE_BIT, 16, 2) as u16
+        );
+    }
+}
+
+#[test]
+fn test_quire_extremes() {
+    use num_bigint::BigInt;
+    let mut q = Q16E2::init();
+    q += (P16E2::MIN_POSITIVE, P16E2::MIN_POSITIVE);
+    assert_eq!(value(&q).0, BigInt::from(1));
+    assert_eq!(q.to_posit(), P16E2::MIN_POSITIVE);
+    q -= (P16E2::MAX, P16E2::MAX);
+    assert_eq!(value(&q).0, 1 - (BigInt::from(1) << (2 * ONE_BIT) as usize));
+    // the carry bits hold 2^31 - 1 products of maxpos
+    let mut q = Q16E2::init();
+    for _ in 0..1000 {
+        q += (P16E2::MAX, P16E2::MAX);
+    }
+    assert_eq!(q.to_posit(), P16E2::MAX);
+    q += P16E2::ONE;
+    for _ in 0..1000 {
+        q -= (P16E2::MAX, P16E2::MAX);
+    }
+    assert_eq!(q, Q16E2::ONE);
+    q.neg();
+    assert_eq!(q.to_posit(), -P16E2::ONE);
+    q += (P16E2::NAR, P16E2::ONE);
+    assert!(q.is_nar());
+    q += P16E2::ONE;
+    assert!(q.is_nar());
+}
+
+#[test]
+fn test_quire_overflow() {
+    // the largest value that fits and the bit below the most negative one
+    let top = (1 << 63) - 1;
+    let mut q = Q16E2::from_bits([top, u64::MAX, u64::MAX, u64::MAX]);
+    q -= (P16E2::MIN_POSITIVE, P16E2::MIN_POSITIVE);
+    q += (P16E2::MIN_POSITIVE, P16E2::MIN_POSITIVE);
+    assert!(!q.is_nar());
+    q += (P16E2::MIN_POSITIVE, P16E2::MIN_POSITIVE);
+    assert!(q.is_nar());
+    q -= (P16E2::MAX, P16E2::MAX);
+    assert!(q.is_nar());
+    let mut q = Q16E2::from_bits([1 << 63, 0, 0, 1]);
+    q -= (P16E2::MAX, P16E2::MAX);
+    assert!(q.is_nar());
+}
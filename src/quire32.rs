@@ -7,6 +7,12 @@ mod convert;
 mod math;
 mod ops;
 
+/// Standard quire of `P32E2`: a 512-bit two's complement fixed-point number
+/// that accumulates sums of products without rounding.
+///
+/// The least significant bit is `minpos^2 = 2^-240`, and 31 carry bits lie
+/// above `maxpos^2`; a sum that overflows them makes the quire NaR. The words
+/// are stored most significant first.
 #[derive(Clone, Debug)]
 pub struct Q32E2(i64, u64, u64, u64, u64, u64, u64, u64);
 
@@ -71,7 +77,40 @@ impl Q32E2 {
 
     #[inline]
     pub fn neg(&mut self) {
-        self.0 = self.0.wrapping_neg();
+        let mut u_z = self.to_bits();
+        crate::limbs::neg(&mut u_z);
+        *self = Self::from_bits(u_z);
+    }
+
+    /// The quire as 64-bit limbs, most significant first, for the
+    /// conversions.
+    fn to_limbs(&self) -> [u64; 8] {
+        self.to_bits()
+    }
+
+    fn from_limbs(limbs: [u64; 8]) -> Self {
+        Self::from_bits(limbs)
+    }
+
+    /// Multiplies the quire by `2^n` exactly.
+    ///
+    /// Returns `None` if the product is not representable: if set bits would be
+    /// shifted out below the least significant bit, or the result would not fit
+    /// in the quire. NaR stays NaR.
+    pub fn checked_mul_pow2(&self, n: i32) -> Option<Self> {
+        if self.is_nar() {
+            return Some(Self::NAR);
+        }
+        let mut u_z = self.to_bits();
+        if !crate::limbs::shift(&mut u_z, n) {
+            return None;
+        }
+        let q_z = Self::from_bits(u_z);
+        if q_z.is_nar() {
+            None
+        } else {
+            Some(q_z)
+        }
     }
 
     #[inline]
@@ -89,6 +128,25 @@ impl Q32E2 {
         self -= p2;
         (p1, p2, self.to_posit())
     }
+
+    /// Splits the quire into the posits of its expansion: each one is the
+    /// remainder of the previous ones rounded to a posit.
+    #[inline]
+    pub fn into_posits(self) -> crate::IntoPosits<P32E2, Self> {
+        crate::IntoPosits::new(self)
+    }
+
+    /// The first `K` posits of the expansion, padded with zeros.
+    #[inline]
+    pub fn to_posits<const K: usize>(&self) -> [P32E2; K] {
+        crate::expansion::to_posits(self.clone())
+    }
+
+    /// Sums posits exactly, which reloads an expansion.
+    #[inline]
+    pub fn from_posits<I: IntoIterator<Item = P32E2>>(posits: I) -> Self {
+        crate::expansion::from_posits(posits)
+    }
 }
 
 impl crate::Quire<P32E2> for Q32E2 {
@@ -170,9 +228,51 @@ impl<const N: u32> crate::Quire<PxE2<{ N }>> for Q32E2 {
     }
 }
 
-use core::fmt;
-impl fmt::Display for Q32E2 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", f64::from(self.clone().to_posit()))
-    }
+crate::impl_quire_fmt!(Q32E2, 512, 240);
+
+#[test]
+fn test_quire_fmt() {
+    use crate::format::tests::Buf;
+    crate::format::tests::test_quire_fmt(512, 240, Q32E2::to_limbs, Q32E2::from_limbs);
+    let q = Q32E2::from(P32E2::from(-1.5));
+    assert_eq!(Buf::from_fmt(format_args!("{}", q)).as_str(), "-1.5");
+    assert_eq!(Buf::from_fmt(format_args!("{:x}", q)).as_str(), "-1.8");
+    assert_eq!(
+        Buf::from_fmt(format_args!("{:+#x}", Q32E2::ONE)).as_str(),
+        "+0x1"
+    );
+    assert_eq!(Buf::from_fmt(format_args!("{:>6.2}", q)).as_str(), " -1.50");
+    assert_eq!(Buf::from_fmt(format_args!("{:e}", q)).as_str(), "-1.5e0");
+    assert_eq!(
+        Buf::from_fmt(format_args!("{}", Q32E2::NAR)).as_str(),
+        "NaR"
+    );
+    assert_eq!(
+        Buf::from_fmt(format_args!("{:x}", Q32E2::NAR)).as_str(),
+        "NaR"
+    );
+    let mut min = Q32E2::init();
+    min += (P32E2::MIN_POSITIVE, P32E2::MIN_POSITIVE);
+    min.neg();
+    let neg_min = Buf::from_fmt(format_args!("{:#x}", min));
+    assert_eq!(
+        neg_min.as_str(),
+        "-0x0.000000000000000000000000000000000000000000000000000000000001"
+    );
+    let p = |s: &str| s.parse::<Q32E2>().unwrap();
+    assert_eq!(p("1").to_bits(), Q32E2::ONE.to_bits());
+    assert_eq!(p("-0x1.8p0").to_bits(), q.to_bits());
+    assert!(p("nar").is_nar());
+    assert!(p("1e100").is_nar());
+    assert!(p("-1e100").is_nar());
+    assert!(p("0x1p-241").is_zero());
+    assert!(p("1e-999999999999999").is_zero());
+    assert_eq!(
+        "".parse::<Q32E2>().err(),
+        Some(crate::ParsePositError::Empty)
+    );
+    assert_eq!(
+        "1x".parse::<Q32E2>().err(),
+        Some(crate::ParsePositError::Invalid)
+    );
 }
@@ -5,6 +5,8 @@ use crate::WithSign;
 use crate::P32E2;
 use core::convert::From;
 
+crate::impl_quire_convert!(Q32E2, 512, 240);
+
 impl From<P32E2> for Q32E2 {
     #[inline]
     fn from(a: P32E2) -> Self {
@@ -282,3 +284,17 @@ impl<const N: u32> From<&Q32E2> for PxE2<{ N }> {
         Self::from_bits(u_a.with_sign(sign))
     }
 }
+
+#[test]
+fn test_quire_convert() {
+    use crate::PositConversionError;
+    use core::convert::TryFrom;
+    use num_traits::FromPrimitive;
+    crate::limbs::test_convert(512, 240, Q32E2::to_limbs, Q32E2::from_limbs);
+    assert_eq!(Q32E2::from(P32E2::ONE).to_bits(), Q32E2::ONE.to_bits());
+    assert_eq!(Q32E2::from_f64(1.).unwrap().to_bits(), Q32E2::ONE.to_bits());
+    assert_eq!(f64::from(&Q32E2::ONE), 1.);
+    assert!(f64::from(Q32E2::NAR).is_nan());
+    assert!(Q32E2::from_f64(f64::NAN).unwrap().is_nar());
+    assert_eq!(i64::try_from(&Q32E2::NAR), Err(PositConversionError::NaR));
+}
@@ -6,6 +6,7 @@ use core::ops;
 
 crate::quire_add_sub!(P32E2, Q32E2);
 crate::quire_add_sub_array!(P32E2, Q32E2, 1, 2, 3, 4);
+crate::quire_add_sub_quire!(Q32E2);
 
 crate::quire_add_sub_x!(PxE2<{ N }>, Q32E2);
 crate::quire_add_sub_array_x!(PxE2<{ N }>, Q32E2, 1, 2, 3, 4);
@@ -112,9 +113,12 @@ pub(super) fn fdp(q: &mut Q32E2, mut ui_a: u32, mut ui_b: u32, plus: bool) {
         }
     }
 
-    //Exception handling
-    let q_z = Q32E2::from_bits(u_z);
-    *q = if q_z.is_nar() { Q32E2::ZERO } else { q_z }
+    //NaR if the sum overflows the carry bits
+    *q = if overflows(&u_z1, &u_z2, &u_z) {
+        Q32E2::NAR
+    } else {
+        Q32E2::from_bits(u_z)
+    }
 }
 
 pub(super) fn fdp_one(q: &mut Q32E2, mut ui_a: u32, plus: bool) {
@@ -211,9 +215,32 @@ pub(super) fn fdp_one(q: &mut Q32E2, mut ui_a: u32, plus: bool) {
         }
     }
 
-    //Exception handling
-    let q_z = Q32E2::from_bits(u_z);
-    *q = if q_z.is_nar() { Q32E2::ZERO } else { q_z }
+    //NaR if the sum overflows the carry bits
+    *q = if overflows(&u_z1, &u_z2, &u_z) {
+        Q32E2::NAR
+    } else {
+        Q32E2::from_bits(u_z)
+    }
+}
+
+/// Whether `z = a + b` wrapped around: the summands have the same sign and the
+/// sum has the other one.
+fn overflows(a: &[u64; 8], b: &[u64; 8], z: &[u64; 8]) -> bool {
+    let (a, b, z) = (a[0] >> 63, b[0] >> 63, z[0] >> 63);
+    a == b && z != a
+}
+
+pub(super) fn add_quire(q: &mut Q32E2, other: &Q32E2, plus: bool) {
+    if q.is_nar() || other.is_nar() {
+        *q = Q32E2::NAR;
+        return;
+    }
+    let mut u_z = q.to_bits();
+    *q = if crate::limbs::add(&mut u_z, &other.to_bits(), !plus) {
+        Q32E2::NAR
+    } else {
+        Q32E2::from_bits(u_z)
+    }
 }
 
 #[test]
@@ -255,3 +282,178 @@ fn test_quire_mul_sub() {
         assert_eq!(p, P32E2::from(f));
     }
 }
+
+#[cfg(test)]
+fn random_quire(rng: &mut impl rand::Rng) -> Q32E2 {
+    let mut q = Q32E2::init();
+    for _ in 0..rng.gen_range(0, 4) {
+        let (p_a, p_b): (P32E2, P32E2) = (rng.gen(), rng.gen());
+        if !p_a.is_nar() && !p_b.is_nar() {
+            q += (p_a, p_b);
+        }
+    }
+    q
+}
+
+#[test]
+fn test_quire_add_quire() {
+    use crate::limbs::value;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let q_a = random_quire(&mut rng);
+        let q_b = random_quire(&mut rng);
+        let (a, b) = (value(&q_a.to_bits()), value(&q_b.to_bits()));
+        let sum = q_a.clone() + &q_b;
+        let diff = q_a.clone() - &q_b;
+        assert_eq!(value(&sum.to_bits()), &a + &b);
+        assert_eq!(value(&diff.to_bits()), &a - &b);
+        let mut neg_b = q_b.clone();
+        neg_b.neg();
+        assert_eq!((q_a.clone() + neg_b).to_bits(), diff.to_bits());
+        let mut q = q_a.clone();
+        q += &q_b;
+        q -= q_b;
+        assert_eq!(q.to_bits(), q_a.to_bits());
+    }
+    assert!((Q32E2::NAR + Q32E2::ONE).is_nar());
+    assert!((Q32E2::ONE - Q32E2::NAR).is_nar());
+}
+
+#[test]
+fn test_quire_add_quire_carry() {
+    use crate::limbs::value;
+    use num_bigint::BigInt;
+    let lsb = Q32E2::from_bits([0, 0, 0, 0, 0, 0, 0, 1]);
+    // 2^(64 k) - 1 carries through the k lowest limbs
+    for k in 1..8 {
+        let mut bits = [0; 8];
+        for b in &mut bits[8 - k..] {
+            *b = u64::MAX;
+        }
+        let q = Q32E2::from_bits(bits) + &lsb;
+        assert_eq!(value(&q.to_bits()), BigInt::from(1) << (64 * k));
+        let q = q - &lsb;
+        assert_eq!(q.to_bits(), bits);
+    }
+    // -1 + 1 carries out of the top limb
+    let minus_one = Q32E2::from_bits([u64::MAX; 8]);
+    assert!((minus_one.clone() + &lsb).is_zero());
+    assert_eq!((Q32E2::ZERO - &lsb).to_bits(), minus_one.to_bits());
+    // a sum of products in every limb
+    let mut q = Q32E2::init();
+    q += (P32E2::MAX, P32E2::MAX);
+    q += (P32E2::MIN_POSITIVE, P32E2::MIN_POSITIVE);
+    let mut r = q.clone();
+    r.neg();
+    assert!((q.clone() + &r).is_zero());
+    assert_eq!(
+        (q.clone() + &q).to_bits(),
+        q.checked_mul_pow2(1).unwrap().to_bits()
+    );
+}
+
+#[test]
+fn test_quire_mul_pow2() {
+    use crate::limbs::value;
+    use num_bigint::BigInt;
+    use num_traits::Zero;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let max = BigInt::from(1) << 511;
+    for _ in 0..crate::NTESTS16 {
+        let q = random_quire(&mut rng);
+        let n = rng.gen_range(-600, 600);
+        let v = value(&q.to_bits());
+        let expected = if n >= 0 {
+            Some(&v << n as usize)
+        } else if (&v % (BigInt::from(1) << -n as usize)).is_zero() {
+            Some(&v >> -n as usize)
+        } else {
+            None
+        }
+        .filter(|z| *z < max && *z > -&max);
+        assert_eq!(
+            q.checked_mul_pow2(n).map(|z| value(&z.to_bits())),
+            expected,
+            "{:?} {}",
+            q,
+            n
+        );
+    }
+    // bits crossing every limb boundary, up and back down
+    let q = Q32E2::from_bits([
+        0,
+        1 << 63,
+        1 << 63,
+        1 << 63,
+        1 << 63,
+        1 << 63,
+        1 << 63,
+        1 << 63,
+    ]);
+    let z = q.checked_mul_pow2(1).unwrap();
+    assert_eq!(z.to_bits(), [1, 1, 1, 1, 1, 1, 1, 0]);
+    assert_eq!(z.checked_mul_pow2(-1).unwrap().to_bits(), q.to_bits());
+    assert!(q.checked_mul_pow2(-64).is_none());
+    assert!(Q32E2::ONE.checked_mul_pow2(i32::MAX).is_none());
+    assert!(Q32E2::ONE.checked_mul_pow2(i32::MIN).is_none());
+    assert!(Q32E2::ZERO.checked_mul_pow2(i32::MIN).unwrap().is_zero());
+    assert!(Q32E2::NAR.checked_mul_pow2(3).unwrap().is_nar());
+    // -2^510 doubles into the NaR pattern
+    let q = Q32E2::from_bits([0xC000_0000_0000_0000, 0, 0, 0, 0, 0, 0, 0]);
+    assert!(q.checked_mul_pow2(1).is_none());
+    assert!(q.checked_mul_pow2(-448).is_some());
+}
+
+#[test]
+fn test_quire_overflow() {
+    use crate::limbs::value;
+    use num_bigint::BigInt;
+    // blocks of products of maxpos merged as in a blocked dot product
+    let mut block = Q32E2::init();
+    for _ in 0..1024 {
+        block += (P32E2::MAX, P32E2::MAX);
+    }
+    let mut q = block.clone();
+    for _ in 0..20 {
+        q += &q.clone();
+    }
+    // 2^30 products, and 2^31 overflow the 31 carry bits
+    assert_eq!(value(&q.to_bits()), BigInt::from(1) << (480 + 30));
+    assert!((q.clone() + &q).is_nar());
+    let mut full = q.clone() - &block + &q;
+    for _ in 0..1023 {
+        full += (P32E2::MAX, P32E2::MAX);
+    }
+    assert_eq!(
+        value(&full.to_bits()),
+        ((BigInt::from(1) << 31_usize) - 1) << 480_usize
+    );
+    assert_eq!(full.to_posit(), P32E2::MAX);
+    let mut r = full.clone();
+    r += (P32E2::MAX, P32E2::MAX);
+    assert!(r.is_nar());
+    // and stays NaR when the sum comes back in range
+    r -= (P32E2::MAX, P32E2::MAX);
+    assert!(r.is_nar());
+    let mut r = full.clone();
+    r += P32E2::MIN_POSITIVE;
+    assert!(!r.is_nar());
+    r += (P32E2::MAX, P32E2::ONE);
+    assert!(!r.is_nar());
+    // the most negative value, -2^511, is the bit pattern of NaR
+    let mut r = full.clone();
+    r.neg();
+    assert_eq!(r.to_posit(), P32E2::MIN);
+    r -= (P32E2::MIN_POSITIVE, P32E2::MIN_POSITIVE);
+    assert!(!r.is_nar());
+    r -= (P32E2::MAX, P32E2::MAX);
+    assert!(r.is_nar());
+    let mut r = full.clone();
+    r.neg();
+    r -= (P32E2::MAX, P32E2::MAX);
+    r -= (P32E2::MAX, P32E2::MAX);
+    assert!(r.is_nar());
+    assert!((full.clone() - &block.checked_mul_pow2(-2).unwrap() + &block).is_nar());
+    assert!(full.checked_mul_pow2(1).is_none());
+}
@@ -0,0 +1,196 @@
+use super::{LIMBS, Q64E2};
+
+/// Quire whose eight least significant limbs are `low`; the constants below
+/// are rounded to the nearest multiple of 2^-496.
+const fn low(low: [u64; 8]) -> Q64E2 {
+    let mut bits = [0; LIMBS];
+    let mut i = 0;
+    while i < 8 {
+        bits[LIMBS - 8 + i] = low[i];
+        i += 1;
+    }
+    Q64E2(bits)
+}
+
+impl crate::MathConsts for Q64E2 {
+    const E: Self = low([
+        0x_0002_b7e1_5162_8aed,
+        0x_2a6a_bf71_5880_9cf4,
+        0x_f3c7_62e7_160f_38b4,
+        0x_da56_a784_d904_5190,
+        0x_cfef_324e_7738_926c,
+        0x_fbe5_f4bf_8d8d_8c31,
+        0x_d763_da06_c80a_bb11,
+        0x_85eb_4f7c_7b57_57f6,
+    ]);
+    const FRAC_1_PI: Self = low([
+        0x_0000_517c_c1b7_2722,
+        0x_0a94_fe13_abe8_fa9a,
+        0x_6ee0_6db1_4acc_9e21,
+        0x_c820_ff28_b1d5_ef5d,
+        0x_e2b0_db92_371d_2126,
+        0x_e970_0324_9775_04e8,
+        0x_c90e_7f0e_f58e_5894,
+        0x_d39f_7441_1afa_975e,
+    ]);
+    const FRAC_1_SQRT_2: Self = low([
+        0x_0000_b504_f333_f9de,
+        0x_6484_597d_89b3_754a,
+        0x_be9f_1d6f_60ba_893b,
+        0x_a84c_ed17_ac85_8333,
+        0x_9915_4afc_8304_3ab8,
+        0x_a2c3_a8b1_fe6f_dc83,
+        0x_db39_0f74_a85e_439c,
+        0x_7b4a_7804_8736_3dfa,
+    ]);
+    const FRAC_2_PI: Self = low([
+        0x_0000_a2f9_836e_4e44,
+        0x_1529_fc27_57d1_f534,
+        0x_ddc0_db62_9599_3c43,
+        0x_9041_fe51_63ab_debb,
+        0x_c561_b724_6e3a_424d,
+        0x_d2e0_0649_2eea_09d1,
+        0x_921c_fe1d_eb1c_b129,
+        0x_a73e_e882_35f5_2ebb,
+    ]);
+    const FRAC_2_SQRT_PI: Self = low([
+        0x_0001_20dd_7504_29b6,
+        0x_d11a_e3a9_14fe_d7fd,
+        0x_8688_2813_41d7_587c,
+        0x_ea2e_7342_b061_99cc,
+        0x_4161_80eb_39f0_b24e,
+        0x_1e22_8180_6c12_d98f,
+        0x_35d7_7a3e_9ddc_91c3,
+        0x_94f0_e9ee_df0e_fffe,
+    ]);
+    const FRAC_PI_2: Self = low([
+        0x_0001_921f_b544_42d1,
+        0x_8469_898c_c517_01b8,
+        0x_39a2_5204_9c11_14cf,
+        0x_98e8_0417_7d4c_7627,
+        0x_3644_a294_10f3_1c68,
+        0x_09bb_df2a_3367_9a74,
+        0x_8636_6056_14db_e4be,
+        0x_286e_9fc2_6ada_daa4,
+    ]);
+    const FRAC_PI_3: Self = low([
+        0x_0001_0c15_2382_d736,
+        0x_5846_5bb3_2e0f_567a,
+        0x_d116_e158_680b_6335,
+        0x_109a_ad64_fe32_f96f,
+        0x_7983_170d_60a2_12f0,
+        0x_067d_3f71_779a_66f8,
+        0x_5979_958e_b892_987e,
+        0x_c59f_152c_473c_91c2,
+    ]);
+    const FRAC_PI_4: Self = low([
+        0x_0000_c90f_daa2_2168,
+        0x_c234_c4c6_628b_80dc,
+        0x_1cd1_2902_4e08_8a67,
+        0x_cc74_020b_bea6_3b13,
+        0x_9b22_514a_0879_8e34,
+        0x_04dd_ef95_19b3_cd3a,
+        0x_431b_302b_0a6d_f25f,
+        0x_1437_4fe1_356d_6d52,
+    ]);
+    const FRAC_PI_6: Self = low([
+        0x_0000_860a_91c1_6b9b,
+        0x_2c23_2dd9_9707_ab3d,
+        0x_688b_70ac_3405_b19a,
+        0x_884d_56b2_7f19_7cb7,
+        0x_bcc1_8b86_b051_0978,
+        0x_033e_9fb8_bbcd_337c,
+        0x_2cbc_cac7_5c49_4c3f,
+        0x_62cf_8a96_239e_48e1,
+    ]);
+    const FRAC_PI_8: Self = low([
+        0x_0000_6487_ed51_10b4,
+        0x_611a_6263_3145_c06e,
+        0x_0e68_9481_2704_4533,
+        0x_e63a_0105_df53_1d89,
+        0x_cd91_28a5_043c_c71a,
+        0x_026e_f7ca_8cd9_e69d,
+        0x_218d_9815_8536_f92f,
+        0x_8a1b_a7f0_9ab6_b6a9,
+    ]);
+    const LN_10: Self = low([
+        0x_0002_4d76_3776_aaa2,
+        0x_b05b_a95b_58ae_0b4c,
+        0x_28a3_8a3f_b3e7_6977,
+        0x_e43a_0f18_7a08_07c0,
+        0x_b5ca_58bc_0b5e_c6a0,
+        0x_4173_31c3_2f00_b17c,
+        0x_35a0_b188_9061_042f,
+        0x_8b6b_ee3d_e210_0b94,
+    ]);
+    const LN_2: Self = low([
+        0x_0000_b172_17f7_d1cf,
+        0x_79ab_c9e3_b398_03f2,
+        0x_f6af_40f3_4326_7298,
+        0x_b62d_8a0d_175b_8baa,
+        0x_fa2b_e7b8_7620_6deb,
+        0x_ac98_5595_52fb_4afa,
+        0x_1b10_ed2e_ae35_c138,
+        0x_2144_2757_3b29_116a,
+    ]);
+    const LOG10_E: Self = low([
+        0x_0000_6f2d_ec54_9b94,
+        0x_38ca_9aad_d557_d699,
+        0x_ee19_1f71_a301_22e4,
+        0x_d101_1d1f_96a2_7bc7,
+        0x_529e_3aa1_277d_0a01,
+        0x_79f9_4911_aac9_6323,
+        0x_250a_8c67_1dec_fe9c,
+        0x_6e5e_37d1_5c69_6467,
+    ]);
+    const LOG2_E: Self = low([
+        0x_0001_7154_7652_b82f,
+        0x_e177_7d0f_fda0_d23a,
+        0x_7d11_d6ae_f551_bad2,
+        0x_b4b1_164a_2cd9_a342,
+        0x_648f_bc38_87ee_aa2e,
+        0x_d9ac_49b2_5eeb_82d7,
+        0x_c167_d521_73cc_1895,
+        0x_213f_897f_5e06_a7be,
+    ]);
+    const PI: Self = low([
+        0x_0003_243f_6a88_85a3,
+        0x_08d3_1319_8a2e_0370,
+        0x_7344_a409_3822_299f,
+        0x_31d0_082e_fa98_ec4e,
+        0x_6c89_4528_21e6_38d0,
+        0x_1377_be54_66cf_34e9,
+        0x_0c6c_c0ac_29b7_c97c,
+        0x_50dd_3f84_d5b5_b547,
+    ]);
+    const SQRT_2: Self = low([
+        0x_0001_6a09_e667_f3bc,
+        0x_c908_b2fb_1366_ea95,
+        0x_7d3e_3ade_c175_1277,
+        0x_5099_da2f_590b_0667,
+        0x_322a_95f9_0608_7571,
+        0x_4587_5163_fcdf_b907,
+        0x_b672_1ee9_50bc_8738,
+        0x_f694_f009_0e6c_7bf4,
+    ]);
+    const LOG2_10: Self = low([
+        0x_0003_5269_e12f_346e,
+        0x_2bf9_24af_dbfd_36bf,
+        0x_6d33_65b1_57f8_dece,
+        0x_b53a_46da_b202_0b9e,
+        0x_1674_1994_3f7a_7754,
+        0x_7ce8_f892_faad_8eb4,
+        0x_2f58_50d7_b920_1597,
+        0x_2953_3fc5_8b35_3d81,
+    ]);
+    const LOG10_2: Self = low([
+        0x_0000_4d10_4d42_7de7,
+        0x_fbcc_47c4_acd6_05be,
+        0x_48bc_1356_9862_a1e8,
+        0x_f9a4_c52f_3793_5be6,
+        0x_31e5_9435_16c0_c8cf,
+        0x_d5e8_4f2e_5e39_9a38,
+        0x_de89_48a3_9a4a_d8c5,
+        0x_c90f_2c5a_93fa_92a9,
+    ]);
+}
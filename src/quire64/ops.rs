@@ -0,0 +1,143 @@
+#[cfg(test)]
+use super::LIMBS;
+use super::{ONE_BIT, Q64E2};
+use crate::P64E2;
+use core::ops;
+
+crate::quire_add_sub!(P64E2, Q64E2);
+crate::quire_add_sub_array!(P64E2, Q64E2, 1, 2, 3, 4);
+
+pub(super) fn fdp(q: &mut Q64E2, ui_a: u64, ui_b: u64, plus: bool) {
+    let (p_a, p_b) = (P64E2::from_bits(ui_a), P64E2::from_bits(ui_b));
+    if q.is_nar() || p_a.is_nar() || p_b.is_nar() {
+        *q = Q64E2::NAR;
+        return;
+    } else if p_a.is_zero() || p_b.is_zero() {
+        return;
+    }
+    if crate::limbs::add_product(&mut q.0, ONE_BIT, p_a.unpack(), p_b.unpack(), plus) {
+        *q = Q64E2::NAR;
+    }
+}
+
+pub(super) fn fdp_one(q: &mut Q64E2, ui_a: u64, plus: bool) {
+    fdp(q, ui_a, P64E2::ONE.to_bits(), plus);
+}
+
+/// Exact value `m * 2^e` of the quire.
+#[cfg(test)]
+fn value(q: &Q64E2) -> (num_bigint::BigInt, i32) {
+    (crate::limbs::value(&q.0), -ONE_BIT)
+}
+
+#[test]
+fn test_quire_dot() {
+    use crate::p64e2::reference::{self, round};
+    use num_bigint::BigInt;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS8 * 10 {
+        let mut q = Q64E2::init();
+        let mut sum = BigInt::from(0);
+        let n = rng.gen_range(1, 8);
+        for i in 0..n {
+            let p_a: P64E2 = rng.gen();
+            // products close to the previous ones exercise cancellation
+            let p_b: P64E2 = if i % 2 == 1 && rng.gen() {
+                let (m, e) = value(&q);
+                -P64E2::from_bits(round(&m, &1.into(), e, 64, 2)) / p_a
+            } else {
+                rng.gen()
+            };
+            if p_b.is_nar() {
+                continue;
+            }
+            let plus = rng.gen();
+            if plus {
+                q += (p_a, p_b);
+            } else {
+                q -= (p_a, p_b);
+            }
+            let (a, ea) = reference::value(p_a.to_bits() as u128, 64, 2);
+            let (b, eb) = reference::value(p_b.to_bits() as u128, 64, 2);
+            let prod = (a * b) << (ea + eb + ONE_BIT) as usize;
+            if plus {
+                sum += prod;
+            } else {
+                sum -= prod;
+            }
+            assert_eq!(value(&q), (sum.clone(), -ONE_BIT));
+        }
+        assert_eq!(
+            q.to_posit().to_bits(),
+            round(&sum, &1.into(), -ONE_BIT, 64, 2)
+        );
+    }
+}
+
+#[test]
+fn test_quire_extremes() {
+    let mut q = Q64E2::init();
+    q += (P64E2::MAX, P64E2::MAX);
+    assert_eq!(q.to_posit(), P64E2::MAX);
+    q -= (P64E2::MAX, P64E2::MAX);
+    assert!(q.is_zero());
+    q += (P64E2::MIN_POSITIVE, P64E2::MIN_POSITIVE);
+    assert_eq!(q.to_bits()[LIMBS - 1], 1);
+    assert_eq!(q.to_posit(), P64E2::MIN_POSITIVE);
+    q -= (P64E2::MIN_POSITIVE, -P64E2::MIN_POSITIVE);
+    q += (P64E2::MAX, -P64E2::MAX);
+    assert_eq!(value(&q).0, -(num_bigint::BigInt::from(1) << 992) + 2);
+    q.neg();
+    q -= (P64E2::MAX, P64E2::MAX);
+    assert_eq!(value(&q).0, num_bigint::BigInt::from(-2));
+    // a sum far beyond maxpos still cancels exactly
+    let mut q = Q64E2::init();
+    for _ in 0..1000 {
+        q += (P64E2::MAX, P64E2::MAX);
+    }
+    q += P64E2::ONE;
+    for _ in 0..1000 {
+        q -= (P64E2::MAX, P64E2::MAX);
+    }
+    assert_eq!(q, Q64E2::ONE);
+    q += (P64E2::NAR, P64E2::ONE);
+    assert!(q.is_nar());
+    q += P64E2::ONE;
+    assert!(q.is_nar());
+}
+
+#[test]
+fn test_into_posits() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS8 {
+        let p_a: P64E2 = rng.gen();
+        let p_b: P64E2 = rng.gen();
+        let mut q = Q64E2::from(p_a);
+        q += (p_b, p_b);
+        let (p1, p2) = q.clone().into_two_posits();
+        assert_eq!(p1, q.to_posit());
+        // the second posit rounds the remainder of the first one
+        let (m, e) = value(&q);
+        let mut r = q.clone();
+        r -= p1;
+        assert_eq!(
+            p2.to_bits(),
+            crate::p64e2::reference::round(&value(&r).0, &1.into(), e, 64, 2)
+        );
+        r -= p2;
+        assert!(num_traits::Signed::abs(&value(&r).0) <= num_traits::Signed::abs(&m));
+    }
+}
+
+#[test]
+fn test_quire_overflow() {
+    let mut bits = [u64::MAX; LIMBS];
+    bits[0] = (1 << 63) - 1;
+    let mut q = Q64E2::from_bits(bits);
+    q += (P64E2::MIN_POSITIVE, P64E2::MIN_POSITIVE);
+    assert!(q.is_nar());
+    q -= (P64E2::MAX, P64E2::MAX);
+    assert!(q.is_nar());
+}
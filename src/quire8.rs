@@ -5,6 +5,11 @@ mod convert;
 mod math;
 mod ops;
 
+/// Standard quire of `P8E0`: a 32-bit two's complement fixed-point number that
+/// accumulates sums of products without rounding.
+///
+/// The least significant bit is `minpos^2 = 2^-12`, and 7 carry bits lie above
+/// `maxpos^2`; a sum that overflows them makes the quire NaR.
 #[derive(Clone, Debug)]
 pub struct Q8E0(i32);
 
@@ -72,6 +77,40 @@ impl Q8E0 {
         self.0 = self.0.wrapping_neg();
     }
 
+    /// The quire sign-extended to a 64-bit limb, for the conversions.
+    fn to_limbs(&self) -> [u64; 1] {
+        [self.0 as i64 as u64]
+    }
+
+    fn from_limbs(limbs: [u64; 1]) -> Self {
+        Self(limbs[0] as i32)
+    }
+
+    /// Multiplies the quire by `2^n` exactly.
+    ///
+    /// Returns `None` if the product is not representable: if set bits would be
+    /// shifted out below the least significant bit, or the result would not fit
+    /// in the quire. NaR stays NaR.
+    pub fn checked_mul_pow2(&self, n: i32) -> Option<Self> {
+        if self.is_nar() {
+            return Some(Self::NAR);
+        }
+        let shift = |x: i32, n: i32| {
+            if n >= 0 {
+                x.checked_shl(n as u32).unwrap_or(0)
+            } else {
+                x.checked_shr(n.unsigned_abs()).unwrap_or(x >> 31)
+            }
+        };
+        let z = shift(self.0, n);
+        // the shift is exact if and only if it can be undone
+        if shift(z, n.saturating_neg()) == self.0 && z != Self::NAR.0 {
+            Some(Self(z))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     pub fn into_two_posits(mut self) -> (P8E0, P8E0) {
         let p1 = self.to_posit();
@@ -87,6 +126,25 @@ impl Q8E0 {
         self -= p2;
         (p1, p2, self.to_posit())
     }
+
+    /// Splits the quire into the posits of its expansion: each one is the
+    /// remainder of the previous ones rounded to a posit.
+    #[inline]
+    pub fn into_posits(self) -> crate::IntoPosits<P8E0, Self> {
+        crate::IntoPosits::new(self)
+    }
+
+    /// The first `K` posits of the expansion, padded with zeros.
+    #[inline]
+    pub fn to_posits<const K: usize>(&self) -> [P8E0; K] {
+        crate::expansion::to_posits(self.clone())
+    }
+
+    /// Sums posits exactly, which reloads an expansion.
+    #[inline]
+    pub fn from_posits<I: IntoIterator<Item = P8E0>>(posits: I) -> Self {
+        crate::expansion::from_posits(posits)
+    }
 }
 
 impl crate::Quire<P8E0> for Q8E0 {
@@ -126,9 +184,49 @@ impl crate::Quire<P8E0> for Q8E0 {
     }
 }
 
-use core::fmt;
-impl fmt::Display for Q8E0 {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", f64::from(self.clone().to_posit()))
-    }
+crate::impl_quire_fmt!(Q8E0, 32, 12);
+
+#[test]
+fn test_quire_fmt() {
+    use crate::format::tests::Buf;
+    crate::format::tests::test_quire_fmt(32, 12, Q8E0::to_limbs, Q8E0::from_limbs);
+    let q = Q8E0::from(P8E0::from(-1.5));
+    assert_eq!(Buf::from_fmt(format_args!("{}", q)).as_str(), "-1.5");
+    assert_eq!(Buf::from_fmt(format_args!("{:x}", q)).as_str(), "-1.8");
+    assert_eq!(
+        Buf::from_fmt(format_args!("{:+#x}", Q8E0::ONE)).as_str(),
+        "+0x1"
+    );
+    assert_eq!(Buf::from_fmt(format_args!("{:>6.2}", q)).as_str(), " -1.50");
+    assert_eq!(Buf::from_fmt(format_args!("{:e}", q)).as_str(), "-1.5e0");
+    assert_eq!(Buf::from_fmt(format_args!("{}", Q8E0::NAR)).as_str(), "NaR");
+    assert_eq!(
+        Buf::from_fmt(format_args!("{:x}", Q8E0::NAR)).as_str(),
+        "NaR"
+    );
+    let mut min = Q8E0::init();
+    min += (P8E0::MIN_POSITIVE, P8E0::MIN_POSITIVE);
+    assert_eq!(
+        Buf::from_fmt(format_args!("{}", min)).as_str(),
+        "0.000244140625"
+    );
+    min.neg();
+    let neg_min = Buf::from_fmt(format_args!("{:#x}", min));
+    assert_eq!(neg_min.as_str(), "-0x0.001");
+    let p = |s: &str| s.parse::<Q8E0>().unwrap();
+    assert_eq!(p("1").to_bits(), Q8E0::ONE.to_bits());
+    assert_eq!(p("-0x1.8p0").to_bits(), q.to_bits());
+    assert!(p("nar").is_nar());
+    assert!(p("1e10").is_nar());
+    assert!(p("-1e10").is_nar());
+    assert!(p("0x1p-13").is_zero());
+    assert!(p("1e-999999999999999").is_zero());
+    assert_eq!(
+        "".parse::<Q8E0>().err(),
+        Some(crate::ParsePositError::Empty)
+    );
+    assert_eq!(
+        "1x".parse::<Q8E0>().err(),
+        Some(crate::ParsePositError::Invalid)
+    );
 }
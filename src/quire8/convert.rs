@@ -3,6 +3,8 @@ use crate::WithSign;
 use crate::P8E0;
 use core::convert::From;
 
+crate::impl_quire_convert!(Q8E0, 32, 12);
+
 impl From<P8E0> for Q8E0 {
     #[inline]
     fn from(a: P8E0) -> Self {
@@ -76,3 +78,17 @@ impl From<&Q8E0> for P8E0 {
         Self::from_bits(u_a.with_sign(sign))
     }
 }
+
+#[test]
+fn test_quire_convert() {
+    use crate::PositConversionError;
+    use core::convert::TryFrom;
+    use num_traits::FromPrimitive;
+    crate::limbs::test_convert(32, 12, Q8E0::to_limbs, Q8E0::from_limbs);
+    assert_eq!(Q8E0::from(P8E0::ONE).to_bits(), Q8E0::ONE.to_bits());
+    assert_eq!(Q8E0::from_f64(1.).unwrap().to_bits(), Q8E0::ONE.to_bits());
+    assert_eq!(f64::from(&Q8E0::ONE), 1.);
+    assert!(f64::from(Q8E0::NAR).is_nan());
+    assert!(Q8E0::from_f64(f64::NAN).unwrap().is_nar());
+    assert_eq!(i64::try_from(&Q8E0::NAR), Err(PositConversionError::NaR));
+}
@@ -4,6 +4,7 @@ use core::ops;
 
 crate::quire_add_sub!(P8E0, Q8E0);
 crate::quire_add_sub_array!(P8E0, Q8E0, 1, 2, 3, 4);
+crate::quire_add_sub_quire!(Q8E0);
 
 pub(super) fn fdp(q: &mut Q8E0, mut ui_a: u8, mut ui_b: u8, plus: bool) {
     let uq_z1 = q.to_bits();
@@ -45,12 +46,13 @@ pub(super) fn fdp(q: &mut Q8E0, mut ui_a: u8, mut ui_b: u8, plus: bool) {
         uq_z2 = uq_z2.wrapping_neg();
     }
 
-    //Addition
-    let uq_z = uq_z2.wrapping_add(uq_z1);
-
-    //Exception handling
-    let q_z = Q8E0::from_bits(uq_z);
-    *q = if q_z.is_nar() { Q8E0::ZERO } else { q_z }
+    //Addition, NaR if it overflows the carry bits
+    let (uq_z, overflow) = (uq_z1 as i32).overflowing_add(uq_z2 as i32);
+    *q = if overflow {
+        Q8E0::NAR
+    } else {
+        Q8E0::from_bits(uq_z as u32)
+    }
 }
 
 pub(super) fn fdp_one(q: &mut Q8E0, mut ui_a: u8, plus: bool) {
@@ -79,12 +81,31 @@ pub(super) fn fdp_one(q: &mut Q8E0, mut ui_a: u8, plus: bool) {
         uq_z2 = uq_z2.wrapping_neg();
     }
 
-    //Addition
-    let uq_z = uq_z2.wrapping_add(uq_z1);
+    //Addition, NaR if it overflows the carry bits
+    let (uq_z, overflow) = (uq_z1 as i32).overflowing_add(uq_z2 as i32);
+    *q = if overflow {
+        Q8E0::NAR
+    } else {
+        Q8E0::from_bits(uq_z as u32)
+    }
+}
 
-    //Exception handling
-    let q_z = Q8E0::from_bits(uq_z);
-    *q = if q_z.is_nar() { Q8E0::ZERO } else { q_z }
+pub(super) fn add_quire(q: &mut Q8E0, other: &Q8E0, plus: bool) {
+    if q.is_nar() || other.is_nar() {
+        *q = Q8E0::NAR;
+        return;
+    }
+    let (uq_z1, uq_z2) = (q.to_bits() as i32, other.to_bits() as i32);
+    let (uq_z, overflow) = if plus {
+        uq_z1.overflowing_add(uq_z2)
+    } else {
+        uq_z1.overflowing_sub(uq_z2)
+    };
+    *q = if overflow {
+        Q8E0::NAR
+    } else {
+        Q8E0::from_bits(uq_z as u32)
+    }
 }
 
 #[test]
@@ -126,3 +147,88 @@ fn test_quire_mul_sub() {
         assert_eq!(p, P8E0::from(f));
     }
 }
+
+#[test]
+fn test_quire_add_quire() {
+    for i in 0..=u8::MAX {
+        for j in 0..=u8::MAX {
+            let (p_a, p_b) = (P8E0::from_bits(i), P8E0::from_bits(j));
+            if p_a.is_nar() || p_b.is_nar() {
+                continue;
+            }
+            let mut q_a = Q8E0::init();
+            q_a += (p_a, p_b);
+            let q_b = Q8E0::from(p_b);
+            let (a, b) = (q_a.0, q_b.0);
+            assert_eq!((q_a.clone() + &q_b).0, a + b);
+            assert_eq!((q_a.clone() - q_b.clone()).0, a - b);
+            q_a -= q_b;
+            q_a += (-p_b, P8E0::ONE);
+            assert_eq!(q_a.0, a - 2 * b);
+        }
+    }
+    assert!((Q8E0::NAR + Q8E0::ONE).is_nar());
+    assert!((Q8E0::ONE - Q8E0::NAR).is_nar());
+}
+
+#[test]
+fn test_quire_mul_pow2() {
+    for i in 0..=u8::MAX {
+        let p = P8E0::from_bits(i);
+        if p.is_nar() {
+            continue;
+        }
+        let q = Q8E0::from(p);
+        let v = q.0 as i128;
+        for n in -40..40 {
+            let z = if n >= 0 { v << n } else { v >> -n };
+            let exact = (if n >= 0 { z >> n } else { z << -n }) == v;
+            let fits = z > i32::MIN as i128 && z <= i32::MAX as i128;
+            let expected = if exact && fits { Some(z as i32) } else { None };
+            assert_eq!(
+                q.checked_mul_pow2(n).map(|z| z.0),
+                expected,
+                "{:?} {}",
+                q,
+                n
+            );
+        }
+    }
+}
+
+#[test]
+fn test_quire_overflow() {
+    // the 7 carry bits hold 2^7 - 1 products of maxpos
+    let mut q = Q8E0::init();
+    for _ in 0..127 {
+        q += (P8E0::MAX, P8E0::MAX);
+    }
+    assert_eq!(q.0, 127 << 24);
+    assert_eq!(q.to_posit(), P8E0::MAX);
+    let full = q.clone();
+    q += (P8E0::MAX, P8E0::MAX);
+    assert!(q.is_nar());
+    // and stays NaR when the sum comes back in range
+    q -= (P8E0::MAX, P8E0::MAX);
+    assert!(q.is_nar());
+    assert!((full.clone() + &full).is_nar());
+    assert!(!(full.clone() + Q8E0::ONE).is_nar());
+    assert_eq!((full.clone() - &full).0, 0);
+    // -2^31 is the bit pattern of NaR
+    let mut q = Q8E0::init();
+    for _ in 0..127 {
+        q -= (P8E0::MAX, P8E0::MAX);
+    }
+    assert_eq!(q.to_posit(), P8E0::MIN);
+    q -= (P8E0::MAX, P8E0::MIN_POSITIVE);
+    assert!(!q.is_nar());
+    q += (P8E0::MIN, P8E0::MAX);
+    assert!(q.is_nar());
+    // cancelling products never overflow
+    let mut q = Q8E0::init();
+    for i in 0..1000 {
+        let p = if i % 2 == 0 { P8E0::MAX } else { P8E0::MIN };
+        q += (p, P8E0::MAX);
+    }
+    assert!(q.is_zero());
+}
@@ -0,0 +1,23 @@
+use super::Q8E2;
+
+// The constants are rounded to the nearest multiple of 2^-48.
+impl crate::MathConsts for Q8E2 {
+    const E: Self = Self([0, 0x_0002_b7e1_5162_8aed]);
+    const FRAC_1_PI: Self = Self([0, 0x_0000_517c_c1b7_2722]);
+    const FRAC_1_SQRT_2: Self = Self([0, 0x_0000_b504_f333_f9de]);
+    const FRAC_2_PI: Self = Self([0, 0x_0000_a2f9_836e_4e44]);
+    const FRAC_2_SQRT_PI: Self = Self([0, 0x_0001_20dd_7504_29b7]);
+    const FRAC_PI_2: Self = Self([0, 0x_0001_921f_b544_42d2]);
+    const FRAC_PI_3: Self = Self([0, 0x_0001_0c15_2382_d736]);
+    const FRAC_PI_4: Self = Self([0, 0x_0000_c90f_daa2_2169]);
+    const FRAC_PI_6: Self = Self([0, 0x_0000_860a_91c1_6b9b]);
+    const FRAC_PI_8: Self = Self([0, 0x_0000_6487_ed51_10b4]);
+    const LN_10: Self = Self([0, 0x_0002_4d76_3776_aaa3]);
+    const LN_2: Self = Self([0, 0x_0000_b172_17f7_d1cf]);
+    const LOG10_E: Self = Self([0, 0x_0000_6f2d_ec54_9b94]);
+    const LOG2_E: Self = Self([0, 0x_0001_7154_7652_b830]);
+    const PI: Self = Self([0, 0x_0003_243f_6a88_85a3]);
+    const SQRT_2: Self = Self([0, 0x_0001_6a09_e667_f3bd]);
+    const LOG2_10: Self = Self([0, 0x_0003_5269_e12f_346e]);
+    const LOG10_2: Self = Self([0, 0x_0000_4d10_4d42_7de8]);
+}
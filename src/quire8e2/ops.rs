@@ -0,0 +1,118 @@
+use super::{ONE_BIT, Q8E2};
+use crate::P8E2;
+use core::ops;
+
+crate::quire_add_sub!(P8E2, Q8E2);
+crate::quire_add_sub_array!(P8E2, Q8E2, 1, 2, 3, 4);
+
+pub(super) fn fdp(q: &mut Q8E2, ui_a: u8, ui_b: u8, plus: bool) {
+    let (p_a, p_b) = (P8E2::from_bits(ui_a), P8E2::from_bits(ui_b));
+    if q.is_nar() || p_a.is_nar() || p_b.is_nar() {
+        *q = Q8E2::NAR;
+        return;
+    } else if p_a.is_zero() || p_b.is_zero() {
+        return;
+    }
+    if crate::limbs::add_product(&mut q.0, ONE_BIT, p_a.unpack(), p_b.unpack(), plus) {
+        *q = Q8E2::NAR;
+    }
+}
+
+pub(super) fn fdp_one(q: &mut Q8E2, ui_a: u8, plus: bool) {
+    fdp(q, ui_a, P8E2::ONE.to_bits(), plus);
+}
+
+/// Exact value `m * 2^e` of the quire.
+#[cfg(test)]
+fn value(q: &Q8E2) -> (num_bigint::BigInt, i32) {
+    (crate::limbs::value(&q.0), -ONE_BIT)
+}
+
+#[test]
+fn test_quire_dot() {
+    use crate::p64e2::reference::{self, round};
+    use num_bigint::BigInt;
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for _ in 0..crate::NTESTS16 {
+        let mut q = Q8E2::init();
+        let mut sum = BigInt::from(0);
+        let n = rng.gen_range(1, 8);
+        for i in 0..n {
+            let p_a: P8E2 = rng.gen();
+            // products close to the previous ones exercise cancellation
+            let p_b: P8E2 = if i % 2 == 1 && rng.gen() {
+                let (m, e) = value(&q);
+                -P8E2::from_bits(round(&m, &1.into(), e, 8, 2) as u8) / p_a
+            } else {
+                rng.gen()
+            };
+            if p_b.is_nar() {
+                continue;
+            }
+            let plus = rng.gen();
+            if plus {
+                q += (p_a, p_b);
+            } else {
+                q -= (p_a, p_b);
+            }
+            let (a, ea) = reference::value(p_a.to_bits() as u128, 8, 2);
+            let (b, eb) = reference::value(p_b.to_bits() as u128, 8, 2);
+            let prod = (a * b) << (ea + eb + ONE_BIT) as usize;
+            if plus {
+                sum += prod;
+            } else {
+                sum -= prod;
+            }
+            assert_eq!(value(&q), (sum.clone(), -ONE_BIT));
+        }
+        assert_eq!(
+            q.to_posit().to_bits(),
+            round(&sum, &1.into(), -ONE_BIT, 8, 2) as u8
+        );
+    }
+}
+
+#[test]
+fn test_quire_extremes() {
+    use num_bigint::BigInt;
+    let mut q = Q8E2::init();
+    q += (P8E2::MIN_POSITIVE, P8E2::MIN_POSITIVE);
+    assert_eq!(value(&q).0, BigInt::from(1));
+    assert_eq!(q.to_posit(), P8E2::MIN_POSITIVE);
+    q -= (P8E2::MAX, P8E2::MAX);
+    assert_eq!(value(&q).0, 1 - (BigInt::from(1) << (2 * ONE_BIT) as usize));
+    // the carry bits hold 2^31 - 1 products of maxpos
+    let mut q = Q8E2::init();
+    for _ in 0..1000 {
+        q += (P8E2::MAX, P8E2::MAX);
+    }
+    assert_eq!(q.to_posit(), P8E2::MAX);
+    q += P8E2::ONE;
+    for _ in 0..1000 {
+        q -= (P8E2::MAX, P8E2::MAX);
+    }
+    assert_eq!(q, Q8E2::ONE);
+    q.neg();
+    assert_eq!(q.to_posit(), -P8E2::ONE);
+    q += (P8E2::NAR, P8E2::ONE);
+    assert!(q.is_nar());
+    q += P8E2::ONE;
+    assert!(q.is_nar());
+}
+
+#[test]
+fn test_quire_overflow() {
+    // the largest value that fits and the bit below the most negative one
+    let mut q = Q8E2::from_bits(i128::MAX as u128);
+    q -= (P8E2::MIN_POSITIVE, P8E2::MIN_POSITIVE);
+    q += (P8E2::MIN_POSITIVE, P8E2::MIN_POSITIVE);
+    assert!(!q.is_nar());
+    q += (P8E2::MIN_POSITIVE, P8E2::MIN_POSITIVE);
+    assert!(q.is_nar());
+    q -= (P8E2::MAX, P8E2::MAX);
+    assert!(q.is_nar());
+    let mut q = Q8E2::from_bits((1 << 127) | 1);
+    q -= (P8E2::MAX, P8E2::MAX);
+    assert!(q.is_nar());
+}
@@ -0,0 +1,545 @@
+//! Extended precision unpacked values used to produce correctly rounded
+//! results, e.g. for integer powers.
+
+use crate::convert::{decode_posit, encode_posit};
+
+/// Value `(-1)^sign * frac * 2^(scale - 127)` with the hidden bit of `frac`
+/// at bit 127.
+///
+/// All operations truncate, so the exact value lies in `[frac, frac + err]`
+/// in units of the last place.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct XFloat {
+    sign: bool,
+    scale: i64,
+    frac: u128,
+    err: u64,
+}
+
+impl XFloat {
+    pub const ONE: Self = Self {
+        sign: false,
+        scale: 0,
+        frac: 1 << 127,
+        err: 0,
+    };
+
+    /// Unpacks a nonzero, non-NaR `n`-bit posit with `es` exponent bits.
+    pub fn from_posit_bits(bits: u64, n: u32, es: u32) -> Self {
+        let (sign, scale, frac) = decode_posit(bits, n, es);
+        Self {
+            sign,
+            scale: scale as i64,
+            frac: (frac as u128) << 64,
+            err: 0,
+        }
+    }
+
+    /// Reciprocal of a value with a fraction of at most 64 significant bits.
+    // `u128::is_multiple_of` needs a newer compiler
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn recip(self) -> Self {
+        debug_assert_eq!(self.err, 0);
+        debug_assert_eq!(self.frac as u64, 0);
+        let m = (self.frac >> 64) as u64 as u128;
+        if m == 1 << 63 {
+            return Self {
+                scale: -self.scale,
+                ..self
+            };
+        }
+        // floor(2^191 / m), which lies in (2^127, 2^128)
+        let hi = (1 << 127) / m;
+        let rem = ((1 << 127) % m) << 64;
+        let lo = rem / m;
+        Self {
+            sign: self.sign,
+            scale: -self.scale - 1,
+            frac: (hi << 64) | lo,
+            err: (rem % m != 0) as u64,
+        }
+    }
+
+    pub fn mul(self, other: Self) -> Self {
+        let (hi, lo) = mul_u128(self.frac, other.frac);
+        let err = self
+            .err
+            .saturating_add(other.err)
+            .saturating_mul(2)
+            .saturating_add(2);
+        let (frac, scale, lost) = if (hi >> 127) != 0 {
+            (hi, self.scale + other.scale + 1, lo != 0)
+        } else {
+            (
+                (hi << 1) | (lo >> 127),
+                self.scale + other.scale,
+                (lo << 1) != 0,
+            )
+        };
+        Self {
+            sign: self.sign ^ other.sign,
+            scale,
+            frac,
+            err: if lost || self.err != 0 || other.err != 0 {
+                err
+            } else {
+                0
+            },
+        }
+    }
+
+    /// Rounds to an `n`-bit posit with `es` exponent bits.
+    ///
+    /// The result is correctly rounded unless a rounding boundary lies within
+    /// `err` units of the last place (2^-127 relative each) of the exact
+    /// value, in which case the lower bound is rounded.
+    pub fn to_posit_bits(self, n: u32, es: u32) -> u64 {
+        // Anything this far out of range saturates anyway.
+        let scale = self.scale.clamp(-(1 << 20), 1 << 20) as i32;
+        let lower = encode_posit(self.sign, scale, self.frac, self.err != 0, n, es);
+        if self.err == 0 {
+            return lower;
+        }
+        let (frac, scale) = match self.frac.checked_add(self.err as u128) {
+            Some(frac) => (frac, scale),
+            None => (1 << 127, scale + 1),
+        };
+        let upper = encode_posit(self.sign, scale, frac, true, n, es);
+        if upper == lower {
+            upper
+        } else {
+            lower
+        }
+    }
+}
+
+/// Full 256-bit product of two 128-bit integers as `(high, low)`.
+fn mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = 0xFFFF_FFFF_FFFF_FFFF;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+    let ll = a_lo * b_lo;
+    let lh = a_lo * b_hi;
+    let hl = a_hi * b_lo;
+    let hh = a_hi * b_hi;
+    let mid = (ll >> 64) + (lh & MASK) + (hl & MASK);
+    let lo = (ll & MASK) | (mid << 64);
+    let hi = hh + (lh >> 64) + (hl >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Integer power of an `n`-bit posit with `es` exponent bits.
+///
+/// `powi(x, 0)` is 1 for every real `x`, NaR stays NaR, and zero raised to a
+/// negative power is NaR. Results saturate to maxpos / minpos.
+///
+/// The error bound of the 128-bit evaluation grows to about `exp^2 2^-124`
+/// (relative), and the result is correctly rounded unless a rounding boundary
+/// lies that close to the exact power.
+pub(crate) fn powi(bits: u64, exp: i32, n: u32, es: u32) -> u64 {
+    let mask = u64::MAX >> (64 - n);
+    let bits = bits & mask;
+    let nar = 1 << (n - 1);
+    if bits == nar {
+        return nar;
+    } else if exp == 0 {
+        return 1 << (n - 2);
+    } else if bits == 0 {
+        return if exp > 0 { 0 } else { nar };
+    }
+
+    let mut base = XFloat::from_posit_bits(bits, n, es);
+    if exp < 0 {
+        base = base.recip();
+    }
+    let mut e = exp.wrapping_abs() as u32;
+    let mut acc = XFloat::ONE;
+    loop {
+        if (e & 1) != 0 {
+            acc = acc.mul(base);
+        }
+        e >>= 1;
+        if e == 0 {
+            break;
+        }
+        base = base.mul(base);
+    }
+    acc.to_posit_bits(n, es)
+}
+
+/// Exact integer power computed with big integers, used as a test reference.
+#[cfg(test)]
+pub(crate) fn powi_exact(bits: u64, exp: i32, n: u32, es: u32) -> u64 {
+    use num_bigint::BigUint;
+    use num_traits::{ToPrimitive, Zero};
+
+    let mask = u64::MAX >> (64 - n);
+    let bits = bits & mask;
+    let nar = 1 << (n - 1);
+    if bits == nar {
+        return nar;
+    } else if exp == 0 {
+        return 1 << (n - 2);
+    } else if bits == 0 {
+        return if exp > 0 { 0 } else { nar };
+    }
+
+    let (sign, scale, frac) = decode_posit(bits, n, es);
+    let tz = frac.trailing_zeros();
+    let k = exp.wrapping_abs() as u32 as usize;
+    // |x| = m * 2^e with odd m
+    let m = BigUint::from(frac >> tz);
+    let e = (scale - 63 + tz as i32) as i64 * (k as i64);
+    let p = num_traits::pow(m, k);
+    let len = p.bits();
+
+    let (top, sticky, scale) = if exp > 0 {
+        let sh = len as i64 - 128;
+        if sh > 0 {
+            let top = &p >> (sh as usize);
+            let sticky = (&top << (sh as usize)) != p;
+            (top, sticky, e + len as i64 - 1)
+        } else {
+            (p << ((-sh) as usize), false, e + len as i64 - 1)
+        }
+    } else {
+        let num = BigUint::from(1_u8) << (len + 130);
+        let q = &num / &p;
+        let sticky = !(&num % &p).is_zero();
+        let sh = q.bits() - 128;
+        let top = &q >> sh;
+        let sticky = sticky || (&top << sh) != q;
+        (top, sticky, -e + q.bits() as i64 - 1 - (len as i64 + 130))
+    };
+    let hi = (&top >> 64).to_u64().unwrap() as u128;
+    let lo = (top & BigUint::from(u64::MAX)).to_u64().unwrap() as u128;
+    let scale = scale.clamp(-(1 << 20), 1 << 20) as i32;
+    encode_posit(sign && (k & 1) != 0, scale, (hi << 64) | lo, sticky, n, es)
+}
+
+/// Sum of two `n`-bit posits with `es` exponent bits, correctly rounded.
+pub(crate) fn add(a: u64, b: u64, n: u32, es: u32) -> u64 {
+    let mask = u64::MAX >> (64 - n);
+    let (a, b) = (a & mask, b & mask);
+    let nar = 1 << (n - 1);
+    if a == nar || b == nar {
+        return nar;
+    } else if a == 0 {
+        return b;
+    } else if b == 0 {
+        return a;
+    }
+
+    let (sign_a, scale_a, frac_a) = decode_posit(a, n, es);
+    let (sign_b, scale_b, frac_b) = decode_posit(b, n, es);
+    match add_unpacked(
+        (sign_a, scale_a, (frac_a as u128) << 62),
+        (sign_b, scale_b, (frac_b as u128) << 62),
+    ) {
+        Some((sign, scale, frac, sticky)) => encode_posit(sign, scale, frac, sticky, n, es),
+        None => 0,
+    }
+}
+
+/// Product of two `n`-bit posits with `es` exponent bits, correctly rounded.
+pub(crate) fn mul(a: u64, b: u64, n: u32, es: u32) -> u64 {
+    let mask = u64::MAX >> (64 - n);
+    let (a, b) = (a & mask, b & mask);
+    let nar = 1 << (n - 1);
+    if a == nar || b == nar {
+        return nar;
+    } else if a == 0 || b == 0 {
+        return 0;
+    }
+
+    let (sign_a, scale_a, frac_a) = decode_posit(a, n, es);
+    let (sign_b, scale_b, frac_b) = decode_posit(b, n, es);
+    let mut frac = (frac_a as u128) * (frac_b as u128);
+    let mut scale = scale_a + scale_b;
+    if (frac >> 127) != 0 {
+        scale += 1;
+    } else {
+        frac <<= 1;
+    }
+    encode_posit(sign_a ^ sign_b, scale, frac, false, n, es)
+}
+
+/// Quotient of two `n`-bit posits with `es` exponent bits, correctly
+/// rounded.
+///
+/// Returns NaR if the divisor is zero.
+pub(crate) fn div(a: u64, b: u64, n: u32, es: u32) -> u64 {
+    let mask = u64::MAX >> (64 - n);
+    let (a, b) = (a & mask, b & mask);
+    let nar = 1 << (n - 1);
+    if a == nar || b == nar || b == 0 {
+        return nar;
+    } else if a == 0 {
+        return 0;
+    }
+
+    let (sign_a, scale_a, frac_a) = decode_posit(a, n, es);
+    let (sign_b, scale_b, frac_b) = decode_posit(b, n, es);
+    // The quotient lies in (2^63, 2^65) and has at least 64 significant
+    // bits, more than any posit fraction.
+    let num = (frac_a as u128) << 64;
+    let (quot, rem) = (num / (frac_b as u128), num % (frac_b as u128));
+    let lz = quot.leading_zeros();
+    let scale = scale_a - scale_b + 63 - lz as i32;
+    encode_posit(sign_a ^ sign_b, scale, quot << lz, rem != 0, n, es)
+}
+
+/// How the integer quotient of `div_rem` is chosen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum QuotRounding {
+    /// Towards zero, the remainder has the sign of the dividend (`fmod`).
+    Trunc,
+    /// To nearest, ties to even, the remainder is at most half the divisor
+    /// (IEEE 754 `remainder`).
+    Nearest,
+    /// Such that the remainder is never negative.
+    Euclid,
+}
+
+/// Integer quotient `q` of two `n`-bit posits with `es` exponent bits and the
+/// remainder `a - q * b`, both computed exactly on the integer fractions and
+/// rounded once, and the lowest 31 bits of `q` with its sign.
+///
+/// Returns NaR if an argument is NaR or the divisor is zero.
+pub(crate) fn div_rem(a: u64, b: u64, n: u32, es: u32, rounding: QuotRounding) -> (u64, u64, i32) {
+    let mask = u64::MAX >> (64 - n);
+    let (a, b) = (a & mask, b & mask);
+    let nar = 1 << (n - 1);
+    if a == nar || b == nar || b == 0 {
+        return (nar, nar, 0);
+    } else if a == 0 {
+        return (0, 0, 0);
+    }
+
+    let (sign_a, scale_a, frac_a) = decode_posit(a, n, es);
+    let (sign_b, scale_b, frac_b) = decode_posit(b, n, es);
+    let den = frac_b as u128;
+    // The quotient is `quot * 2^dropped`, where `sticky` and `ones` tell
+    // whether any and all of the dropped bits are set, and `low` holds its
+    // lowest bits. The remainder is `rem * 2^(rem_scale - 63)`.
+    let (mut quot, mut dropped, mut sticky, mut ones, mut low) = (0_u128, 0, false, true, 0_u64);
+    let (rem, rem_scale) = if scale_a < scale_b {
+        (frac_a, scale_a)
+    } else {
+        // Long division of `frac_a * 2^(scale_a - scale_b)` by `frac_b`, at
+        // most 64 bits at a time.
+        let mut rem = frac_a as u128 % den;
+        quot = frac_a as u128 / den;
+        low = quot as u64;
+        let mut shift = (scale_a - scale_b) as u32;
+        while shift > 0 {
+            let s = shift.min(64);
+            shift -= s;
+            rem <<= s;
+            let digit = rem / den;
+            rem %= den;
+            low = (((low as u128) << s) | digit) as u64;
+            let keep = quot.leading_zeros().min(s);
+            let drop = s - keep;
+            quot = (quot << keep) | (digit >> drop);
+            if drop != 0 {
+                let bits = digit & ((1 << drop) - 1);
+                sticky |= bits != 0;
+                ones &= bits == (1 << drop) - 1;
+                dropped += drop;
+            }
+        }
+        (rem as u64, scale_b)
+    };
+
+    let bump = match rounding {
+        QuotRounding::Trunc => false,
+        QuotRounding::Nearest => {
+            // twice the remainder against the divisor, in units of the remainder
+            let shift = (scale_b - rem_scale) as u32;
+            shift <= 1 && {
+                let (twice, den) = ((rem as u128) << 1, den << shift);
+                twice > den || (twice == den && (low & 1) != 0)
+            }
+        }
+        QuotRounding::Euclid => sign_a && rem != 0,
+    };
+
+    let rem_bits = if bump {
+        // |b| - |rem| with the opposite sign
+        let lz = rem.leading_zeros();
+        match add_unpacked(
+            (!sign_a, scale_b, den << 62),
+            (sign_a, rem_scale - lz as i32, ((rem << lz) as u128) << 62),
+        ) {
+            Some((sign, scale, frac, sticky)) => encode_posit(sign, scale, frac, sticky, n, es),
+            None => 0,
+        }
+    } else if rem == 0 {
+        0
+    } else {
+        let lz = rem.leading_zeros();
+        let frac = ((rem << lz) as u128) << 64;
+        encode_posit(sign_a, rem_scale - lz as i32, frac, false, n, es)
+    };
+
+    if bump {
+        low = low.wrapping_add(1);
+        // the carry runs through the dropped bits only if they are all set
+        if ones {
+            sticky = false;
+            quot = match quot.checked_add(1) {
+                Some(quot) => quot,
+                None => {
+                    dropped += 1;
+                    1 << 127
+                }
+            };
+        } else {
+            sticky = true;
+        }
+    }
+    let sign = sign_a ^ sign_b;
+    let quot_bits = if quot == 0 {
+        0
+    } else {
+        let lz = quot.leading_zeros();
+        let scale = 127 - lz as i32 + dropped as i32;
+        encode_posit(sign, scale, quot << lz, sticky, n, es)
+    };
+    let quo = (low & 0x7FFF_FFFF) as i32;
+    (quot_bits, rem_bits, if sign { -quo } else { quo })
+}
+
+/// Correctly rounded square root of an `n`-bit posit with `es` exponent bits.
+///
+/// Returns NaR for negative numbers and NaR.
+pub(crate) fn sqrt(bits: u64, n: u32, es: u32) -> u64 {
+    let mask = u64::MAX >> (64 - n);
+    let bits = bits & mask;
+    if bits == 0 {
+        return 0;
+    } else if (bits >> (n - 1)) != 0 {
+        return 1 << (n - 1);
+    }
+    let (_, scale, frac) = decode_posit(bits, n, es);
+    // Scale the fraction to an even power of two, the root then has its
+    // hidden bit at bit 63 and the scale of the result is floor(scale / 2).
+    let m = if (scale & 1) != 0 {
+        (frac as u128) << 64
+    } else {
+        (frac as u128) << 63
+    };
+    let r = isqrt(m);
+    let sticky = r * r != m;
+    encode_posit(false, scale >> 1, r << 64, sticky, n, es)
+}
+
+/// Integer square root, `floor(sqrt(m))`, by Newton's iteration from above.
+fn isqrt(m: u128) -> u128 {
+    if m == 0 {
+        return 0;
+    }
+    // 2^ceil(bits / 2) is at least the root
+    let mut x = 1 << ((129 - m.leading_zeros()) / 2);
+    loop {
+        let y = (x + m / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Fused multiply-add `a * b + c` of `n`-bit posits with `es` exponent bits,
+/// computed exactly and rounded once.
+///
+/// Returns NaR if any argument is NaR.
+pub(crate) fn mul_add(a: u64, b: u64, c: u64, n: u32, es: u32) -> u64 {
+    let mask = u64::MAX >> (64 - n);
+    let (a, b, c) = (a & mask, b & mask, c & mask);
+    let nar = 1 << (n - 1);
+    if a == nar || b == nar || c == nar {
+        return nar;
+    } else if a == 0 || b == 0 {
+        return c;
+    }
+
+    let (sign_a, scale_a, frac_a) = decode_posit(a, n, es);
+    let (sign_b, scale_b, frac_b) = decode_posit(b, n, es);
+    // The fractions have less than 64 significant bits, so the product is
+    // exact and its lowest bits are zero.
+    let mut prod = (frac_a as u128) * (frac_b as u128);
+    let mut scale = scale_a + scale_b;
+    if (prod >> 127) != 0 {
+        scale += 1;
+    } else {
+        prod <<= 1;
+    }
+    let sign = sign_a ^ sign_b;
+    if c == 0 {
+        return encode_posit(sign, scale, prod, false, n, es);
+    }
+
+    let (sign_c, scale_c, frac_c) = decode_posit(c, n, es);
+    match add_unpacked(
+        (sign, scale, prod >> 2),
+        (sign_c, scale_c, (frac_c as u128) << 62),
+    ) {
+        Some((sign, scale, frac, sticky)) => encode_posit(sign, scale, frac, sticky, n, es),
+        None => 0,
+    }
+}
+
+/// Exact sum of two unpacked values `(sign, scale, frac)`, whose fractions
+/// have the hidden bit at bit 125 and two guard bits above it to catch the
+/// carry.
+///
+/// Returns the sum with the hidden bit at bit 127 and whether nonzero bits
+/// were shifted out, or `None` if the sum is zero.
+pub(crate) fn add_unpacked(
+    x: (bool, i32, u128),
+    y: (bool, i32, u128),
+) -> Option<(bool, i32, u128, bool)> {
+    let (big, small) = if (x.1, x.2) >= (y.1, y.2) {
+        (x, y)
+    } else {
+        (y, x)
+    };
+    let shift = (big.1 - small.1) as u32;
+    let (addend, sticky) = if shift >= 128 {
+        (0, true)
+    } else {
+        let addend = small.2 >> shift;
+        (addend, (addend << shift) != small.2)
+    };
+    let frac = if big.0 == small.0 {
+        big.2 + addend
+    } else {
+        // The sticky bits are subtracted too, so that the difference lies
+        // strictly between `frac` and `frac + 1`.
+        big.2 - addend - (sticky as u128)
+    };
+    if frac == 0 {
+        return None;
+    }
+    let lz = frac.leading_zeros();
+    Some((big.0, big.1 + 2 - lz as i32, frac << lz, sticky))
+}
+
+#[test]
+fn test_isqrt() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    assert_eq!(isqrt(0), 0);
+    assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    for _ in 0..crate::NTESTS32 {
+        let r = (rng.gen::<u64>() >> rng.gen_range(0, 64)) as u128;
+        let s = r * r;
+        assert_eq!(isqrt(s), r);
+        assert_eq!(isqrt(s + 2 * r), r);
+        if r != 0 {
+            assert_eq!(isqrt(s - 1), r - 1);
+        }
+    }
+}